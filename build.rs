@@ -1,12 +1,15 @@
-use std::{ env, fs, path::Path };
+use std::{ collections::BTreeMap, env, fs, path::Path };
 
 use proc_macro2::TokenStream;
 use quote::quote;
 use serde::{ Deserialize, Serialize };
 use syn::File;
+use unicode_normalization::{ char::is_combining_mark, UnicodeNormalization };
 
-const FALLBACK_JSON: &'static str = include_str!("fallback.json");
-const SOURCE_URL: &'static str =
+const FALLBACK_JSON: &str = include_str!("fallback.json");
+const CHANGELOG_JSON: &str = include_str!("changelog.json");
+const NOTES_JSON: &str = include_str!("notes.json");
+const SOURCE_URL: &str =
     "https://raw.githubusercontent.com/internetarchive/openlibrary/refs/heads/master/openlibrary/components/LibraryExplorer/ddc.json";
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -16,125 +19,540 @@ enum Class {
         name: String,
         short: String,
         query: String,
-        children: Vec<Box<Class>>,
+        children: Vec<Class>,
         count: u64,
+        #[serde(default)]
+        notes: Option<String>,
     },
     Leaf {
         name: String,
         short: String,
         query: String,
         count: u64,
+        #[serde(default)]
+        notes: Option<String>,
     },
 }
 
-fn get_classes() -> Vec<Class> {
-    if let Ok(response) = reqwest::blocking::get(SOURCE_URL).and_then(|r| r.error_for_status()) {
-        if let Ok(result) = response.json::<Vec<Class>>() {
-            return result;
+impl Class {
+    fn trimmed_code(&self) -> Option<String> {
+        let short = match self {
+            Class::Node { short, .. } | Class::Leaf { short, .. } => short,
+        };
+        // Only embed codes in DDC notation (ie `5`, `813`, `813.54`), skipping ranges like `007–009`
+        let trimmed_code = short.trim_end_matches('X');
+        let is_digits = |part: &str| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit());
+        let is_code = match trimmed_code.split_once('.') {
+            Some((integer, fraction)) => integer.len() == 3 && is_digits(integer) && is_digits(fraction),
+            None => is_digits(trimmed_code),
+        };
+        is_code.then(|| trimmed_code.to_string())
+    }
+
+    /// Gets the depth of the class, counting its digits (`0` for main classes, `3` for `516.3`), or [None] for ranges
+    fn depth(&self) -> Option<usize> {
+        self.trimmed_code().map(|code| code.chars().filter(char::is_ascii_digit).count().saturating_sub(1))
+    }
+
+    /// Removes the descendants deeper than `max_depth`, turning nodes left without children into leaves
+    fn prune(self, max_depth: usize) -> Class {
+        match self {
+            Class::Node { name, short, query, children, count, notes } => {
+                let children = children
+                    .into_iter()
+                    .filter(|child| child.depth().is_none_or(|depth| depth <= max_depth))
+                    .map(|child| child.prune(max_depth))
+                    .collect::<Vec<_>>();
+                if children.is_empty() {
+                    Class::Leaf { name, short, query, count, notes }
+                } else {
+                    Class::Node { name, short, query, children, count, notes }
+                }
+            }
+            leaf => leaf,
+        }
+    }
+
+    fn embedded_children(&self) -> Vec<&Class> {
+        match self {
+            Class::Node { children, .. } => children
+                .iter()
+                .filter(|child| child.trimmed_code().is_some())
+                .collect(),
+            Class::Leaf { .. } => Vec::new(),
         }
     }
 
-    serde_json::from_str(FALLBACK_JSON).expect("Failed to deserialize fallback data.")
+    fn descendant_count(&self) -> usize {
+        self.embedded_children()
+            .into_iter()
+            .map(|child| 1 + child.descendant_count())
+            .sum()
+    }
 }
 
-fn generate_class(output: &mut Vec<TokenStream>, class: Class) {
-    match class {
-        Class::Node { name, short, children, .. } => {
-            let trimmed_code = short.trim_end_matches('X').to_string();
-            if trimmed_code.len() > 4 {
-                return;
-            }
-            output.push(
-                quote! {
-                {
-                    let code: String = #trimmed_code.to_owned();
-                    let _ = trie.insert(
-                        code
-                            .chars()
-                            .map(|c| c.to_string().parse::<u8>().unwrap())
-                            .collect::<Vec<_>>(),
-                        Class {
-                            code: code.clone(),
-                            name: #name.to_owned(),
-                            has_children: true,
-                        }
-                    );
-                };
-            }
-            );
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum Change {
+    Added {
+        code: String,
+        name: String,
+    },
+    Renamed {
+        code: String,
+        from: String,
+        to: String,
+    },
+    Removed {
+        code: String,
+        name: String,
+    },
+}
 
-            for class in children {
-                generate_class(output, *class);
-            }
+#[derive(Clone, Debug, Deserialize)]
+struct Release {
+    version: String,
+    changes: Vec<Change>,
+}
+
+fn generate_changelog() -> TokenStream {
+    let releases: Vec<Release> = serde_json::from_str(CHANGELOG_JSON).expect("Invalid changelog.json");
+    let items = releases.iter().flat_map(|release| {
+        let version = &release.version;
+        release.changes.iter().map(move |change| {
+            let change = match change {
+                Change::Added { code, name } => quote! { crate::changelog::DatasetChange::Added { code: #code, name: #name } },
+                Change::Renamed { code, from, to } =>
+                    quote! { crate::changelog::DatasetChange::Renamed { code: #code, from: #from, to: #to } },
+                Change::Removed { code, name } => quote! { crate::changelog::DatasetChange::Removed { code: #code, name: #name } },
+            };
+            quote! { crate::changelog::ChangelogEntry { version: #version, change: #change } }
+        })
+    });
+
+    quote! {
+        /// Changes to the bundled dataset, ordered by release
+        pub(crate) static CHANGELOG: &[crate::changelog::ChangelogEntry] = &[#(#items),*];
+    }
+}
+
+/// Whether the network is off-limits, through the `offline` feature or `DEWEY_OFFLINE` (ie `DEWEY_OFFLINE=1`)
+fn is_offline() -> bool {
+    has_feature("offline") ||
+        env::var("DEWEY_OFFLINE").is_ok_and(|value| !matches!(value.trim(), "" | "0" | "false"))
+}
+
+/// Reads an override of the data source from the environment, treating empty values as unset
+fn source_override(name: &str) -> Option<String> {
+    env::var(name).ok().map(|value| value.trim().to_string()).filter(|value| !value.is_empty())
+}
+
+/// Gets the source classes, along with the `DatasetSource` variant they came from
+///
+/// `DEWEY_SOURCE_FILE` takes precedence over everything else, including offline builds, and is required by the `data-ddc23-summaries` feature (without it, the vendored copy is embedded so that [missing_source_error] is the only error reported). `DEWEY_SOURCE_URL` replaces the OpenLibrary URL, and since it's an explicit choice, failing to fetch or parse it fails the build instead of falling back to the vendored copy. Offline builds ignore it.
+fn get_classes() -> (Vec<Class>, TokenStream) {
+    if let Some(path) = source_override("DEWEY_SOURCE_FILE") {
+        println!("cargo::rerun-if-changed={path}");
+        let json = fs::read_to_string(&path).unwrap_or_else(|error| panic!("Failed to read DEWEY_SOURCE_FILE {path:?}: {error}"));
+        let classes = serde_json::from_str(&json).unwrap_or_else(|error| panic!("Failed to parse DEWEY_SOURCE_FILE {path:?}: {error}"));
+        return (classes, quote! { File });
+    }
+
+    let pinned = source_override("DEWEY_SOURCE_URL");
+    if !is_offline() && !has_feature("data-ddc23-summaries") {
+        let url = pinned.as_deref().unwrap_or(SOURCE_URL);
+        let response = reqwest::blocking::get(url).and_then(|response| response.error_for_status());
+        match response.map(|response| response.json::<Vec<Class>>()) {
+            Ok(Ok(classes)) => return (classes, quote! { Network }),
+            Ok(Err(error)) | Err(error) if pinned.is_some() => panic!("Failed to load DEWEY_SOURCE_URL {url:?}: {error}"),
+            Ok(Err(error)) =>
+                println!("cargo::warning=OpenLibrary's dataset is in an unexpected format ({error}), embedding the vendored copy"),
+            Err(_) => {}
         }
-        Class::Leaf { name, short, .. } => {
-            let trimmed_code = short.trim_end_matches('X').to_string();
-            if trimmed_code.len() > 4 {
-                return;
+    }
+
+    (serde_json::from_str(FALLBACK_JSON).expect("Failed to deserialize fallback data."), quote! { Vendored })
+}
+
+/// Generates a `compile_error!` when the `data-ddc23-summaries` feature is enabled without `DEWEY_SOURCE_FILE`, as OCLC's summaries can't be redistributed with the crate
+fn missing_source_error() -> TokenStream {
+    if !has_feature("data-ddc23-summaries") || source_override("DEWEY_SOURCE_FILE").is_some() {
+        return TokenStream::new();
+    }
+
+    quote! {
+        compile_error!(
+            "The `data-ddc23-summaries` feature embeds OCLC's DDC 23 summaries, which can't be redistributed with this crate. \
+             Set `DEWEY_SOURCE_FILE` to a copy in the OpenLibrary JSON format (see the README)."
+        );
+    }
+}
+
+/// Reads `DEWEY_INCLUDE` (ie `5,6`), returning the main classes to embed, or [None] to embed all of them
+fn get_included() -> Option<Vec<char>> {
+    let value = env::var("DEWEY_INCLUDE").ok()?;
+    let included = value
+        .split(',')
+        .map(|item| item.trim())
+        .filter(|item| !item.is_empty())
+        .map(|item| {
+            let item = item.trim_end_matches('X');
+            match item.chars().collect::<Vec<_>>()[..] {
+                [digit] if digit.is_ascii_digit() => digit,
+                _ => panic!("DEWEY_INCLUDE entries must be main classes (0-9), got {item:?}"),
             }
-            output.push(
-                quote! {
-                {
-                    let code: String = #trimmed_code.to_owned();
-                    let _ = trie.insert(
-                        code
-                            .chars()
-                            .map(|c| c.to_string().parse::<u8>().unwrap())
-                            .collect::<Vec<_>>(),
-                        Class {
-                            code: code.clone(),
-                            name: #name.to_owned(),
-                            has_children: false,
-                        }
-                    );
-                };
+        })
+        .collect::<Vec<_>>();
+
+    if included.is_empty() { None } else { Some(included) }
+}
+
+/// Reads `DEWEY_MAX_DEPTH` (ie `2` to stop at sections), returning the depth of the deepest classes to embed, or [None] to embed everything the source has
+fn get_max_depth() -> Option<usize> {
+    let value = source_override("DEWEY_MAX_DEPTH")?;
+    Some(value.parse().unwrap_or_else(|_| panic!("DEWEY_MAX_DEPTH must be a depth (0 for main classes, 1 for divisions, etc), got {value:?}")))
+}
+
+fn is_included(class: &Class, included: &Option<Vec<char>>) -> bool {
+    let short = match class {
+        Class::Node { short, .. } | Class::Leaf { short, .. } => short,
+    };
+
+    match included {
+        Some(included) => short.chars().next().is_some_and(|c| included.contains(&c)),
+        None => true,
+    }
+}
+
+/// Generates a node of the raw tree, with its query & work count only when the `query` & `count` features are enabled
+fn generate_raw(class: &Class) -> TokenStream {
+    let (name, short, query, count) = match class {
+        Class::Node { name, short, query, count, .. } | Class::Leaf { name, short, query, count, .. } => (name, short, query, count),
+    };
+    let query = has_feature("query").then(|| quote! { query: #query, });
+    let count = has_feature("count").then(|| quote! { count: #count, });
+    let children = match class {
+        Class::Node { children, .. } => children.iter().map(generate_raw).collect(),
+        Class::Leaf { .. } => Vec::new(),
+    };
+    quote! {
+        RawNode {
+            name: #name,
+            short: #short,
+            #query
+            #count
+            children: &[#(#children),*],
+        }
+    }
+}
+
+fn collect_names(output: &mut Vec<(String, String)>, class: &Class) {
+    let Some(trimmed_code) = class.trimmed_code() else {
+        return;
+    };
+    let name = match class {
+        Class::Node { name, .. } | Class::Leaf { name, .. } => name,
+    };
+    output.push((fold(name), trimmed_code));
+
+    for child in class.embedded_children() {
+        collect_names(output, child);
+    }
+}
+
+/// Normalizes a class name for matching (kept in sync with `search::fold`)
+fn fold(text: &str) -> String {
+    let mut folded = String::with_capacity(text.len());
+    for c in text.nfkd().filter(|c| !is_combining_mark(*c)).flat_map(char::to_lowercase) {
+        match c {
+            '&' => folded.push_str(" and "),
+            'ß' => folded.push_str("ss"),
+            'æ' => folded.push_str("ae"),
+            'œ' => folded.push_str("oe"),
+            'ø' => folded.push('o'),
+            'ł' => folded.push('l'),
+            'đ' => folded.push('d'),
+            c => folded.push(c),
+        }
+    }
+    folded.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Words too common in class names to be worth indexing (kept in sync with `search::STOPWORDS`)
+const STOPWORDS: &[&str] = &["and", "for", "in", "of", "on", "the", "to", "with"];
+
+/// Markers of classes that aren't in use, which are left out of the keyword index (kept in sync with `search::UNUSED_MARKERS`)
+const UNUSED_MARKERS: &[&str] = &["unassigned", "not assigned", "no longer used"];
+
+fn generate_keywords(names: &[(String, String)]) -> Vec<TokenStream> {
+    let mut keywords: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for (name, code) in names {
+        if UNUSED_MARKERS.iter().any(|marker| name.contains(marker)) {
+            continue;
+        }
+
+        for token in name.split(|c: char| !c.is_alphanumeric()).filter(|token| !token.is_empty() && !STOPWORDS.contains(token)) {
+            let codes = keywords.entry(token.to_string()).or_default();
+            if !codes.contains(code) {
+                codes.push(code.clone());
             }
-            );
+        }
+    }
+
+    keywords
+        .into_iter()
+        .map(|(keyword, mut codes)| {
+            codes.sort();
+            quote! { (#keyword, &[#(#codes),*]) }
+        })
+        .collect()
+}
+
+fn collect_children(output: &mut Vec<(String, Vec<String>)>, class: &Class) {
+    let Some(trimmed_code) = class.trimmed_code() else {
+        return;
+    };
+    let children = class.embedded_children();
+    if !children.is_empty() {
+        output.push((trimmed_code, children.iter().filter_map(|child| child.trimmed_code()).collect()));
+    }
+
+    for child in children {
+        collect_children(output, child);
+    }
+}
+
+fn has_feature(name: &str) -> bool {
+    env::var_os(format!("CARGO_FEATURE_{}", name.to_uppercase().replace('-', "_"))).is_some()
+}
+
+fn generate_raw_metadata_fields() -> TokenStream {
+    let mut fields = Vec::new();
+    if has_feature("query") {
+        fields.push(quote! {
+            /// OpenLibrary query matching this node (ie `00*`, `[007 TO 009]`) (requires the `query` feature)
+            pub query: &'static str,
+        });
+    }
+    if has_feature("count") {
+        fields.push(quote! {
+            /// Number of works in this node, according to OpenLibrary (requires the `count` feature)
+            pub count: u64,
+        });
+    }
+    quote! { #(#fields)* }
+}
+
+fn generate_metadata_fields() -> TokenStream {
+    let mut fields = Vec::new();
+    if has_feature("count") {
+        fields.push(quote! {
+            /// Number of works in this class, according to OpenLibrary (requires the `count` feature)
+            pub work_count: u64,
+        });
+    }
+    if has_feature("query") {
+        fields.push(quote! {
+            /// OpenLibrary query matching this class (ie `00*`) (requires the `query` feature)
+            #[cfg_attr(feature = "rkyv", rkyv(with = rkyv::with::AsOwned))]
+            pub query: Cow<'static, str>,
+        });
+    }
+    if has_feature("notes") {
+        fields.push(quote! {
+            /// Scope notes of this class (ie what it includes, or where related topics are classed), from the source dataset or `notes.json` (requires the `notes` feature)
+            #[cfg_attr(feature = "rkyv", rkyv(with = rkyv::with::Map<rkyv::with::AsOwned>))]
+            pub notes: Option<Cow<'static, str>>,
+        });
+    }
+    quote! { #(#fields)* }
+}
+
+/// Gets the supplemental scope notes from `notes.json`, keyed by code (empty unless the `notes` feature is enabled, keeping the blob small)
+fn get_notes() -> BTreeMap<String, String> {
+    if !has_feature("notes") {
+        return BTreeMap::new();
+    }
+    serde_json::from_str(NOTES_JSON).expect("Invalid notes.json")
+}
+
+fn write_str(output: &mut Vec<u8>, value: &str) {
+    output.extend_from_slice(&(value.len() as u32).to_le_bytes());
+    output.extend_from_slice(value.as_bytes());
+}
+
+/// Appends the record of a class & its descendants to the class blob, see `src/blob.rs` for the layout
+///
+/// The work count & query are only written when the `count` & `query` features are enabled. Notes from the source dataset take precedence over the supplemental ones, and are only written when the `notes` feature is enabled.
+fn encode_class(output: &mut Vec<u8>, class: &Class, parent_code: Option<&str>, notes: &BTreeMap<String, String>) {
+    let Some(trimmed_code) = class.trimmed_code() else {
+        return;
+    };
+    let (name, query, count, source_notes) = match class {
+        Class::Node { name, query, count, notes, .. } | Class::Leaf { name, query, count, notes, .. } =>
+            (name, query, count, notes),
+    };
+    let class_notes = source_notes.as_ref().filter(|_| has_feature("notes")).or_else(|| notes.get(&trimmed_code));
+    let children = class.embedded_children();
+
+    write_str(output, &trimmed_code);
+    write_str(output, name);
+    write_str(output, parent_code.unwrap_or(""));
+    output.push(matches!(class, Class::Node { .. }) as u8);
+    output.extend_from_slice(&(children.len() as u32).to_le_bytes());
+    output.extend_from_slice(&(class.descendant_count() as u32).to_le_bytes());
+    if has_feature("count") {
+        output.extend_from_slice(&count.to_le_bytes());
+    }
+    if has_feature("query") {
+        write_str(output, query);
+    }
+    write_str(output, class_notes.map_or("", String::as_str));
+
+    if let Class::Node { children, .. } = class {
+        for child in children {
+            encode_class(output, child, Some(&trimmed_code), notes);
         }
     }
 }
 
 fn main() {
-    let classes = get_classes();
+    let included = get_included();
+    let max_depth = get_max_depth();
+    let (classes, source) = get_classes();
+    let classes = classes
+        .into_iter()
+        .filter(|class| is_included(class, &included))
+        .map(|class| match max_depth {
+            Some(max_depth) => class.prune(max_depth),
+            None => class,
+        })
+        .collect::<Vec<_>>();
+
+    let raw_items = classes.iter().map(generate_raw).collect::<Vec<_>>();
+
+    let mut names: Vec<(String, String)> = Vec::new();
+    for class in &classes {
+        collect_names(&mut names, class);
+    }
+    names.sort();
+    let name_items = names.iter().map(|(name, code)| quote! { (#name, #code) });
+    let keyword_items = generate_keywords(&names);
 
-    let mut class_items: Vec<TokenStream> = Vec::new();
+    let mut children: Vec<(String, Vec<String>)> = vec![(
+        String::new(),
+        classes.iter().filter_map(|class| class.trimmed_code()).collect(),
+    )];
+    for class in &classes {
+        collect_children(&mut children, class);
+    }
+    let child_items = children.iter().map(|(parent, codes)| quote! { (#parent, &[#(#codes),*]) });
 
-    for class in classes {
-        generate_class(&mut class_items, class);
+    let notes = get_notes();
+    let mut blob = Vec::new();
+    for class in &classes {
+        encode_class(&mut blob, class, None, &notes);
     }
 
+    let metadata_fields = generate_metadata_fields();
+    let raw_metadata_fields = generate_raw_metadata_fields();
+    let changelog = generate_changelog();
+    let missing_source = missing_source_error();
+
     let output =
         quote! {
+        #missing_source
+
         /// Representation of a single Dewey Decimal class
+        ///
+        /// Fields are declared in a fixed order (`code`, `name`, `has_children`, `child_count`, `descendant_count`, `parent_code`, then `work_count`, `query` & `notes` when enabled), which the positional `bincode` & `borsh` encodings rely on. New fields are only ever appended, and changing the enabled metadata features changes the encoding.
+        ///
+        /// Strings are borrowed from the embedded dataset (or from a loaded one, which lives for the rest of the program), so cloning a class doesn't allocate.
         #[derive(Clone, Debug)]
-        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+        #[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
         #[cfg_attr(feature = "specta", derive(specta::Type))]
         #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+        #[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
         #[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+        #[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+        #[cfg_attr(feature = "bincode", derive(bincode::Encode))]
+        #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
         pub struct Class {
-            /// DDC code of this class (ie `001`, `24`, etc)
-            pub code: String,
+            /// DDC code of this class (ie `001`, `24`, `813.54`, etc), serialized in the crate-wide `CodeStyle`
+            #[cfg_attr(feature = "serde", serde(serialize_with = "crate::style::serialize_code"))]
+            #[cfg_attr(feature = "rkyv", rkyv(with = rkyv::with::AsOwned))]
+            pub code: Cow<'static, str>,
 
             /// Friendly name of this class
-            pub name: String,
+            #[cfg_attr(feature = "rkyv", rkyv(with = rkyv::with::AsOwned))]
+            pub name: Cow<'static, str>,
 
             /// Whether this class has children
-            pub has_children: bool
+            pub has_children: bool,
+
+            /// Number of direct children of this class
+            pub child_count: usize,
+
+            /// Total number of descendants of this class (children, grandchildren, etc)
+            pub descendant_count: usize,
+
+            /// DDC code of the parent class, taken from the source hierarchy
+            #[cfg_attr(feature = "serde", serde(serialize_with = "crate::style::serialize_parent_code"))]
+            #[cfg_attr(feature = "rkyv", rkyv(with = rkyv::with::Map<rkyv::with::AsOwned>))]
+            parent_code: Option<Cow<'static, str>>,
+
+            #metadata_fields
         }
 
-        pub(crate) fn make_class_static() -> trie_rs::map::Trie<u8, Class> {
-            let mut trie = trie_rs::map::TrieBuilder::new();
+        /// Node of the source dataset, mirroring the original JSON structure
+        #[derive(Clone, Copy, Debug)]
+        #[cfg_attr(feature = "serde", derive(::serde::Serialize))]
+        pub struct RawNode {
+            /// Friendly name of this node
+            pub name: &'static str,
+
+            /// Untrimmed code of this node (ie `0XX`, `00X`, `007–009`)
+            pub short: &'static str,
 
-            #(#class_items)*
+            #raw_metadata_fields
 
-            trie.build()
+            /// Child nodes
+            pub children: &'static [RawNode]
         }
+
+        pub(crate) static RAW_TREE: &[RawNode] = &[#(#raw_items),*];
+
+        /// Codes of each class's direct children, keyed by parent code (main classes are keyed by an empty string)
+        pub(crate) static CHILD_CODES: &[(&str, &[&str])] = &[#(#child_items),*];
+
+        /// Lowercased class names & their codes, sorted by name
+        pub(crate) static NAME_INDEX: &[(&str, &str)] = &[#(#name_items),*];
+
+        /// Lowercased keywords of class names & the codes of the classes using them, sorted by keyword
+        pub(crate) static KEYWORD_INDEX: &[(&str, &[&str])] = &[#(#keyword_items),*];
+
+        /// Where the embedded dataset was taken from at build time
+        pub(crate) static EMBEDDED_SOURCE: crate::DatasetSource = crate::DatasetSource::#source;
+
+        #changelog
+
+        /// Records of the embedded classes in shelf order, compressed with DEFLATE (decoded by `blob::embedded_classes`)
+        pub(crate) static CLASS_BLOB: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/classes.bin"));
     };
 
     let str_out = prettyplease::unparse(&syn::parse2::<File>(output).unwrap());
     let out_dir = env::var_os("OUT_DIR").unwrap();
     let dest_path = Path::new(&out_dir).join("classes.rs");
     fs::write(&dest_path, str_out).unwrap();
+    fs::write(Path::new(&out_dir).join("classes.bin"), miniz_oxide::deflate::compress_to_vec(&blob, 10)).unwrap();
 
     println!("cargo::rerun-if-changed=fallback.json");
+    println!("cargo::rerun-if-changed=changelog.json");
+    println!("cargo::rerun-if-changed=notes.json");
+    println!("cargo::rerun-if-env-changed=DEWEY_INCLUDE");
+    println!("cargo::rerun-if-env-changed=DEWEY_MAX_DEPTH");
+    println!("cargo::rerun-if-env-changed=DEWEY_OFFLINE");
+    println!("cargo::rerun-if-env-changed=DEWEY_SOURCE_FILE");
+    println!("cargo::rerun-if-env-changed=DEWEY_SOURCE_URL");
 }
@@ -0,0 +1,116 @@
+#ifndef DEWEY_DECIMAL_H
+#define DEWEY_DECIMAL_H
+
+/* Generated with cbindgen from src/ffi.rs, do not edit by hand */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// Class returned over the C API
+typedef struct DeweyClass {
+  // Code of the class (ie `516`)
+  char *code;
+  // Name of the class
+  char *name;
+  // Code of the parent class, or `NULL` for main classes
+  char *parent_code;
+} DeweyClass;
+
+// Array of classes returned over the C API
+typedef struct DeweyClassList {
+  // Pointer to the first class, or `NULL` if the list is empty
+  struct DeweyClass *classes;
+  // Number of classes in the list
+  size_t len;
+} DeweyClassList;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Gets a class by code
+//
+// # Safety
+//
+// `code` must be `NULL`, or a valid NUL-terminated string. The returned class must be released with [dewey_class_free].
+//
+// # Arguments
+//
+// - `code` (`*const c_char`) - Code of the class (ie `516`)
+//
+// # Returns
+//
+// - `*mut DeweyClass` - The class, or `NULL` if the code is `NULL`, isn't UTF-8, or doesn't exist
+struct DeweyClass *dewey_get_class(const char *code);
+
+// Gets the most specific class a code or call number falls under, see [Dewey::classify]
+//
+// # Safety
+//
+// `code` must be `NULL`, or a valid NUL-terminated string. The returned class must be released with [dewey_class_free].
+//
+// # Arguments
+//
+// - `code` (`*const c_char`) - Code or call number to classify (ie `813.54 K37`)
+//
+// # Returns
+//
+// - `*mut DeweyClass` - The class, or `NULL` if the code is `NULL`, isn't UTF-8, or doesn't start with a known class
+struct DeweyClass *dewey_classify(const char *code);
+
+// Gets the direct children of a class
+//
+// # Safety
+//
+// `code` must be `NULL`, or a valid NUL-terminated string. The returned list must be released with [dewey_class_list_free].
+//
+// # Arguments
+//
+// - `code` (`*const c_char`) - Code of the parent class, or an empty string for the main classes
+//
+// # Returns
+//
+// - `DeweyClassList` - The children, which is empty if the code is `NULL`, isn't UTF-8, or has no children
+struct DeweyClassList dewey_children(const char *code);
+
+// Releases a string returned by this API
+//
+// # Safety
+//
+// `value` must be `NULL`, or a string returned by this API that hasn't been released yet
+//
+// # Arguments
+//
+// - `value` (`*mut c_char`) - String to release
+void dewey_string_free(char *value);
+
+// Releases a class returned by [dewey_get_class] or [dewey_classify]
+//
+// # Safety
+//
+// `class` must be `NULL`, or a class returned by this API that hasn't been released yet
+//
+// # Arguments
+//
+// - `class` (`*mut DeweyClass`) - Class to release
+void dewey_class_free(struct DeweyClass *class_);
+
+// Releases a list returned by [dewey_children]
+//
+// # Safety
+//
+// `list` must have been returned by this API, and not released yet
+//
+// # Arguments
+//
+// - `list` (`DeweyClassList`) - List to release
+void dewey_class_list_free(struct DeweyClassList list);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* DEWEY_DECIMAL_H */
@@ -0,0 +1,106 @@
+//! Compile-time validated Dewey Decimal class codes for [dewey-decimal](https://crates.io/crates/dewey-decimal)
+//!
+//! ```rust
+//! use dewey_decimal::Class;
+//! use dewey_decimal_macros::dewey;
+//!
+//! const FICTION: Class = dewey!("813");
+//! assert_eq!(FICTION.name, "American fiction in English");
+//! ```
+//!
+//! Unknown or malformed codes fail the build:
+//!
+//! ```rust,compile_fail
+//! use dewey_decimal_macros::dewey;
+//!
+//! let typo = dewey!("81a");
+//! ```
+
+use dewey_decimal::{ DdcCode, Dewey };
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{ parse::{ Parse, ParseStream }, LitStr };
+
+/// Code passed to [dewey!]
+struct CodeInput(LitStr);
+
+impl Parse for CodeInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let code = input.parse()?;
+        if !input.is_empty() {
+            return Err(input.error("expected a single string literal, ie dewey!(\"813\")"));
+        }
+        Ok(Self(code))
+    }
+}
+
+/// Validates a code against the embedded dataset, and expands to a constant of the matching class
+///
+/// The class is copied field by field into the expansion, so it never has to be looked up again at runtime (where the dataset may have been built differently).
+fn expand(input: TokenStream2) -> syn::Result<TokenStream2> {
+    let CodeInput(literal) = syn::parse2(input)?;
+    let code = DdcCode::new(literal.value()).map_err(|error| syn::Error::new(literal.span(), format!("{error}")))?;
+    let class = Dewey::standard()
+        .class_ref(&code)
+        .ok_or_else(|| syn::Error::new(literal.span(), format!("no DDC class with code {code}")))?;
+
+    let (code, name) = (class.code.as_ref(), class.name.as_ref());
+    let (has_children, child_count, descendant_count) = (class.has_children, class.child_count, class.descendant_count);
+    let parent_code = option(class.parent_code());
+    let (work_count, query, notes) = (class.work_count, class.query.as_ref(), option(class.notes.as_deref()));
+    Ok(quote! {
+        ::dewey_decimal::Class::__constant(
+            #code,
+            #name,
+            #has_children,
+            #child_count,
+            #descendant_count,
+            #parent_code,
+            #work_count,
+            #query,
+            #notes
+        )
+    })
+}
+
+/// Expands an optional string into an `Option<&'static str>` expression
+fn option(value: Option<&str>) -> TokenStream2 {
+    match value {
+        Some(value) => quote! { ::core::option::Option::Some(#value) },
+        None => quote! { ::core::option::Option::None },
+    }
+}
+
+/// Gets a class by code, validating the code against the embedded dataset at compile time
+///
+/// Codes are written as in [Dewey::get_class] (ie `"813"`, `"51X"` or `"516"`). Malformed codes and codes without a class are compile errors. The class is expanded into a constant expression, so it can be used in `const` items & never fails at runtime.
+///
+/// # Arguments
+///
+/// - `code` (string literal) - Code of the class
+///
+/// # Returns
+///
+/// - `Class` - The class, as found in [Dewey::standard] at compile time
+#[proc_macro]
+pub fn dewey(input: TokenStream) -> TokenStream {
+    expand(input.into()).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_expand() {
+        let expanded = expand(quote! { "51X" }).unwrap().to_string();
+        assert!(expanded.starts_with(":: dewey_decimal :: Class :: __constant (\"51\" , \"Mathematics\""), "Expected the trimmed code, got {expanded}");
+        assert!(expanded.contains("Some (\"5\")") && !expanded.contains("expect"), "Unexpected expansion: {expanded}");
+
+        let error = expand(quote! { "81a" }).unwrap_err().to_string();
+        assert!(error.contains("invalid character 'a'"), "Unexpected error: {error}");
+        assert_eq!(expand(quote! { "008" }).unwrap_err().to_string(), "no DDC class with code 008");
+        assert!(expand(quote! { 813 }).is_err() && expand(quote! { "813", "814" }).is_err());
+    }
+}
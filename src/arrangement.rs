@@ -0,0 +1,199 @@
+use crate::{ dataset::{ Diagnostic, Severity }, Dewey };
+
+/// Where biographies are classed
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum BiographyArrangement {
+    /// Biographies are collected in `920`–`928`
+    #[default]
+    Collected,
+
+    /// Biographies are classed with the subject they're associated with, leaving `920`–`928` unused
+    WithSubject,
+}
+
+/// Where works of fiction are classed
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum FictionArrangement {
+    /// Fiction is classed by literature (ie `813` for American fiction)
+    #[default]
+    ByLiterature,
+
+    /// Fiction is shelved under the `FIC` pseudo-class (see [crate::PseudoClass])
+    PseudoClass,
+}
+
+/// How law is arranged
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum LawArrangement {
+    /// Law is classed by branch (`342`–`347`)
+    #[default]
+    ByBranch,
+
+    /// Law is classed by jurisdiction first, in `349`
+    ByJurisdiction,
+}
+
+/// Selection of DDC's optional arrangements in use by an installation
+///
+/// The defaults follow the standard (preferred) arrangements. Policies are kept per dataset (see [Dewey::set_arrangement_policies]), and classes they leave unused aren't proposed by [Dewey::suggest_from_text], [Dewey::suggest_for_text_with], [Dewey::correct] or [Dewey::nearest_assigned].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct ArrangementPolicies {
+    /// Where biographies are classed
+    pub biography: BiographyArrangement,
+
+    /// Where works of fiction are classed
+    pub fiction: FictionArrangement,
+
+    /// How law is arranged
+    pub law: LawArrangement,
+}
+
+impl ArrangementPolicies {
+    /// Checks a code against these policies
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`&str`) - Code to check (ie `813`)
+    ///
+    /// # Returns
+    ///
+    /// - `Option<(String, Option<String>)>` - [None] if the code is allowed, otherwise the reason and the preferred number, if there's a single one
+    fn check(&self, code: &str) -> Option<(String, Option<String>)> {
+        let digits = code.as_bytes();
+        let section = code.get(..3).and_then(|section| section.parse::<u16>().ok())?;
+
+        if self.biography == BiographyArrangement::WithSubject && (920..=928).contains(&section) {
+            return Some((format!("biographies are classed with their subject, so {section} is unused"), None));
+        }
+        if self.fiction == FictionArrangement::PseudoClass && digits[0] == b'8' && digits[1] != b'0' && digits[2] == b'3' {
+            return Some((format!("fiction is shelved under FIC rather than {section}"), Some("FIC".to_string())));
+        }
+        if self.law == LawArrangement::ByJurisdiction && (342..=347).contains(&section) {
+            return Some((format!("law is classed by jurisdiction, so {section} is unused"), Some("349".to_string())));
+        }
+        None
+    }
+}
+
+impl Dewey {
+    /// Gets the arrangement policies of this dataset
+    ///
+    /// # Returns
+    ///
+    /// - `ArrangementPolicies` - The current [ArrangementPolicies] (the standard arrangements unless changed)
+    pub fn arrangement_policies(&self) -> ArrangementPolicies {
+        *self.dataset().policies.read().unwrap()
+    }
+
+    /// Replaces the arrangement policies of this dataset
+    ///
+    /// Policies belong to the dataset rather than the handle, so they're shared by every handle to it (ie by all [Dewey::standard] handles), but datasets loaded with a [DeweyBuilder](crate::DeweyBuilder) keep their own.
+    ///
+    /// # Arguments
+    ///
+    /// - `policies` (`ArrangementPolicies`) - The new policies
+    pub fn set_arrangement_policies(&self, policies: ArrangementPolicies) {
+        *self.dataset().policies.write().unwrap() = policies;
+    }
+
+    /// Checks whether the arrangement policies of this dataset use a code, see [Dewey::check_arrangement]
+    pub(crate) fn is_arranged(&self, code: &str) -> bool {
+        self.arrangement_policies().check(code).is_none()
+    }
+
+    /// Gets the number preferred over a code by the arrangement policies of this dataset
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code to check (ie `813`)
+    ///
+    /// # Returns
+    ///
+    /// - `Option<String>` - The preferred number or shelving mark (ie `FIC`), or [None] if the code is allowed or there's no single preferred number
+    pub fn preferred_code(&self, code: impl AsRef<str>) -> Option<String> {
+        self.arrangement_policies().check(code.as_ref()).and_then(|(_, preferred)| preferred)
+    }
+
+    /// Validates codes assigned to items against the arrangement policies of this dataset
+    ///
+    /// Every code that's unused under the current policies produces a `W100` warning, suggesting the preferred number if there is one.
+    ///
+    /// # Arguments
+    ///
+    /// - `codes` (`impl IntoIterator<Item = impl AsRef<str>>`) - Codes assigned to items
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<Diagnostic>` - A [Diagnostic] for every code that's unused under the current policies
+    pub fn check_arrangement(&self, codes: impl IntoIterator<Item = impl AsRef<str>>) -> Vec<Diagnostic> {
+        let policies = self.arrangement_policies();
+        codes
+            .into_iter()
+            .filter_map(|code| {
+                let code = code.as_ref();
+                let (reason, preferred) = policies.check(code)?;
+                Some(Diagnostic {
+                    severity: Severity::Warning,
+                    rule: "W100",
+                    code: Some(code.to_string()),
+                    message: format!("{code} isn't used by this installation: {reason}"),
+                    suggestion: Some(match preferred {
+                        Some(preferred) => format!("use {preferred}"),
+                        None => "class with the subject".to_string(),
+                    }),
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_policies() {
+        let standard = ArrangementPolicies::default();
+        assert!(["813", "921", "345"].iter().all(|code| standard.check(code).is_none()));
+
+        let custom = ArrangementPolicies {
+            biography: BiographyArrangement::WithSubject,
+            fiction: FictionArrangement::PseudoClass,
+            law: LawArrangement::ByJurisdiction,
+        };
+        assert_eq!(custom.check("813").unwrap().1.as_deref(), Some("FIC"));
+        assert_eq!(custom.check("345").unwrap().1.as_deref(), Some("349"));
+        assert_eq!(custom.check("921").unwrap().1, None);
+        assert!(["810", "803", "929", "349", "5", "51"].iter().all(|code| custom.check(code).is_none()));
+
+        assert!(Dewey::standard().check_arrangement(["813", "921"]).is_empty(), "Expected the standard arrangements");
+    }
+
+    #[test]
+    fn test_applied_policies() {
+        let dewey = Dewey::builder().include('0'..='9').build().unwrap();
+        dewey.set_arrangement_policies(ArrangementPolicies {
+            biography: BiographyArrangement::WithSubject,
+            fiction: FictionArrangement::PseudoClass,
+            law: LawArrangement::ByJurisdiction,
+        });
+        assert_eq!(Dewey::standard().arrangement_policies(), ArrangementPolicies::default(), "Expected policies to stay with their dataset");
+
+        assert_eq!(dewey.nearest_assigned("813").unwrap().code, "81", "Expected unused classes to be skipped");
+        assert_eq!(Dewey::standard().nearest_assigned("813").unwrap().code, "813");
+        assert!(dewey.correct("B13").iter().all(|correction| correction.class.code != "813"));
+        assert!(dewey.suggest_from_text("American fiction").iter().all(|suggestion| dewey.is_arranged(&suggestion.class.code)));
+        assert!(dewey.suggest_from_text("Criminal law").iter().all(|suggestion| suggestion.class.code != "345"));
+    }
+}
@@ -0,0 +1,160 @@
+use std::fmt;
+
+use crate::{ dataset, lenient_code, search, Class, DdcCode, Dewey, DeweyError };
+
+/// Run of consecutive unassigned codes sharing a parent (ie `007`–`009`), see [Dewey::gaps]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct CodeGap {
+    /// First unassigned code of the run
+    pub first: String,
+
+    /// Last unassigned code of the run (the same as `first` for single codes)
+    pub last: String,
+}
+
+/// Gaps display like the ranges of the source dataset (ie `007–009`, or `04` for single codes)
+impl fmt::Display for CodeGap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.first == self.last { write!(f, "{}", self.first) } else { write!(f, "{}–{}", self.first, self.last) }
+    }
+}
+
+/// Gets the codes one digit longer than a code, in shelf order (ie `000` through `009` for `00`, none past sections)
+fn subdivisions(code: &str) -> impl Iterator<Item = String> + use<> {
+    let code = code.to_string();
+    (code.len() < 3).then(|| (0..10).map(move |digit| format!("{code}{digit}"))).into_iter().flatten()
+}
+
+/// Checks whether a code directly follows another under the same parent (ie `008` after `007`)
+fn follows(code: &str, previous: &str) -> bool {
+    let (Some(last), Some(previous_last)) = (code.chars().last(), previous.chars().last()) else {
+        return false;
+    };
+    code.len() == previous.len() &&
+        code[..code.len() - 1] == previous[..previous.len() - 1] &&
+        last.to_digit(10).zip(previous_last.to_digit(10)).is_some_and(|(last, previous)| last == previous + 1)
+}
+
+impl Dewey {
+    /// Checks whether a code belongs to a class in use
+    ///
+    /// Codes without a class (ie `008`) and classes marked as unassigned or no longer used (ie `009 [Unassigned]` or `217 No longer used—formerly Prayer`) aren't assigned.
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code to check
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether the code has a class that is in use
+    pub fn is_assigned(&self, code: impl AsRef<str>) -> bool {
+        self.class_ref(code).is_some_and(|class| !search::is_unused(&class.name))
+    }
+
+    /// Gets the closest assigned class of a code, walking up from the code itself, ie to repair records pointing at dead numbers
+    ///
+    /// ```rust
+    /// use dewey_decimal::{ Dewey, DeweyError };
+    ///
+    /// let dewey = Dewey::standard();
+    /// assert_eq!(dewey.nearest_assigned("008").unwrap().code, "00");
+    /// assert_eq!(dewey.nearest_assigned("217").unwrap().code, "21");
+    /// assert!(matches!(dewey.nearest_assigned("8a3"), Err(DeweyError::InvalidCode(_))));
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code to resolve, cleaned up like in [Dewey::get_class]
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Class, DeweyError>` - The class itself if it's assigned (see [Dewey::is_assigned]) & used by the arrangement policies (see [Dewey::arrangement_policies]), otherwise its closest such ancestor, or an error if the code is invalid or nothing above it is assigned
+    pub fn nearest_assigned(&self, code: impl AsRef<str>) -> Result<Class, DeweyError> {
+        let code = DdcCode::parse_lenient(code)?;
+        std::iter::once(code.as_str())
+            .chain(dataset::prefixes(code.as_str()).rev())
+            .find(|prefix| self.is_assigned(prefix) && self.is_arranged(prefix))
+            .and_then(|prefix| self.get_class(prefix))
+            .ok_or_else(|| DeweyError::NotFound(code.to_string()))
+    }
+
+    fn collect_unassigned(&self, code: &str, output: &mut Vec<String>) {
+        for subdivision in subdivisions(code) {
+            if self.is_assigned(&subdivision) {
+                self.collect_unassigned(&subdivision, output);
+            } else {
+                output.push(subdivision);
+            }
+        }
+    }
+
+    /// Gets the codes under a prefix that aren't assigned (see [Dewey::is_assigned]), down to sections, ie `008` under `00`
+    ///
+    /// Only the topmost unassigned code of a span is reported, so an unassigned division (ie `04`) stands for all of its sections.
+    ///
+    /// # Arguments
+    ///
+    /// - `prefix` (`impl AsRef<str>`) - Code of the span to check (an empty string checks the entire scheme)
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<String>` - [Vec] of unassigned codes, in shelf order
+    pub fn unassigned_under(&self, prefix: impl AsRef<str>) -> Vec<String> {
+        let mut unassigned = Vec::new();
+        self.collect_unassigned(&lenient_code(prefix.as_ref()), &mut unassigned);
+        unassigned
+    }
+
+    /// Reports every gap in the scheme, merging consecutive unassigned codes (see [Dewey::unassigned_under])
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<CodeGap>` - [Vec] of [CodeGap]s, in shelf order
+    pub fn gaps(&self) -> Vec<CodeGap> {
+        let mut gaps: Vec<CodeGap> = Vec::new();
+        for code in self.unassigned_under("") {
+            match gaps.last_mut() {
+                Some(gap) if follows(&code, &gap.last) => gap.last = code,
+                _ => gaps.push(CodeGap { first: code.clone(), last: code }),
+            }
+        }
+        gaps
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_assigned() {
+        let dewey = Dewey::standard();
+        assert!(dewey.is_assigned("516") && dewey.is_assigned("51X"));
+        assert!(!dewey.is_assigned("008") && !dewey.is_assigned("009") && !dewey.is_assigned("abc"), "Expected unassigned codes");
+
+        assert_eq!(dewey.nearest_assigned("516").unwrap().code, "516", "Expected assigned classes to resolve to themselves");
+        assert_eq!(dewey.nearest_assigned("009.5").unwrap().code, "00");
+        assert_eq!(dewey.nearest_assigned("775").unwrap().code, "77");
+        assert!(matches!(dewey.nearest_assigned(""), Err(DeweyError::InvalidCode(_))), "Expected garbage to be told apart");
+    }
+
+    #[test]
+    fn test_unassigned_under() {
+        let dewey = Dewey::standard();
+        let unassigned = dewey.unassigned_under("00");
+        assert!(unassigned.contains(&"008".to_string()) && unassigned.contains(&"009".to_string()));
+        assert!(unassigned.iter().all(|code| code.starts_with("00") && !dewey.is_assigned(code)));
+        assert!(dewey.unassigned_under("0XX").contains(&"04".to_string()), "Expected unassigned divisions");
+        assert!(!dewey.unassigned_under("0").iter().any(|code| code.starts_with("04") && code != "04"), "Expected divisions to stand for their sections");
+        assert!(dewey.unassigned_under("516").is_empty());
+
+        let gaps = dewey.gaps();
+        assert!(gaps.iter().any(|gap| gap.to_string() == "007–009"), "Expected consecutive codes to be merged");
+        assert!(gaps.iter().any(|gap| gap.to_string() == "04"));
+        let span = |gap: &CodeGap| gap.last.chars().last().unwrap() as usize - gap.first.chars().last().unwrap() as usize + 1;
+        assert_eq!(gaps.iter().map(span).sum::<usize>(), dewey.unassigned_under("").len(), "Expected every unassigned code in a gap");
+    }
+}
@@ -0,0 +1,91 @@
+use crate::{ Class, Dewey };
+
+/// Collection a class is commonly shelved in
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum Audience {
+    /// Commonly used in juvenile (children's & young adult) collections
+    Juvenile,
+
+    /// Commonly restricted to adult collections
+    Adult,
+}
+
+/// Audience hints by code prefix, following common school & public library practice
+///
+/// The most specific matching prefix applies, so subclasses can override their ancestors.
+const AUDIENCE_HINTS: &[(&str, Audience)] = &[
+    ("133", Audience::Adult),
+    ("176", Audience::Adult),
+    ("306", Audience::Adult),
+    ("34", Audience::Adult),
+    ("364", Audience::Adult),
+    ("398", Audience::Juvenile),
+    ("59", Audience::Juvenile),
+    ("616", Audience::Adult),
+    ("636", Audience::Juvenile),
+    ("65", Audience::Adult),
+    ("741", Audience::Juvenile),
+    ("793", Audience::Juvenile),
+    ("794", Audience::Juvenile),
+    ("796", Audience::Juvenile),
+];
+
+fn audience_hint(code: &str) -> Option<Audience> {
+    AUDIENCE_HINTS.iter()
+        .filter(|(prefix, _)| code.starts_with(prefix))
+        .max_by_key(|(prefix, _)| prefix.len())
+        .map(|(_, audience)| *audience)
+}
+
+impl Dewey {
+    /// Gets the classes suitable for an audience, in shelf order
+    ///
+    /// Classes hinted for a different audience are excluded, while classes without a hint are kept (see [Class::audience_hint]).
+    ///
+    /// # Arguments
+    ///
+    /// - `audience` (`Audience`) - Audience to filter for
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<Class>` - [Vec] of [Class] instances that aren't hinted for a different audience
+    pub fn classes_for_audience(&self, audience: Audience) -> Vec<Class> {
+        self.all()
+            .into_iter()
+            .filter(|class| class.audience_hint().is_none_or(|hint| hint == audience))
+            .collect()
+    }
+}
+
+impl Class {
+    /// Gets the collection this class is commonly shelved in, if it's typical of one
+    ///
+    /// Hints are a curated heuristic for filtering the hierarchy (ie to age-appropriate shelves), not part of the DDC itself.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Audience>` - The hinted [Audience], or [None] if the class is common to all collections
+    pub fn audience_hint(&self) -> Option<Audience> {
+        audience_hint(&self.code)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_audience_hint() {
+        let dewey = Dewey::standard();
+        assert_eq!(dewey.get_class("398").unwrap().audience_hint(), Some(Audience::Juvenile));
+        assert_eq!(dewey.get_class("345").unwrap().audience_hint(), Some(Audience::Adult));
+        assert_eq!(dewey.get_class("51").unwrap().audience_hint(), None);
+
+        let juvenile = dewey.classes_for_audience(Audience::Juvenile);
+        assert!(juvenile.iter().any(|class| class.code == "796") && juvenile.iter().any(|class| class.code == "51"));
+        assert!(!juvenile.iter().any(|class| class.code == "616"), "Expected adult classes to be excluded");
+    }
+}
@@ -0,0 +1,282 @@
+//! Incremental type-ahead over class codes, walking the trie one digit at a time
+
+use std::cmp::Reverse;
+
+use trie_rs::inc_search::IncSearch;
+
+use crate::{ Class, Dewey };
+
+/// Default ranking weight of a suggestion: its OpenLibrary work count with the `count` feature, otherwise its number of descendants
+fn default_weight(class: &Class) -> u64 {
+    #[cfg(feature = "count")]
+    return class.work_count;
+
+    #[cfg(not(feature = "count"))]
+    return class.descendant_count as u64;
+}
+
+/// Keeps the `limit` heaviest classes, heaviest first, breaking ties in shelf order
+fn rank<W: Ord>(classes: impl Iterator<Item = &'static Class>, limit: usize, weight: impl Fn(&Class) -> W) -> Vec<Class> {
+    let mut classes = classes.collect::<Vec<_>>();
+    classes.sort_by_key(|class| Reverse(weight(class)));
+    classes.into_iter().take(limit).cloned().collect()
+}
+
+/// Incremental search over class codes, created by [Dewey::autocomplete]
+///
+/// Each digit advances the search by one trie node, so keystrokes don't repeat the lookup of the whole prefix.
+///
+/// ```rust
+/// use dewey_decimal::Dewey;
+///
+/// let mut search = Dewey::standard().autocomplete();
+/// assert!(search.push('5') && search.push('1'));
+/// assert_eq!(search.class().unwrap().name, "Mathematics");
+/// assert_eq!(search.candidates().count(), Dewey::standard().get_matches("51").len());
+/// ```
+#[derive(Clone, Debug)]
+pub struct Autocomplete {
+    dewey: Dewey,
+    search: IncSearch<'static, u8, Class>,
+    input: String,
+    label: Vec<u8>,
+    matched: bool,
+}
+
+impl Autocomplete {
+    /// Feeds the next character of the code
+    ///
+    /// The decimal point after the third digit is accepted without moving the search. Any other character that isn't a digit, and any digit that no class continues with, leaves the search without candidates until it's [popped](Autocomplete::pop).
+    ///
+    /// # Arguments
+    ///
+    /// - `character` (`char`) - Character typed
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether any class still matches the input
+    pub fn push(&mut self, character: char) -> bool {
+        self.input.push(character);
+        if character == '.' && self.input.len() == 4 {
+            return self.matched;
+        }
+
+        let digit = character.to_digit(10).map_or(u8::MAX, |digit| digit as u8);
+        self.label.push(digit);
+        self.matched = self.matched && self.search.query(&digit).is_some();
+        self.matched
+    }
+
+    /// Feeds several characters, see [Autocomplete::push]
+    ///
+    /// # Arguments
+    ///
+    /// - `characters` (`&str`) - Characters typed
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether any class still matches the input
+    pub fn push_str(&mut self, characters: &str) -> bool {
+        characters.chars().for_each(|character| {
+            self.push(character);
+        });
+        self.matched
+    }
+
+    /// Removes the last character of the input, ie on backspace
+    ///
+    /// # Returns
+    ///
+    /// - `Option<char>` - The removed character, or [None] if the input was empty
+    pub fn pop(&mut self) -> Option<char> {
+        let character = self.input.pop()?;
+        if character == '.' && self.input.len() == 3 {
+            return Some(character);
+        }
+
+        self.label.pop();
+        self.search.reset();
+        self.matched = self.search.query_until(&self.label).is_ok();
+        Some(character)
+    }
+
+    /// Clears the input
+    pub fn reset(&mut self) {
+        self.search.reset();
+        self.input.clear();
+        self.label.clear();
+        self.matched = true;
+    }
+
+    /// Gets the characters fed so far
+    ///
+    /// # Returns
+    ///
+    /// - `&str` - The current input
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    /// Checks whether any class matches the input
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - `true` if at least one class starts with the input (always `true` for an empty input)
+    pub fn has_candidates(&self) -> bool {
+        self.matched
+    }
+
+    /// Gets the class whose code is exactly the input
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&'static Class>` - The [Class], or [None] if the input isn't itself a class
+    pub fn class(&self) -> Option<&'static Class> {
+        self.matched.then(|| self.search.value()).flatten()
+    }
+
+    /// Checks whether the input is itself a class
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - `true` if [Autocomplete::class] would return a class
+    pub fn is_class(&self) -> bool {
+        self.class().is_some()
+    }
+
+    /// Iterates over the classes starting with the input
+    ///
+    /// # Returns
+    ///
+    /// - `impl Iterator<Item = &'static Class>` - Iterator over the candidate [Class] instances in shelf order (including the input's own class), empty if nothing matches
+    pub fn candidates(&self) -> impl Iterator<Item = &'static Class> + use<> {
+        let label = if self.matched { self.label.clone() } else { vec![u8::MAX] };
+        self.dewey
+            .classes()
+            .predictive_search(label)
+            .map(|item: (Vec<u8>, &'static Class)| item.1)
+    }
+
+    /// Gets the best candidates for a suggestion dropdown, see [Dewey::suggest]
+    ///
+    /// # Arguments
+    ///
+    /// - `limit` (`usize`) - Maximum number of suggestions
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<Class>` - Up to `limit` candidates, heaviest first
+    pub fn ranked(&self, limit: usize) -> Vec<Class> {
+        rank(self.candidates(), limit, default_weight)
+    }
+
+    /// Gets the best candidates by a custom weight, see [Dewey::suggest_by]
+    ///
+    /// # Arguments
+    ///
+    /// - `limit` (`usize`) - Maximum number of suggestions
+    /// - `weight` (`impl Fn(&Class) -> W`) - Weight of a candidate, where heavier candidates rank first
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<Class>` - Up to `limit` candidates, heaviest first
+    pub fn ranked_by<W: Ord>(&self, limit: usize, weight: impl Fn(&Class) -> W) -> Vec<Class> {
+        rank(self.candidates(), limit, weight)
+    }
+}
+
+impl Dewey {
+    /// Gets ranked suggestions for a code prefix, ie for a type-ahead dropdown
+    ///
+    /// Classes are ranked by their OpenLibrary work count with the `count` feature, and by their number of descendants otherwise. Ties keep shelf order.
+    ///
+    /// # Arguments
+    ///
+    /// - `prefix` (`impl AsRef<str>`) - Code prefix typed so far (ie `51`)
+    /// - `limit` (`usize`) - Maximum number of suggestions
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<Class>` - Up to `limit` classes starting with the prefix (including the prefix's own class), heaviest first
+    pub fn suggest(&self, prefix: impl AsRef<str>, limit: usize) -> Vec<Class> {
+        rank(self.matches_ref(prefix), limit, default_weight)
+    }
+
+    /// Gets suggestions for a code prefix, ranked by a custom weight
+    ///
+    /// # Arguments
+    ///
+    /// - `prefix` (`impl AsRef<str>`) - Code prefix typed so far (ie `51`)
+    /// - `limit` (`usize`) - Maximum number of suggestions
+    /// - `weight` (`impl Fn(&Class) -> W`) - Weight of a class, where heavier classes rank first (ties keep shelf order)
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<Class>` - Up to `limit` classes starting with the prefix, heaviest first
+    pub fn suggest_by<W: Ord>(&self, prefix: impl AsRef<str>, limit: usize, weight: impl Fn(&Class) -> W) -> Vec<Class> {
+        rank(self.matches_ref(prefix), limit, weight)
+    }
+
+    /// Starts an incremental search over class codes, for type-ahead inputs
+    ///
+    /// # Returns
+    ///
+    /// - `Autocomplete` - An [Autocomplete] with an empty input, matching every class
+    pub fn autocomplete(&self) -> Autocomplete {
+        Autocomplete {
+            dewey: *self,
+            search: self.classes().inc_search(),
+            input: String::new(),
+            label: Vec::new(),
+            matched: true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_autocomplete() {
+        let dewey = Dewey::standard();
+        let mut search = dewey.autocomplete();
+        assert_eq!(search.candidates().count(), dewey.all().len());
+        assert!(!search.is_class());
+
+        assert!(search.push_str("81"));
+        assert_eq!(search.class().map(|class| class.code.as_ref()), Some("81"));
+        assert!(search.push('3') && search.is_class());
+
+        assert!(search.push('.') && search.input() == "813.", "Expected the decimal point to be accepted");
+        assert!(!search.push('a'));
+        assert_eq!((search.candidates().count(), search.class()), (0, None));
+
+        assert_eq!(search.pop(), Some('a'));
+        assert_eq!(search.pop(), Some('.'));
+        assert_eq!(search.class().map(|class| class.code.as_ref()), Some("813"));
+        assert!(!search.push('9') && !search.has_candidates(), "Expected 8139 not to exist");
+        assert!(!search.push('1'), "Expected the search to stay without candidates");
+
+        search.reset();
+        assert!(search.push('5') && search.has_candidates() && search.input() == "5");
+    }
+
+    #[test]
+    fn test_suggest() {
+        let dewey = Dewey::standard();
+        let suggestions = dewey.suggest("51", 5);
+        assert_eq!(suggestions.len(), 5);
+        assert!(suggestions.windows(2).all(|pair| default_weight(&pair[0]) >= default_weight(&pair[1])));
+        assert!(suggestions.iter().all(|class| class.code.starts_with("51")));
+
+        let mut search = dewey.autocomplete();
+        search.push_str("51");
+        assert_eq!(search.ranked(5), suggestions);
+
+        let shortest = dewey.suggest_by("5", 3, |class| Reverse(class.name.len()));
+        assert!(shortest.windows(2).all(|pair| pair[0].name.len() <= pair[1].name.len()));
+        assert_eq!(search.ranked_by(100, |_| 0).len(), dewey.get_matches("51").len(), "Expected equal weights to keep every candidate");
+        assert!(dewey.suggest("008", 5).is_empty());
+    }
+}
@@ -0,0 +1,168 @@
+use std::{ collections::hash_map::RandomState, fs::File, hash::{ BuildHasher, Hasher }, io::{ self, BufWriter, Write }, path::Path };
+
+use crate::{ Class, Dewey };
+
+/// Avro schema of the records written by [Dewey::write_avro]
+///
+/// `path` holds the codes of every ancestor of the class followed by its own code (ie `["5", "51", "516"]`), and is annotated with the custom `ddc-hierarchy-path` logical type. Readers that don't know it fall back to a plain array of strings.
+pub const AVRO_SCHEMA: &str = r#"{"type":"record","name":"Class","namespace":"dewey_decimal","fields":[{"name":"code","type":"string"},{"name":"name","type":"string"},{"name":"parent_code","type":["null","string"],"default":null},{"name":"path","type":{"type":"array","items":"string","logicalType":"ddc-hierarchy-path"}},{"name":"child_count","type":"long"},{"name":"descendant_count","type":"long"}]}"#;
+
+const MAGIC: &[u8; 4] = b"Obj\x01";
+const BLOCK_SIZE: usize = 1024;
+
+fn write_long(output: &mut Vec<u8>, value: i64) {
+    let mut value = ((value << 1) ^ (value >> 63)) as u64;
+    while value >= 0x80 {
+        output.push((value as u8 & 0x7f) | 0x80);
+        value >>= 7;
+    }
+    output.push(value as u8);
+}
+
+fn write_bytes(output: &mut Vec<u8>, value: &[u8]) {
+    write_long(output, value.len() as i64);
+    output.extend_from_slice(value);
+}
+
+fn sync_marker() -> [u8; 16] {
+    let state = RandomState::new();
+    let mut marker = [0; 16];
+    for (index, chunk) in marker.chunks_mut(8).enumerate() {
+        let mut hasher = state.build_hasher();
+        hasher.write_usize(index);
+        chunk.copy_from_slice(&hasher.finish().to_le_bytes());
+    }
+    marker
+}
+
+impl Dewey {
+    fn write_avro_record(&self, output: &mut Vec<u8>, class: &Class) {
+        write_bytes(output, class.code.as_bytes());
+        write_bytes(output, class.name.as_bytes());
+        match class.parent_code() {
+            Some(parent_code) => {
+                write_long(output, 1);
+                write_bytes(output, parent_code.as_bytes());
+            }
+            None => write_long(output, 0),
+        }
+
+        let lineage = self.lineage(class);
+        write_long(output, lineage.len() as i64);
+        for ancestor in &lineage {
+            write_bytes(output, ancestor.code.as_bytes());
+        }
+        write_long(output, 0);
+
+        write_long(output, class.child_count as i64);
+        write_long(output, class.descendant_count as i64);
+    }
+
+    /// Writes every class to an Avro object container file (uncompressed), in shelf order
+    ///
+    /// Codes are always written trimmed, as padded codes aren't unique (see [CodeStyle](crate::CodeStyle)).
+    ///
+    /// # Arguments
+    ///
+    /// - `writer` (`impl Write`) - Destination of the container file
+    ///
+    /// # Returns
+    ///
+    /// - `io::Result<()>` - An error if writing fails
+    pub fn write_avro(&self, mut writer: impl Write) -> io::Result<()> {
+        let sync = sync_marker();
+
+        let mut header = MAGIC.to_vec();
+        write_long(&mut header, 2);
+        write_bytes(&mut header, b"avro.schema");
+        write_bytes(&mut header, AVRO_SCHEMA.as_bytes());
+        write_bytes(&mut header, b"avro.codec");
+        write_bytes(&mut header, b"null");
+        write_long(&mut header, 0);
+        header.extend_from_slice(&sync);
+        writer.write_all(&header)?;
+
+        for classes in self.all().chunks(BLOCK_SIZE) {
+            let mut records = Vec::new();
+            for class in classes {
+                self.write_avro_record(&mut records, class);
+            }
+
+            let mut block = Vec::new();
+            write_long(&mut block, classes.len() as i64);
+            write_long(&mut block, records.len() as i64);
+            block.extend_from_slice(&records);
+            block.extend_from_slice(&sync);
+            writer.write_all(&block)?;
+        }
+        Ok(())
+    }
+
+    /// Exports every class to an Avro object container file, see [Dewey::write_avro]
+    ///
+    /// # Arguments
+    ///
+    /// - `path` (`impl AsRef<Path>`) - Path of the file to write
+    ///
+    /// # Returns
+    ///
+    /// - `io::Result<()>` - An error if writing fails
+    pub fn export_avro(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_avro(&mut writer)?;
+        writer.flush()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn read_long(input: &mut &[u8]) -> i64 {
+        let (mut value, mut shift) = (0u64, 0);
+        loop {
+            let byte = input[0];
+            *input = &input[1..];
+            value |= ((byte & 0x7f) as u64) << shift;
+            shift += 7;
+            if byte & 0x80 == 0 {
+                return ((value >> 1) as i64) ^ -((value & 1) as i64);
+            }
+        }
+    }
+
+    fn read_string(input: &mut &[u8]) -> String {
+        let len = read_long(input) as usize;
+        let value = String::from_utf8(input[..len].to_vec()).unwrap();
+        *input = &input[len..];
+        value
+    }
+
+    #[test]
+    fn test_write_avro() {
+        let mut output = Vec::new();
+        Dewey::standard().write_avro(&mut output).unwrap();
+        assert!(output.starts_with(MAGIC), "Expected an Avro container");
+
+        let mut input = &output[MAGIC.len()..];
+        assert_eq!(read_long(&mut input), 2);
+        assert_eq!((read_string(&mut input), read_string(&mut input)), ("avro.schema".to_string(), AVRO_SCHEMA.to_string()));
+        assert_eq!((read_string(&mut input), read_string(&mut input)), ("avro.codec".to_string(), "null".to_string()));
+        assert_eq!(read_long(&mut input), 0);
+        let sync = &input[..16];
+        input = &input[16..];
+
+        let mut total = 0;
+        while !input.is_empty() {
+            total += read_long(&mut input);
+            let size = read_long(&mut input) as usize;
+            assert_eq!(&input[size..size + 16], sync, "Expected a sync marker after each block");
+            input = &input[size + 16..];
+        }
+        assert_eq!(total as usize, Dewey::standard().all().len());
+
+        let class = Dewey::standard().get_class("516").unwrap();
+        let lineage = Dewey::standard().lineage(&class);
+        assert_eq!(lineage.iter().map(|class| class.code.as_ref()).collect::<Vec<_>>(), vec!["5", "51", "516"]);
+    }
+}
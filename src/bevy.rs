@@ -0,0 +1,96 @@
+//! Bevy plugin making a dataset available to systems as a resource
+//!
+//! ```rust
+//! use bevy_app::App;
+//! use bevy_ecs::system::Res;
+//! use dewey_decimal::bevy::{ DeweyIndex, DeweyPlugin };
+//!
+//! fn shelves(index: Res<DeweyIndex>) {
+//!     assert_eq!(index.children_of("5").len(), 10);
+//! }
+//!
+//! App::new().add_plugins(DeweyPlugin::default()).add_systems(bevy_app::Update, shelves).update();
+//! ```
+
+use std::ops::Deref;
+
+use bevy_app::{ App, Plugin };
+use bevy_ecs::resource::Resource;
+
+use crate::{ Class, Dewey };
+
+/// Resource holding the dataset inserted by [DeweyPlugin], dereferencing to [Dewey]
+#[derive(Resource, Clone, Copy, Default)]
+pub struct DeweyIndex(pub Dewey);
+
+impl Deref for DeweyIndex {
+    type Target = Dewey;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Plugin inserting a [DeweyIndex] resource & registering [Class] in the app's type registry, so it can be used in reflected components & scenes
+///
+/// Defaults to the standard dataset; use [DeweyPlugin::new] for a loaded one.
+#[derive(Clone, Copy, Default)]
+pub struct DeweyPlugin {
+    dewey: Dewey,
+}
+
+impl DeweyPlugin {
+    /// Creates a plugin inserting a dataset
+    ///
+    /// # Arguments
+    ///
+    /// - `dewey` (`Dewey`) - Dataset to insert as the [DeweyIndex] resource
+    ///
+    /// # Returns
+    ///
+    /// - `DeweyPlugin` - The plugin
+    pub fn new(dewey: Dewey) -> Self {
+        DeweyPlugin { dewey }
+    }
+}
+
+impl Plugin for DeweyPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(DeweyIndex(self.dewey)).register_type::<Class>();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::any::TypeId;
+
+    use bevy_app::Update;
+    use bevy_ecs::{ reflect::AppTypeRegistry, system::{ Commands, Res } };
+    use bevy_reflect::PartialReflect;
+
+    use super::*;
+
+    #[derive(Resource)]
+    struct Shelf(Vec<Class>);
+
+    fn stock_shelf(index: Res<DeweyIndex>, mut commands: Commands) {
+        commands.insert_resource(Shelf(index.children_of("51").to_vec()));
+    }
+
+    #[test]
+    fn test_plugin() {
+        let mut app = App::new();
+        app.add_plugins(DeweyPlugin::default()).add_systems(Update, stock_shelf);
+        app.update();
+
+        let shelf = app.world().resource::<Shelf>();
+        assert_eq!(shelf.0.len(), Dewey::standard().children_of("51").len());
+        assert!(shelf.0.iter().any(|class| class.name == "Geometry"));
+        assert_eq!(app.world().resource::<DeweyIndex>().get_class("516").unwrap().name, "Geometry");
+
+        let registry = app.world().resource::<AppTypeRegistry>().read();
+        assert!(registry.get(TypeId::of::<Class>()).is_some(), "Expected Class to be registered");
+        let geometry = Dewey::standard().get_class("516").unwrap();
+        assert!(geometry.reflect_ref().as_struct().unwrap().field("name").is_some());
+    }
+}
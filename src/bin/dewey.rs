@@ -0,0 +1,160 @@
+//! Command-line interface over the embedded dataset, built with the `cli` feature
+//!
+//! ```text
+//! dewey get 813
+//! dewey children 5
+//! dewey search "quantum" --limit 5
+//! dewey tree 51 --depth 2 --format json
+//! ```
+
+use std::process::ExitCode;
+
+use clap::{ Parser, Subcommand, ValueEnum };
+use dewey_decimal::{ render::{ self, Column }, Class, ClassTree, Dewey, DeweyError };
+
+/// Look up Dewey Decimal classes from the terminal
+#[derive(Parser)]
+#[command(name = "dewey", version, about)]
+struct Cli {
+    /// Output format
+    #[arg(short, long, value_enum, global = true, default_value_t = Format::Table)]
+    format: Format,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    /// Aligned text table (or an indented listing, for trees)
+    Table,
+
+    /// Pretty-printed JSON
+    Json,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Show a class by code
+    Get {
+        /// Code of the class (ie 813 or 51X)
+        code: String,
+    },
+
+    /// List the direct children of a class
+    Children {
+        /// Code of the parent class (omit for the main classes)
+        #[arg(default_value = "")]
+        code: String,
+    },
+
+    /// Search class names
+    Search {
+        /// Text to search for
+        query: String,
+
+        /// Maximum number of results
+        #[arg(short, long, default_value_t = 20)]
+        limit: usize,
+    },
+
+    /// Show the subtree of a class
+    Tree {
+        /// Code of the subtree's root
+        code: String,
+
+        /// Number of levels to show below the root (unlimited if omitted)
+        #[arg(short, long)]
+        depth: Option<usize>,
+    },
+}
+
+const COLUMNS: [Column; 3] = [Column::PaddedCode, Column::Name, Column::Children];
+
+/// Removes the levels of a tree deeper than `depth` below its root
+fn prune(mut tree: ClassTree, depth: usize) -> ClassTree {
+    tree.children = match depth {
+        0 => Vec::new(),
+        _ => tree.children.into_iter().map(|child| prune(child, depth - 1)).collect(),
+    };
+    tree
+}
+
+fn to_json(value: &impl serde::Serialize) -> String {
+    format!("{}\n", serde_json::to_string_pretty(value).expect("Classes always serialize"))
+}
+
+fn classes(classes: &[Class], format: Format) -> String {
+    match format {
+        Format::Table => render::table(classes, &COLUMNS),
+        Format::Json => to_json(&classes),
+    }
+}
+
+fn run(cli: Cli) -> Result<String, DeweyError> {
+    let dewey = Dewey::standard();
+    Ok(match cli.command {
+        Command::Get { code } => {
+            let class = dewey.try_get_class(&code)?;
+            match cli.format {
+                Format::Table => render::table([class], &COLUMNS),
+                Format::Json => to_json(&class),
+            }
+        }
+        Command::Children { code } if code.trim().is_empty() => classes(&dewey.categories(), cli.format),
+        Command::Children { code } => classes(&dewey.try_get_direct_children(&code)?, cli.format),
+        Command::Search { query, limit } => classes(&dewey.search_options().query(query).limit(limit).execute(), cli.format),
+        Command::Tree { code, depth } => {
+            let class = dewey.class_ref(&code).ok_or(DeweyError::NotFound(code.clone()))?;
+            match cli.format {
+                Format::Table => dewey.listing(&class.code, depth, false),
+                Format::Json => {
+                    let tree = dewey.subtree(&class.code).expect("The class exists");
+                    to_json(&match depth {
+                        Some(depth) => prune(tree, depth),
+                        None => tree,
+                    })
+                }
+            }
+        }
+    })
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(output) => {
+            print!("{output}");
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("dewey: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn depth(tree: &ClassTree) -> usize {
+        tree.children.iter().map(|child| 1 + depth(child)).max().unwrap_or(0)
+    }
+
+    #[test]
+    fn test_commands() {
+        let run = |args: &[&str]| run(Cli::try_parse_from([&["dewey"], args].concat()).unwrap());
+        assert!(run(&["get", "813"]).unwrap().lines().nth(2).unwrap().starts_with("813  "));
+        assert!(matches!(run(&["get", "12a"]), Err(DeweyError::InvalidCode(_))));
+        assert_eq!(run(&["children"]).unwrap().lines().count(), 12, "Expected a header, a rule & the main classes");
+
+        let children: Vec<Class> = serde_json::from_str(&run(&["children", "5", "--format", "json"]).unwrap()).unwrap();
+        assert!(children.iter().all(|class| class.parent_code() == Some("5")));
+
+        let tree: ClassTree = serde_json::from_str(&run(&["tree", "5", "--depth", "1", "-f", "json"]).unwrap()).unwrap();
+        assert_eq!(depth(&tree), 1, "Expected the tree to be pruned");
+        let listing = run(&["tree", "5", "--depth", "1"]).unwrap();
+        assert_eq!(listing.lines().count(), 1 + tree.children.len());
+        assert!(matches!(run(&["tree", "008"]), Err(DeweyError::NotFound(_))));
+    }
+}
@@ -0,0 +1,124 @@
+//! TypeScript bindings for the public data types, generated with `specta`
+
+use std::{ fs, path::Path };
+
+use specta::{ ts::{ self, BigIntExportBehavior, ExportConfiguration, TsExportError }, NamedType };
+
+use crate::{ dataset, facets::FacetNode, CategoryStats, Class, ClassTree, DatasetStats, IndexEntry, LookupResult, Suggestion };
+
+fn export<T: NamedType>(output: &mut String, config: &ExportConfiguration) -> Result<(), TsExportError> {
+    output.push_str(&ts::export::<T>(config)?);
+    output.push_str("\n\n");
+    Ok(())
+}
+
+/// Generates TypeScript declarations for every public serializable type
+///
+/// Includes [Class], [ClassTree], [FacetNode], [LookupResult], [Suggestion], [DatasetStats], [IndexEntry], the [dataset] report types, and any types enabled by other features (ie `CacheStats`, `TextMatch`). Integers are exported as `number`, matching `serde_json`'s output.
+///
+/// # Returns
+///
+/// - `Result<String, TsExportError>` - The declarations, or an error if a type can't be exported
+pub fn typescript() -> Result<String, TsExportError> {
+    let config = ExportConfiguration::new().bigint(BigIntExportBehavior::Number);
+    let mut output = "// This file has been generated by dewey-decimal. DO NOT EDIT.\n\n".to_string();
+
+    export::<Class>(&mut output, &config)?;
+    export::<ClassTree>(&mut output, &config)?;
+    export::<FacetNode>(&mut output, &config)?;
+    export::<LookupResult>(&mut output, &config)?;
+    export::<Suggestion>(&mut output, &config)?;
+    export::<CategoryStats>(&mut output, &config)?;
+    export::<DatasetStats>(&mut output, &config)?;
+    export::<IndexEntry>(&mut output, &config)?;
+    export::<dataset::Entry>(&mut output, &config)?;
+    export::<dataset::SourceNode>(&mut output, &config)?;
+    export::<dataset::Violation>(&mut output, &config)?;
+    export::<dataset::Severity>(&mut output, &config)?;
+    export::<dataset::Diagnostic>(&mut output, &config)?;
+    #[cfg(feature = "cache")]
+    export::<crate::CacheStats>(&mut output, &config)?;
+    #[cfg(feature = "scan")]
+    {
+        export::<crate::TextMatch>(&mut output, &config)?;
+        export::<crate::ClassCandidate>(&mut output, &config)?;
+    }
+
+    Ok(output)
+}
+
+/// Writes the TypeScript declarations generated by [typescript] to a file, ie from a Tauri app's build step
+///
+/// # Arguments
+///
+/// - `path` (`impl AsRef<Path>`) - Path of the bindings file to write (ie `src/bindings/dewey.ts`)
+///
+/// # Returns
+///
+/// - `Result<(), TsExportError>` - An error if a type can't be exported, or writing fails
+pub fn export_typescript(path: impl AsRef<Path>) -> Result<(), TsExportError> {
+    fs::write(path, typescript()?)?;
+    Ok(())
+}
+
+/// TypeScript wrappers of the commands of the `tauri` feature, calling them through `@tauri-apps/api`
+const TAURI_COMMANDS: &str = r#"import { invoke } from "@tauri-apps/api/core";
+
+export function getClass(code: string): Promise<Class | null> {
+    return invoke("get_class", { code });
+}
+
+export function children(code: string): Promise<Class[]> {
+    return invoke("children", { code });
+}
+
+export function search(query: string, limit: number | null = null): Promise<Class[]> {
+    return invoke("search", { query, limit });
+}
+
+export function tree(code: string): Promise<ClassTree[]> {
+    return invoke("tree", { code });
+}
+"#;
+
+/// Generates a TypeScript module for a Tauri frontend, with the declarations of [typescript] & typed wrappers of the commands of the `tauri` feature (`getClass`, `children`, `search` & `tree`)
+///
+/// # Returns
+///
+/// - `Result<String, TsExportError>` - The module, or an error if a type can't be exported
+pub fn tauri_bindings() -> Result<String, TsExportError> {
+    let declarations = typescript()?;
+    let (header, declarations) = declarations.split_once("\n\n").unwrap_or(("", &declarations));
+    Ok(format!("{header}\n\n{TAURI_COMMANDS}\n{declarations}"))
+}
+
+/// Writes the TypeScript module generated by [tauri_bindings] to a file, ie from a Tauri app's build step
+///
+/// # Arguments
+///
+/// - `path` (`impl AsRef<Path>`) - Path of the module to write (ie `src/bindings/dewey.ts`)
+///
+/// # Returns
+///
+/// - `Result<(), TsExportError>` - An error if a type can't be exported, or writing fails
+pub fn export_tauri_bindings(path: impl AsRef<Path>) -> Result<(), TsExportError> {
+    fs::write(path, tauri_bindings()?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_typescript() {
+        let bindings = typescript().unwrap();
+        for name in ["Class", "FacetNode", "LookupResult", "Diagnostic", "Severity"] {
+            assert!(bindings.contains(&format!("export type {name} ")), "Expected a declaration of {name}");
+        }
+
+        let module = tauri_bindings().unwrap();
+        assert!(module.starts_with("// This file has been generated") && module.contains("\nimport { invoke }"), "Expected the import after the header");
+        assert!(module.contains("export function getClass(code: string): Promise<Class | null>") && module.contains("export type ClassTree "));
+    }
+}
@@ -0,0 +1,143 @@
+use crate::{ Class, Dewey };
+
+/// BISAC subject headings, each paired with the DDC class that titles under it are commonly shelved in
+///
+/// Every main subject has a general heading (`XXX000000`), used for codes of that subject that aren't listed.
+const BISAC_SUBJECTS: &[(&str, &str, &str)] = &[
+    ("ANT000000", "ANTIQUES & COLLECTIBLES / General", "745"),
+    ("ARC000000", "ARCHITECTURE / General", "72"),
+    ("ART000000", "ART / General", "7"),
+    ("BIB000000", "BIBLES / General", "22"),
+    ("BIO000000", "BIOGRAPHY & AUTOBIOGRAPHY / General", "92"),
+    ("BOD000000", "BODY, MIND & SPIRIT / General", "13"),
+    ("BUS000000", "BUSINESS & ECONOMICS / General", "65"),
+    ("CGN000000", "COMICS & GRAPHIC NOVELS / General", "741"),
+    ("CKB000000", "COOKING / General", "641"),
+    ("COM000000", "COMPUTERS / General", "004"),
+    ("COM051000", "COMPUTERS / Programming / General", "005"),
+    ("CRA000000", "CRAFTS & HOBBIES / General", "745"),
+    ("DES000000", "DESIGN / General", "745"),
+    ("DRA000000", "DRAMA / General", "812"),
+    ("EDU000000", "EDUCATION / General", "37"),
+    ("FAM000000", "FAMILY & RELATIONSHIPS / General", "306"),
+    ("FIC000000", "FICTION / General", "813"),
+    ("FIC009000", "FICTION / Fantasy / General", "813"),
+    ("FIC022000", "FICTION / Mystery & Detective / General", "813"),
+    ("FIC028000", "FICTION / Science Fiction / General", "813"),
+    ("FOR000000", "FOREIGN LANGUAGE STUDY / General", "4"),
+    ("GAM000000", "GAMES & ACTIVITIES / General", "79"),
+    ("GAR000000", "GARDENING / General", "635"),
+    ("HEA000000", "HEALTH & FITNESS / General", "613"),
+    ("HIS000000", "HISTORY / General", "9"),
+    ("HIS036000", "HISTORY / United States / General", "973"),
+    ("HOM000000", "HOUSE & HOME / General", "64"),
+    ("HUM000000", "HUMOR / General", "817"),
+    ("JUV000000", "JUVENILE FICTION / General", "813"),
+    ("LAN000000", "LANGUAGE ARTS & DISCIPLINES / General", "4"),
+    ("LAW000000", "LAW / General", "34"),
+    ("LCO000000", "LITERARY COLLECTIONS / General", "808"),
+    ("LIT000000", "LITERARY CRITICISM / General", "8"),
+    ("MAT000000", "MATHEMATICS / General", "51"),
+    ("MAT012000", "MATHEMATICS / Geometry / General", "516"),
+    ("MED000000", "MEDICAL / General", "61"),
+    ("MUS000000", "MUSIC / General", "78"),
+    ("NAT000000", "NATURE / General", "57"),
+    ("PER000000", "PERFORMING ARTS / General", "79"),
+    ("PET000000", "PETS / General", "636"),
+    ("PHI000000", "PHILOSOPHY / General", "1"),
+    ("PHO000000", "PHOTOGRAPHY / General", "77"),
+    ("POE000000", "POETRY / General", "811"),
+    ("POL000000", "POLITICAL SCIENCE / General", "32"),
+    ("PSY000000", "PSYCHOLOGY / General", "15"),
+    ("REF000000", "REFERENCE / General", "03"),
+    ("REL000000", "RELIGION / General", "2"),
+    ("SCI000000", "SCIENCE / General", "5"),
+    ("SCI004000", "SCIENCE / Astronomy", "52"),
+    ("SCI013000", "SCIENCE / Chemistry / General", "54"),
+    ("SCI055000", "SCIENCE / Physics / General", "53"),
+    ("SEL000000", "SELF-HELP / General", "158"),
+    ("SOC000000", "SOCIAL SCIENCE / General", "30"),
+    ("SPO000000", "SPORTS & RECREATION / General", "796"),
+    ("TEC000000", "TECHNOLOGY & ENGINEERING / General", "6"),
+    ("TRA000000", "TRANSPORTATION / General", "388"),
+    ("TRU000000", "TRUE CRIME / General", "364"),
+    ("TRV000000", "TRAVEL / General", "91"),
+];
+
+/// BISAC subject heading mapped to a class by [Dewey::bisac_subjects]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct BisacSubject {
+    /// Subject code (ie `FIC022000`)
+    pub code: &'static str,
+
+    /// Subject heading (ie `FICTION / Mystery & Detective / General`)
+    pub heading: &'static str,
+}
+
+impl Dewey {
+    /// Gets the BISAC subject headings mapped to a class
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code of the class
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<BisacSubject>` - Subjects mapped to exactly this class, in code order (empty if there are none, or the class doesn't exist)
+    pub fn bisac_subjects(&self, code: impl AsRef<str>) -> Vec<BisacSubject> {
+        let Some(class) = self.class_ref(code) else {
+            return Vec::new();
+        };
+        BISAC_SUBJECTS.iter()
+            .filter(|(_, _, ddc)| *ddc == class.code)
+            .map(|(code, heading, _)| BisacSubject { code, heading })
+            .collect()
+    }
+
+    /// Gets the class that titles with a BISAC subject are commonly shelved in, ie for placing titles received in ONIX feeds
+    ///
+    /// Subjects that aren't listed fall back to the general heading of their main subject (ie `FIC027000` to `FIC000000`).
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - BISAC subject code (ie `FIC022000`)
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Class>` - The mapped [Class], or [None] if the subject (and its main subject) isn't mapped
+    pub fn from_bisac(&self, code: impl AsRef<str>) -> Option<Class> {
+        let code = code.as_ref().trim().to_ascii_uppercase();
+        let general = format!("{}000000", code.get(..3)?);
+        [code, general]
+            .iter()
+            .find_map(|code| BISAC_SUBJECTS.iter().find(|(subject, _, _)| subject == code))
+            .and_then(|(_, _, ddc)| self.get_class(ddc))
+    }
+}
+
+impl Class {
+    /// See [Dewey::bisac_subjects]
+    pub fn bisac_subjects(&self) -> Vec<BisacSubject> {
+        Dewey::standard().bisac_subjects(&self.code)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_bisac() {
+        let dewey = Dewey::standard();
+        for (bisac, code) in [("FIC022000", "813"), ("fic027000", "813"), ("MAT012000", "516"), ("MAT003000", "51"), ("COM051000", "005")] {
+            assert_eq!(dewey.from_bisac(bisac).map(|class| class.code).as_deref(), Some(code), "Unexpected class for {bisac}");
+        }
+        assert!(dewey.from_bisac("XYZ000000").is_none() && dewey.from_bisac("FI").is_none(), "Expected unknown subjects not to match");
+
+        let subjects = dewey.get_class("516").unwrap().bisac_subjects();
+        assert_eq!(subjects, [BisacSubject { code: "MAT012000", heading: "MATHEMATICS / Geometry / General" }]);
+        assert!(dewey.bisac_subjects("813").iter().any(|subject| subject.code == "FIC022000"));
+        assert!(BISAC_SUBJECTS.iter().all(|(_, _, code)| dewey.get_class(code).is_some()), "Expected every DDC code to exist");
+    }
+}
@@ -0,0 +1,135 @@
+//! Decoding of the class blob embedded by `build.rs`
+//!
+//! The blob is a DEFLATE-compressed sequence of class records in shelf order. Each record is the code, name & parent code (empty for main classes), a `has_children` byte, the child & descendant counts (`u32`), the work count (`u64`, only with the `count` feature), the OpenLibrary query (only with the `query` feature), and the scope notes (empty if there are none, or without the `notes` feature). Strings are prefixed with their length (`u32`), and integers are little-endian.
+//!
+//! Since the record layout depends on the enabled metadata features, records are only readable by builds with the same `count` & `query` features (see [LAYOUT]).
+//!
+//! Decoded classes borrow their strings from the decompressed blob, so cloning them doesn't allocate.
+
+use std::borrow::Cow;
+
+use trie_rs::map::{ Trie, TrieBuilder };
+
+use crate::{ code_label, Class, CLASS_BLOB };
+
+/// Metadata features the record layout includes: bit 0 for `count`, bit 1 for `query`
+pub(crate) const LAYOUT: u8 = cfg!(feature = "count") as u8 | (cfg!(feature = "query") as u8) << 1;
+
+/// Reads fields from a decompressed blob, returning [None] on truncated or invalid data
+pub(crate) struct Reader<'a>(pub(crate) &'a [u8]);
+
+impl<'a> Reader<'a> {
+    pub(crate) fn bytes<const N: usize>(&mut self) -> Option<[u8; N]> {
+        let (bytes, rest) = self.0.split_first_chunk::<N>()?;
+        self.0 = rest;
+        Some(*bytes)
+    }
+
+    pub(crate) fn u32(&mut self) -> Option<u32> {
+        self.bytes().map(u32::from_le_bytes)
+    }
+
+    pub(crate) fn str(&mut self) -> Option<&'a str> {
+        let len = self.u32()? as usize;
+        let (value, rest) = self.0.split_at_checked(len)?;
+        self.0 = rest;
+        std::str::from_utf8(value).ok()
+    }
+}
+
+impl Reader<'static> {
+    /// Reads a class record, borrowing its strings from the blob
+    pub(crate) fn class(&mut self) -> Option<Class> {
+        let code = Cow::Borrowed(self.str()?);
+        let name = Cow::Borrowed(self.str()?);
+        let parent_code = Some(self.str()?).filter(|code| !code.is_empty()).map(Cow::Borrowed);
+        let [has_children] = self.bytes()?;
+        let child_count = self.u32()? as usize;
+        let descendant_count = self.u32()? as usize;
+        #[cfg(feature = "count")]
+        let work_count = u64::from_le_bytes(self.bytes()?);
+        #[cfg(feature = "query")]
+        let query = self.str()?;
+        #[cfg_attr(not(feature = "notes"), allow(unused_variables))]
+        let notes = Some(self.str()?).filter(|notes| !notes.is_empty()).map(Cow::Borrowed);
+
+        Some(Class {
+            code,
+            name,
+            has_children: has_children != 0,
+            child_count,
+            descendant_count,
+            parent_code,
+            #[cfg(feature = "count")]
+            work_count,
+            #[cfg(feature = "query")]
+            query: Cow::Borrowed(query),
+            #[cfg(feature = "notes")]
+            notes,
+        })
+    }
+}
+
+pub(crate) fn write_str(output: &mut Vec<u8>, value: &str) {
+    output.extend_from_slice(&(value.len() as u32).to_le_bytes());
+    output.extend_from_slice(value.as_bytes());
+}
+
+/// Appends the record of a class, in the same layout as `build.rs` (the work count & query are left out without their features, and notes are written empty)
+pub(crate) fn write_class(output: &mut Vec<u8>, class: &Class) {
+    write_str(output, &class.code);
+    write_str(output, &class.name);
+    write_str(output, class.parent_code().unwrap_or(""));
+    output.push(class.has_children as u8);
+    output.extend_from_slice(&(class.child_count as u32).to_le_bytes());
+    output.extend_from_slice(&(class.descendant_count as u32).to_le_bytes());
+    #[cfg(feature = "count")]
+    output.extend_from_slice(&class.work_count.to_le_bytes());
+    #[cfg(feature = "query")]
+    write_str(output, &class.query);
+    #[cfg(feature = "notes")]
+    write_str(output, class.notes.as_deref().unwrap_or(""));
+    #[cfg(not(feature = "notes"))]
+    write_str(output, "");
+}
+
+/// Decodes classes from a blob
+///
+/// The decompressed blob is leaked so that classes can borrow their strings from it, like the rest of the embedded data.
+fn decode(blob: &[u8]) -> Vec<Class> {
+    let data: &'static [u8] = Vec::leak(miniz_oxide::inflate::decompress_to_vec(blob).expect("Class blob is corrupted"));
+    let mut reader = Reader(data);
+    let mut classes = Vec::new();
+    while !reader.0.is_empty() {
+        classes.push(reader.class().expect("Class blob is truncated"));
+    }
+    classes
+}
+
+/// Builds the trie of the embedded classes, decoding them from the blob
+pub(crate) fn embedded_classes() -> Trie<u8, Class> {
+    let mut trie = TrieBuilder::new();
+    for class in decode(CLASS_BLOB) {
+        trie.insert(code_label(&class.code), class);
+    }
+    trie.build()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{ Dewey, RAW_TREE };
+
+    #[test]
+    fn test_blob() {
+        let classes = decode(CLASS_BLOB);
+        assert_eq!(classes.len(), Dewey::standard().all().len());
+        assert_eq!(classes.iter().filter(|class| class.parent_code.is_none()).count(), RAW_TREE.len());
+        assert!(classes.windows(2).all(|pair| pair[0] < pair[1]), "Expected records in shelf order");
+
+        let mathematics = classes.iter().find(|class| class.code == "51").unwrap();
+        assert_eq!((mathematics.name.as_ref(), mathematics.parent_code.as_deref()), ("Mathematics", Some("5")));
+        assert_eq!(mathematics.child_count, Dewey::standard().children_of("51").len());
+        assert!(matches!((&mathematics.code, &mathematics.name), (Cow::Borrowed(_), Cow::Borrowed(_))), "Expected strings borrowed from the blob");
+    }
+}
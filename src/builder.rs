@@ -0,0 +1,500 @@
+use std::{ borrow::Cow, collections::{ HashMap, HashSet }, fmt };
+#[cfg(feature = "serde")]
+use std::{ fs, path::PathBuf };
+
+use trie_rs::map::TrieBuilder;
+
+use crate::{
+    code_label,
+    dataset::{ self, Entry, SourceNode, Violation },
+    search,
+    Class,
+    Dataset,
+    Dewey,
+    PseudoClasses,
+    RawNode,
+    RAW_TREE,
+};
+
+/// Where the classes of a dataset were taken from, see [Dewey::dataset_source]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum DatasetSource {
+    /// Embedded from OpenLibrary's dataset (or the URL in `DEWEY_SOURCE_URL`), fetched at build time
+    Network,
+
+    /// Embedded from the JSON file vendored with the crate, either because the build was offline (the `offline` feature or `DEWEY_OFFLINE`) or because fetching failed
+    Vendored,
+
+    /// Embedded from the local JSON file in `DEWEY_SOURCE_FILE` at build time
+    File,
+
+    /// Loaded at runtime with a [DeweyBuilder]
+    Runtime,
+}
+
+/// Edition of the classification embedded at compile time, selected with the `data-*` features
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum DatasetEdition {
+    /// OpenLibrary's Library Explorer summaries (the `data-openlibrary` feature, enabled by default)
+    OpenLibrary,
+
+    /// OCLC's DDC 23 three-level summaries (the `data-ddc23-summaries` feature), supplied through `DEWEY_SOURCE_FILE`
+    Ddc23Summaries,
+}
+
+#[cfg(feature = "data-ddc23-summaries")]
+const EMBEDDED_EDITION: DatasetEdition = DatasetEdition::Ddc23Summaries;
+
+#[cfg(not(feature = "data-ddc23-summaries"))]
+const EMBEDDED_EDITION: DatasetEdition = DatasetEdition::OpenLibrary;
+
+/// Where a [DeweyBuilder] gets its classes from
+#[derive(Clone, Debug, Default)]
+pub enum DeweySource {
+    /// The dataset embedded at compile time
+    #[default]
+    Embedded,
+
+    /// Nodes in the OpenLibrary JSON format, already parsed
+    Nodes(Vec<SourceNode>),
+
+    /// Flat entries, with parents taken from [Entry::parent_code] (classes without one are main classes)
+    Entries(Vec<Entry>),
+
+    /// A JSON document in the OpenLibrary format
+    #[cfg(feature = "serde")]
+    Json(String),
+
+    /// Path to a JSON file in the OpenLibrary format
+    #[cfg(feature = "serde")]
+    File(PathBuf),
+}
+
+/// Error produced while loading a dataset with a [DeweyBuilder]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LoadError {
+    /// The source file couldn't be read
+    Io(String),
+
+    /// The source couldn't be parsed
+    Format(String),
+
+    /// The dataset breaks the hierarchy invariants (see [dataset::check])
+    Invalid(Vec<Violation>),
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadError::Io(error) => write!(f, "failed to read dataset: {error}"),
+            LoadError::Format(error) => write!(f, "failed to parse dataset: {error}"),
+            LoadError::Invalid(violations) => {
+                write!(f, "invalid dataset: ")?;
+                for (index, violation) in violations.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, "; ")?;
+                    }
+                    write!(f, "{violation}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for LoadError {}
+
+/// Builder for [Dewey] handles backed by datasets loaded at runtime
+///
+/// Every call to [DeweyBuilder::build] loads the source into its own trie & indexes, so services can refresh or swap datasets without recompiling. Loaded datasets live for the rest of the program (handles stay [Copy] and lookups keep returning `&'static` data), so build a new handle when the data changes rather than on every request.
+///
+/// ```rust
+/// use dewey_decimal::{ dataset::Entry, DeweyBuilder, DeweySource };
+///
+/// let dewey = DeweyBuilder::new()
+///     .source(DeweySource::Entries(vec![
+///         Entry::new("5", "Science", None),
+///         Entry::new("51", "Mathematics", Some("5")),
+///     ]))
+///     .build()
+///     .unwrap();
+/// assert_eq!(dewey.get_class("5").unwrap().child_count, 1);
+/// assert!(dewey.get_class("6").is_none());
+/// ```
+#[derive(Clone, Debug)]
+pub struct DeweyBuilder {
+    source: DeweySource,
+    include: Option<Vec<char>>,
+    validate: bool,
+}
+
+impl Default for DeweyBuilder {
+    fn default() -> Self {
+        Self { source: DeweySource::Embedded, include: None, validate: true }
+    }
+}
+
+fn leak(value: impl Into<String>) -> &'static str {
+    Box::leak(value.into().into_boxed_str())
+}
+
+/// Leaks an owned string of a class, so that clones of it don't allocate (like those of the embedded classes)
+fn intern(value: &mut Cow<'static, str>) {
+    if let Cow::Owned(owned) = value {
+        *value = Cow::Borrowed(leak(std::mem::take(owned)));
+    }
+}
+
+fn source_nodes(nodes: &[RawNode]) -> Vec<SourceNode> {
+    nodes
+        .iter()
+        .map(|node| SourceNode {
+            name: node.name.to_string(),
+            short: node.short.to_string(),
+            #[cfg(feature = "query")]
+            query: node.query.to_string(),
+            #[cfg(not(feature = "query"))]
+            query: String::new(),
+            #[cfg(feature = "count")]
+            count: node.count,
+            #[cfg(not(feature = "count"))]
+            count: 0,
+            notes: None,
+            children: source_nodes(node.children),
+        })
+        .collect()
+}
+
+fn raw_nodes(nodes: &[SourceNode]) -> &'static [RawNode] {
+    let nodes = nodes
+        .iter()
+        .map(|node| RawNode {
+            name: leak(node.name.as_str()),
+            short: leak(node.short.as_str()),
+            #[cfg(feature = "query")]
+            query: leak(node.query.as_str()),
+            #[cfg(feature = "count")]
+            count: node.count,
+            children: raw_nodes(&node.children),
+        })
+        .collect::<Vec<_>>();
+    Box::leak(nodes.into_boxed_slice())
+}
+
+/// Flattens nodes into classes (with their metadata), skipping ranges
+#[cfg_attr(not(any(feature = "count", feature = "query", feature = "notes")), allow(unused_mut))]
+fn node_classes(output: &mut Vec<Class>, nodes: &[SourceNode], parent_code: Option<&str>) {
+    for node in nodes {
+        let Some(code) = node.code() else {
+            continue;
+        };
+
+        let mut class = Class::local(&code, &node.name, parent_code.map(String::from));
+        #[cfg(feature = "count")]
+        {
+            class.work_count = node.count;
+        }
+        #[cfg(feature = "query")]
+        {
+            class.query = Cow::Owned(node.query.clone());
+        }
+        #[cfg(feature = "notes")]
+        {
+            class.notes = node.notes.clone().map(Cow::Owned);
+        }
+        output.push(class);
+        node_classes(output, &node.children, Some(&code));
+    }
+}
+
+impl Dataset {
+    /// Builds a dataset from classes in shelf order, computing their hierarchy metadata & indexes
+    pub(crate) fn from_classes(mut classes: Vec<Class>, raw: &'static [RawNode]) -> Self {
+        for class in &mut classes {
+            intern(&mut class.code);
+            intern(&mut class.name);
+            class.parent_code.iter_mut().for_each(intern);
+            #[cfg(feature = "query")]
+            intern(&mut class.query);
+            #[cfg(feature = "notes")]
+            class.notes.iter_mut().for_each(intern);
+        }
+
+        let positions = classes
+            .iter()
+            .enumerate()
+            .map(|(index, class)| (class.code.clone(), index))
+            .collect::<HashMap<_, _>>();
+
+        // Unvalidated datasets may have parent cycles, so each walk up the hierarchy stops at the first class it revisits
+        let mut visited = HashSet::new();
+        for index in 0..classes.len() {
+            let mut parent = classes[index].parent_code.clone();
+            let mut direct = true;
+            visited.clear();
+            visited.insert(index);
+            while let Some(position) = parent.as_ref().and_then(|code| positions.get(code)).copied() {
+                if !visited.insert(position) {
+                    break;
+                }
+                let ancestor = &mut classes[position];
+                if direct {
+                    ancestor.child_count += 1;
+                    ancestor.has_children = true;
+                    direct = false;
+                }
+                ancestor.descendant_count += 1;
+                parent = ancestor.parent_code.clone();
+            }
+        }
+
+        let mut names = classes
+            .iter()
+            .map(|class| (leak(search::fold(&class.name)), leak(class.code.as_ref())))
+            .collect::<Vec<_>>();
+        names.sort();
+
+        let keywords = search::keyword_index(&names)
+            .into_iter()
+            .map(|(keyword, codes)| {
+                let codes = codes.into_iter().map(leak).collect::<Vec<_>>();
+                (leak(keyword), &*Box::leak(codes.into_boxed_slice()))
+            })
+            .collect::<Vec<_>>();
+
+        Self::indexed(classes, raw, Box::leak(names.into_boxed_slice()), Box::leak(keywords.into_boxed_slice()))
+    }
+
+    /// Builds a dataset from classes whose hierarchy metadata & name indexes are already computed, indexing them by code & parent
+    pub(crate) fn indexed(
+        classes: Vec<Class>,
+        raw: &'static [RawNode],
+        names: &'static [(&'static str, &'static str)],
+        keywords: &'static [(&'static str, &'static [&'static str])]
+    ) -> Self {
+        let mut children: HashMap<String, Vec<Class>> = HashMap::new();
+        let mut trie = TrieBuilder::new();
+        for class in &classes {
+            children.entry(class.parent_code.as_deref().unwrap_or_default().to_string()).or_default().push(class.clone());
+            trie.insert(code_label(&class.code), class.clone());
+        }
+        children.values_mut().for_each(|siblings| siblings.sort());
+
+        Self {
+            classes: trie.build(),
+            children,
+            raw,
+            source: DatasetSource::Runtime,
+            names,
+            keywords,
+            exact: Default::default(),
+            policies: Default::default(),
+            pseudo_classes: std::sync::RwLock::new(PseudoClasses::builtin()),
+            #[cfg(feature = "cache")]
+            cache: Default::default(),
+            #[cfg(feature = "scan")]
+            scanner: Default::default(),
+            #[cfg(feature = "tantivy")]
+            fulltext: Default::default(),
+        }
+    }
+}
+
+impl DeweyBuilder {
+    /// Creates a builder for the embedded dataset
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - A new [DeweyBuilder]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets where classes are loaded from
+    ///
+    /// # Arguments
+    ///
+    /// - `source` (`DeweySource`) - Source of the classes
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The updated [DeweyBuilder]
+    pub fn source(mut self, source: DeweySource) -> Self {
+        self.source = source;
+        self
+    }
+
+    /// Restricts the dataset to some main classes, like the `DEWEY_INCLUDE` build variable
+    ///
+    /// # Arguments
+    ///
+    /// - `main_classes` (`impl IntoIterator<Item = char>`) - Main classes to keep (ie `['5', '6']`)
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The updated [DeweyBuilder]
+    pub fn include(mut self, main_classes: impl IntoIterator<Item = char>) -> Self {
+        self.include = Some(main_classes.into_iter().collect());
+        self
+    }
+
+    /// Sets whether the dataset is checked against the hierarchy invariants before it's loaded (enabled by default)
+    ///
+    /// # Arguments
+    ///
+    /// - `enabled` (`bool`) - Whether to reject datasets with [Violation]s
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The updated [DeweyBuilder]
+    pub fn validate(mut self, enabled: bool) -> Self {
+        self.validate = enabled;
+        self
+    }
+
+    /// Loads the dataset
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Dewey, LoadError>` - A handle to the loaded dataset, or an error if it can't be read, parsed, or is invalid
+    pub fn build(self) -> Result<Dewey, LoadError> {
+        let (mut classes, raw) = match self.source {
+            DeweySource::Embedded if self.include.is_none() => return Ok(Dewey::standard()),
+            DeweySource::Embedded => {
+                let nodes = source_nodes(RAW_TREE);
+                let mut classes = Vec::new();
+                node_classes(&mut classes, &nodes, None);
+                #[cfg(feature = "notes")]
+                for class in &mut classes {
+                    class.notes = Dewey::standard().class_ref(&class.code).and_then(|embedded| embedded.notes.clone());
+                }
+                (classes, RAW_TREE)
+            }
+            DeweySource::Nodes(nodes) => {
+                let mut classes = Vec::new();
+                node_classes(&mut classes, &nodes, None);
+                (classes, raw_nodes(&nodes))
+            }
+            DeweySource::Entries(entries) => {
+                let classes = entries
+                    .into_iter()
+                    .map(|entry| Class::local(entry.code, entry.name, entry.parent_code))
+                    .collect();
+                (classes, &[][..])
+            }
+            #[cfg(feature = "serde")]
+            DeweySource::Json(json) => {
+                let nodes = serde_json::from_str::<Vec<SourceNode>>(&json)
+                    .map_err(|error| LoadError::Format(error.to_string()))?;
+                return DeweyBuilder { source: DeweySource::Nodes(nodes), ..self }.build();
+            }
+            #[cfg(feature = "serde")]
+            DeweySource::File(path) => {
+                let json = fs::read_to_string(path).map_err(|error| LoadError::Io(error.to_string()))?;
+                return DeweyBuilder { source: DeweySource::Json(json), ..self }.build();
+            }
+        };
+
+        if let Some(include) = &self.include {
+            classes.retain(|class| class.code.chars().next().is_some_and(|c| include.contains(&c)));
+        }
+        if self.validate {
+            let violations = dataset::check(&classes);
+            if !violations.is_empty() {
+                return Err(LoadError::Invalid(violations));
+            }
+        }
+
+        Ok(Dewey::loaded(Box::leak(Box::new(Dataset::from_classes(classes, raw)))))
+    }
+}
+
+impl Dewey {
+    /// Starts building a handle to a dataset loaded at runtime, see [DeweyBuilder]
+    ///
+    /// # Returns
+    ///
+    /// - `DeweyBuilder` - A new [DeweyBuilder]
+    pub fn builder() -> DeweyBuilder {
+        DeweyBuilder::new()
+    }
+
+    /// Gets where this handle's classes were taken from, ie to assert that a build didn't use the network
+    ///
+    /// # Returns
+    ///
+    /// - `DatasetSource` - The [DatasetSource] of the dataset behind this handle
+    pub fn dataset_source(&self) -> DatasetSource {
+        self.dataset().source
+    }
+
+    /// Gets the edition of the classification behind this handle, ie to check which captions an institution's build embeds
+    ///
+    /// # Returns
+    ///
+    /// - `Option<DatasetEdition>` - The [DatasetEdition] selected at compile time, or [None] for datasets loaded at runtime
+    pub fn edition(&self) -> Option<DatasetEdition> {
+        (self.dataset_source() != DatasetSource::Runtime).then_some(EMBEDDED_EDITION)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_builder() {
+        let dewey = Dewey::builder().include(['5']).build().unwrap();
+        assert_eq!(dewey.categories().len(), 1);
+        assert_eq!(dewey.get_class("51").unwrap().name, "Mathematics");
+        assert!(dewey.get_class("6").is_none() && Dewey::standard().get_class("6").is_some(), "Expected separate datasets");
+        assert_eq!(dewey.get_class("5").unwrap().descendant_count, Dewey::standard().get_class("5").unwrap().descendant_count);
+        assert!(dewey.classes_named_like("math").iter().all(|class| class.code.starts_with('5')));
+        assert_eq!(dewey.search("geometry")[0].code, "516");
+
+        assert_eq!(dewey.dataset_source(), DatasetSource::Runtime);
+        assert_eq!((dewey.edition(), Dewey::standard().edition()), (None, Some(EMBEDDED_EDITION)));
+        if cfg!(feature = "offline") && option_env!("DEWEY_SOURCE_FILE").is_none_or(|path| path.trim().is_empty()) {
+            assert_eq!(Dewey::standard().dataset_source(), DatasetSource::Vendored, "Expected offline builds to use the vendored dataset");
+        }
+
+        let invalid = Dewey::builder().source(DeweySource::Entries(vec![Entry::new("51", "Orphan", Some("5"))])).build();
+        assert!(matches!(invalid, Err(LoadError::Invalid(_))), "Expected the missing parent to be reported");
+    }
+
+    #[test]
+    fn test_builder_cycles() {
+        let cyclic = vec![Entry::new("5", "Science", Some("51")), Entry::new("51", "Maths", Some("5"))];
+        let dewey = Dewey::builder().source(DeweySource::Entries(cyclic)).validate(false).build().unwrap();
+        let (science, maths) = (dewey.get_class("5").unwrap(), dewey.get_class("51").unwrap());
+        assert_eq!((science.child_count, science.descendant_count), (1, 1), "Expected the cycle to be counted once");
+        assert_eq!((maths.child_count, maths.descendant_count), (1, 1));
+
+        let looping = vec![Entry::new("5", "Science", Some("5"))];
+        let dewey = Dewey::builder().source(DeweySource::Entries(looping)).validate(false).build().unwrap();
+        assert_eq!(dewey.get_class("5").unwrap().descendant_count, 0, "Expected a self-parented class not to descend from itself");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_builder_json() {
+        let json = r#"[{"name": "Science", "short": "5XX", "children": [
+            {"name": "Mathematics", "short": "51X", "children": [{"name": "Geometry", "short": "516", "notes": "Including analytic geometries"}]},
+            {"name": "Ranges", "short": "521–522"}
+        ]}]"#;
+        let dewey = Dewey::builder().source(DeweySource::Json(json.to_string())).build().unwrap();
+        assert_eq!(dewey.get_parent("516").unwrap().code, "51");
+        assert!(matches!(dewey.get_class("516").unwrap().name, Cow::Borrowed(_)), "Expected loaded strings to be interned");
+        assert_eq!(dewey.get_direct_children("5").len(), 1, "Expected ranges to be skipped");
+        assert_eq!(dewey.raw_tree()[0].children.len(), 2, "Expected the raw tree to be kept");
+        #[cfg(feature = "notes")]
+        assert_eq!(dewey.get_class("516").unwrap().notes.as_deref(), Some("Including analytic geometries"));
+
+        let missing = Dewey::builder().source(DeweySource::File("missing.json".into())).build();
+        assert!(matches!(missing, Err(LoadError::Io(_))));
+    }
+}
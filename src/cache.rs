@@ -0,0 +1,180 @@
+use std::{ collections::{ BTreeMap, HashMap }, sync::Mutex };
+
+use crate::{ Class, Dewey };
+
+/// Default number of queries kept by each dataset's cache
+pub const DEFAULT_CACHE_CAPACITY: usize = 1024;
+
+/// Snapshot of a query cache's metrics
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct CacheStats {
+    /// Number of queries answered from the cache
+    pub hits: u64,
+
+    /// Number of queries that had to be computed
+    pub misses: u64,
+
+    /// Number of queries currently cached
+    pub entries: usize,
+
+    /// Maximum number of queries kept
+    pub capacity: usize,
+}
+
+#[derive(Debug)]
+struct CacheState {
+    entries: HashMap<String, (u64, Vec<Class>)>,
+    /// Keys of the entries by the tick they were last used at, so the least recently used entry is the first one
+    recency: BTreeMap<u64, String>,
+    tick: u64,
+    stats: CacheStats,
+}
+
+/// Bounded least-recently-used cache of query results
+#[derive(Debug)]
+pub(crate) struct QueryCache {
+    state: Mutex<CacheState>,
+}
+
+impl Default for QueryCache {
+    fn default() -> Self {
+        Self {
+            state: Mutex::new(CacheState {
+                entries: HashMap::new(),
+                recency: BTreeMap::new(),
+                tick: 0,
+                stats: CacheStats { capacity: DEFAULT_CACHE_CAPACITY, ..Default::default() },
+            }),
+        }
+    }
+}
+
+impl QueryCache {
+    pub(crate) fn get_or_insert(&self, key: String, compute: impl FnOnce() -> Vec<Class>) -> Vec<Class> {
+        {
+            let mut state = self.state.lock().unwrap();
+            let state = &mut *state;
+            state.tick += 1;
+            if let Some((used, value)) = state.entries.get_mut(&key) {
+                state.recency.remove(used);
+                *used = state.tick;
+                state.recency.insert(state.tick, key);
+                state.stats.hits += 1;
+                return value.clone();
+            }
+            state.stats.misses += 1;
+        }
+
+        let value = compute();
+        let mut state = self.state.lock().unwrap();
+        if state.stats.capacity > 0 {
+            if state.entries.len() >= state.stats.capacity && !state.entries.contains_key(&key) {
+                evict(&mut state, 1);
+            }
+            state.tick += 1;
+            let tick = state.tick;
+            if let Some((used, _)) = state.entries.insert(key.clone(), (tick, value.clone())) {
+                state.recency.remove(&used);
+            }
+            state.recency.insert(tick, key);
+        }
+        value
+    }
+
+    fn stats(&self) -> CacheStats {
+        let state = self.state.lock().unwrap();
+        CacheStats { entries: state.entries.len(), ..state.stats }
+    }
+
+    fn clear(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.entries.clear();
+        state.recency.clear();
+        state.stats.hits = 0;
+        state.stats.misses = 0;
+    }
+
+    fn set_capacity(&self, capacity: usize) {
+        let mut state = self.state.lock().unwrap();
+        state.stats.capacity = capacity;
+        let excess = state.entries.len().saturating_sub(capacity);
+        evict(&mut state, excess);
+    }
+}
+
+/// Evicts up to `count` of the least recently used entries
+fn evict(state: &mut CacheState, count: usize) {
+    for _ in 0..count {
+        match state.recency.pop_first() {
+            Some((_, key)) => {
+                state.entries.remove(&key);
+            }
+            None => break,
+        }
+    }
+}
+
+impl Dewey {
+    /// Gets the metrics of this dataset's query cache
+    ///
+    /// # Returns
+    ///
+    /// - `CacheStats` - Current [CacheStats]
+    pub fn cache_stats(&self) -> CacheStats {
+        self.dataset().cache.stats()
+    }
+
+    /// Empties this dataset's query cache and resets its metrics
+    pub fn clear_cache(&self) {
+        self.dataset().cache.clear();
+    }
+
+    /// Sets the maximum number of queries kept by this dataset's cache, evicting the least recently used queries if needed
+    ///
+    /// # Arguments
+    ///
+    /// - `capacity` (`usize`) - Maximum number of cached queries (`0` disables caching)
+    pub fn set_cache_capacity(&self, capacity: usize) {
+        self.dataset().cache.set_capacity(capacity);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_lru() {
+        let cache = QueryCache::default();
+        cache.set_capacity(2);
+        let computed = |code: &str| vec![Dewey::standard().get_class(code).unwrap()];
+
+        let _ = cache.get_or_insert("a".to_string(), || computed("5"));
+        let _ = cache.get_or_insert("b".to_string(), || computed("6"));
+        let _ = cache.get_or_insert("a".to_string(), || unreachable!());
+        let _ = cache.get_or_insert("c".to_string(), || computed("7"));
+        assert_eq!(cache.get_or_insert("a".to_string(), || unreachable!())[0].code, "5");
+        assert_eq!(cache.get_or_insert("b".to_string(), || computed("8"))[0].code, "8", "Expected eviction");
+
+        let stats = cache.stats();
+        assert_eq!((stats.hits, stats.misses, stats.entries, stats.capacity), (2, 4, 2, 2));
+        assert_eq!(cache.state.lock().unwrap().recency.len(), 2, "Expected one recency entry per cached query");
+
+        cache.set_capacity(1);
+        assert_eq!(cache.get_or_insert("b".to_string(), || unreachable!())[0].code, "8", "Expected the most recently used query to be kept");
+        cache.clear();
+        assert!(cache.state.lock().unwrap().recency.is_empty());
+    }
+
+    #[test]
+    fn test_cached_search() {
+        let dewey = Dewey::builder().include('0'..='9').build().unwrap();
+        let results = dewey.search("photography");
+        assert_eq!(dewey.search(" Photography "), results);
+        let stats = dewey.cache_stats();
+        assert_eq!((stats.hits, stats.misses), (1, 1), "Expected searches to be cached by their keywords");
+    }
+}
@@ -0,0 +1,314 @@
+use std::{ cmp::Ordering, fmt, str::FromStr };
+
+use crate::{ range::numeric_key, Class, DdcCode, Dewey, ParseCodeError, PseudoClass, ShelfMark, ShelfPosition };
+
+/// Errors produced when parsing a [CallNumber]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseCallNumberError {
+    /// The class number (first part of the call number) isn't valid DDC notation
+    InvalidNumber(ParseCodeError),
+
+    /// The class number doesn't fall under any known class
+    Unclassified(String),
+}
+
+impl fmt::Display for ParseCallNumberError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseCallNumberError::InvalidNumber(error) => write!(f, "invalid class number: {error}"),
+            ParseCallNumberError::Unclassified(number) => write!(f, "class number doesn't match any class: {number}"),
+        }
+    }
+}
+
+impl std::error::Error for ParseCallNumberError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParseCallNumberError::InvalidNumber(error) => Some(error),
+            ParseCallNumberError::Unclassified(_) => None,
+        }
+    }
+}
+
+impl From<ParseCodeError> for ParseCallNumberError {
+    fn from(error: ParseCodeError) -> Self {
+        ParseCallNumberError::InvalidNumber(error)
+    }
+}
+
+/// Full DDC call number, split into its class number (or pseudo-class mark), Cutter number, and year (ie `813.54 K37 2005` or `FIC K37`)
+///
+/// Parts are separated by whitespace, and anything after the year (volume, copy, etc) is kept as is. Formatting a call number gives its normalized form, which parses back to the same call number.
+///
+/// ```rust
+/// use dewey_decimal::{ CallNumber, ShelfMark };
+///
+/// let call_number: CallNumber = "813.54 .K37 2005 v.2".parse().unwrap();
+/// assert_eq!(call_number.number.as_ref().unwrap().as_str(), "813.54");
+/// assert_eq!(call_number.class().unwrap().code, "813");
+/// assert_eq!((call_number.cutter.as_deref(), call_number.year), (Some("K37"), Some(2005)));
+/// assert_eq!(call_number.to_string(), "813.54 K37 2005 v.2");
+///
+/// let fiction: CallNumber = "fic k37".parse().unwrap();
+/// assert!(fiction.number.is_none() && matches!(fiction.mark, ShelfMark::Pseudo(ref class) if class.mark == "FIC"));
+/// assert_eq!(fiction.to_string(), "FIC k37");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+pub struct CallNumber {
+    /// The class number, without segmentation marks (ie `813.54` for `813/.54`), or [None] for call numbers starting with a pseudo-class mark (ie `FIC K37`)
+    pub number: Option<DdcCode>,
+
+    /// What the call number is shelved under: the most specific [Class] the class number falls under (see [Dewey::classify]), or the pseudo-class (see [Dewey::parse_shelf_mark])
+    pub mark: ShelfMark,
+
+    /// The Cutter (or author) number, without its leading period (ie `K37`)
+    pub cutter: Option<String>,
+
+    /// The publication year
+    pub year: Option<u16>,
+
+    /// Remaining parts, such as volume or copy numbers (ie `v.2`)
+    pub extras: Vec<String>,
+}
+
+/// Checks whether a part of a call number is a Cutter number: letters, optionally followed by digits & work marks (ie `K37`, `K37a` or `KIN`)
+fn is_cutter(part: &str) -> bool {
+    part.starts_with(|c: char| c.is_ascii_alphabetic()) && part.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+fn parse_year(part: &str) -> Option<u16> {
+    (part.len() == 4 && part.chars().all(|c| c.is_ascii_digit())).then(|| part.parse().ok()).flatten()
+}
+
+/// First part of a call number, before its class number is resolved
+enum Lead {
+    Number(DdcCode),
+    Pseudo(PseudoClass),
+}
+
+/// Parts of a call number, before its class number is resolved
+type Parts = (Lead, Option<String>, Option<u16>, Vec<String>);
+
+fn split_call_number(dewey: &Dewey, input: &str) -> Result<Parts, ParseCodeError> {
+    let mut parts = input.split_whitespace();
+    let first = parts.next().unwrap_or_default();
+    let lead = match dewey.pseudo_class(first) {
+        Some(class) => Lead::Pseudo(class),
+        None => Lead::Number(DdcCode::new(first.replace(['/', '\''], ""))?),
+    };
+
+    let mut parts = parts.peekable();
+    let cutter = parts
+        .next_if(|part| is_cutter(part.trim_start_matches('.')))
+        .map(|part| part.trim_start_matches('.').to_string());
+    let year = parts.next_if(|part| parse_year(part).is_some()).and_then(parse_year);
+    Ok((lead, cutter, year, parts.map(str::to_string).collect()))
+}
+
+/// Run of digits or of other characters in an extra part, so numbers compare by value (ie `v.2` before `v.10`)
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+enum Segment {
+    Number(u64),
+    Text(String),
+}
+
+fn segments(part: &str) -> Vec<Segment> {
+    let mut segments = Vec::new();
+    let mut rest = part;
+    while let Some(first) = rest.chars().next() {
+        let length = rest.find(|c: char| c.is_ascii_digit() != first.is_ascii_digit()).unwrap_or(rest.len());
+        let (segment, remaining) = rest.split_at(length);
+        segments.push(match first.is_ascii_digit() {
+            true => Segment::Number(segment.parse().unwrap_or(u64::MAX)),
+            false => Segment::Text(segment.to_uppercase()),
+        });
+        rest = remaining;
+    }
+    segments
+}
+
+/// Shelving position of the first part of a call number: pseudo-classes shelved before numbers (by mark), class numbers (compared as decimals), then pseudo-classes shelved after numbers
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+enum Head {
+    Before(String),
+    Number(String),
+    After(String),
+}
+
+fn head(number: Option<&DdcCode>, pseudo: Option<&PseudoClass>) -> Head {
+    match (number, pseudo) {
+        (Some(number), _) => Head::Number(numeric_key(number.as_str())),
+        (None, Some(PseudoClass { mark, position: ShelfPosition::BeforeNumbers, .. })) => Head::Before(mark.clone()),
+        (None, Some(PseudoClass { mark, .. })) => Head::After(mark.clone()),
+        (None, None) => Head::After(String::new()),
+    }
+}
+
+/// Gets the key a call number is shelved by: its class number compared as a decimal (or its pseudo-class, see [ShelfPosition]), then its Cutter number (compared as a decimal too, since `K37` is `K.37`), year, and extras
+///
+/// Missing parts shelve first ("nothing before something").
+fn shelf_key(head: Head, cutter: Option<&str>, year: Option<u16>, extras: &[String]) -> impl Ord + use<> {
+    (head, cutter.map(str::to_uppercase), year, extras.iter().map(|extra| segments(extra)).collect::<Vec<_>>())
+}
+
+/// Compares two raw call numbers in DDC shelf order, see [CallNumber::cmp_shelf]
+///
+/// The class numbers don't have to be in the dataset, and call numbers starting with a pseudo-class mark of the standard dataset (see [Dewey::pseudo_classes]) are shelved before or after numbers (ie `E 123` before `001`, `FIC K37` after `999`). Call numbers that don't start with a valid class number or mark are shelved after all others, in string order.
+///
+/// # Arguments
+///
+/// - `a` (`impl AsRef<str>`) - First call number (ie `813.54 K37 2005`)
+/// - `b` (`impl AsRef<str>`) - Second call number
+///
+/// # Returns
+///
+/// - `Ordering` - How `a` is shelved relative to `b`
+pub fn shelf_cmp(a: impl AsRef<str>, b: impl AsRef<str>) -> Ordering {
+    let key = |input: &str| {
+        split_call_number(&Dewey::standard(), input).ok().map(|(lead, cutter, year, extras)| {
+            let head = match &lead {
+                Lead::Number(number) => head(Some(number), None),
+                Lead::Pseudo(class) => head(None, Some(class)),
+            };
+            shelf_key(head, cutter.as_deref(), year, &extras)
+        })
+    };
+    match (key(a.as_ref()), key(b.as_ref())) {
+        (Some(a), Some(b)) => a.cmp(&b),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => a.as_ref().cmp(b.as_ref()),
+    }
+}
+
+impl CallNumber {
+    /// Gets the class the class number falls under
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&Class>` - The most specific [Class] of the class number, or [None] for pseudo-classes
+    pub fn class(&self) -> Option<&Class> {
+        match &self.mark {
+            ShelfMark::Class(class) => Some(class),
+            ShelfMark::Pseudo(_) => None,
+        }
+    }
+
+    /// Compares two call numbers in DDC shelf order: class numbers as decimals (so `813.54` comes before `813.6`), with pseudo-classes before or after them (see [ShelfPosition]), then Cutter numbers, years, and extras
+    ///
+    /// # Arguments
+    ///
+    /// - `other` (`&CallNumber`) - Call number to compare to
+    ///
+    /// # Returns
+    ///
+    /// - `Ordering` - How this call number is shelved relative to `other`
+    pub fn cmp_shelf(&self, other: &CallNumber) -> Ordering {
+        let key = |call_number: &CallNumber| {
+            let pseudo = match &call_number.mark {
+                ShelfMark::Pseudo(class) => Some(class),
+                ShelfMark::Class(_) => None,
+            };
+            let head = head(call_number.number.as_ref(), pseudo);
+            shelf_key(head, call_number.cutter.as_deref(), call_number.year, &call_number.extras)
+        };
+        key(self).cmp(&key(other))
+    }
+}
+
+impl Dewey {
+    /// Parses a full call number, resolving its class number against this dataset
+    ///
+    /// Call numbers may start with a registered pseudo-class mark instead of a class number (case-insensitive, see [Dewey::pseudo_classes]).
+    ///
+    /// # Arguments
+    ///
+    /// - `input` (`impl AsRef<str>`) - Call number (ie `813.54 K37 2005` or `FIC K37`)
+    ///
+    /// # Returns
+    ///
+    /// - `Result<CallNumber, ParseCallNumberError>` - The parsed [CallNumber], or why it couldn't be parsed
+    pub fn parse_call_number(&self, input: impl AsRef<str>) -> Result<CallNumber, ParseCallNumberError> {
+        let (lead, cutter, year, extras) = split_call_number(self, input.as_ref())?;
+        let (number, mark) = match lead {
+            Lead::Number(number) => {
+                let class = self.classify(&number).ok_or_else(|| ParseCallNumberError::Unclassified(number.to_string()))?;
+                (Some(number), ShelfMark::Class(class))
+            }
+            Lead::Pseudo(class) => (None, ShelfMark::Pseudo(class)),
+        };
+        Ok(CallNumber { number, mark, cutter, year, extras })
+    }
+}
+
+impl FromStr for CallNumber {
+    type Err = ParseCallNumberError;
+
+    /// Parses a call number against the standard dataset, see [Dewey::parse_call_number]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Dewey::standard().parse_call_number(s)
+    }
+}
+
+impl fmt::Display for CallNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.number, &self.mark) {
+            (Some(number), _) => write!(f, "{number}")?,
+            (None, ShelfMark::Pseudo(class)) => write!(f, "{}", class.mark)?,
+            (None, ShelfMark::Class(class)) => write!(f, "{}", class.code)?,
+        }
+        if let Some(cutter) = &self.cutter {
+            write!(f, " {cutter}")?;
+        }
+        if let Some(year) = self.year {
+            write!(f, " {year}")?;
+        }
+        for extra in &self.extras {
+            write!(f, " {extra}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_call_numbers() {
+        let call_number: CallNumber = "813.54 K37 2005".parse().unwrap();
+        assert_eq!((call_number.number.as_ref().unwrap().as_str(), call_number.class().unwrap().code.as_ref()), ("813.54", "813"));
+        assert_eq!((call_number.cutter.as_deref(), call_number.year), (Some("K37"), Some(2005)));
+        assert!(call_number.extras.is_empty());
+
+        for input in ["813.54 K37 2005", "516 KIN", "621.39 B47a c.2", "005.133 2019", "5", "FIC K37", "E 2019"] {
+            let parsed: CallNumber = input.parse().unwrap();
+            assert_eq!(parsed.to_string(), input, "Expected {input} to round-trip");
+            assert_eq!(parsed.to_string().parse::<CallNumber>().unwrap(), parsed);
+        }
+
+        let call_number: CallNumber = "  516/.35  .s65  v.1 ".parse().unwrap();
+        assert_eq!(call_number.to_string(), "516.35 s65 v.1", "Expected the normalized form");
+        assert_eq!(call_number.year, None);
+
+        assert_eq!("".parse::<CallNumber>().unwrap_err(), ParseCallNumberError::InvalidNumber(ParseCodeError::Empty));
+        let fiction: CallNumber = "fic K37 2005".parse().unwrap();
+        assert_eq!((fiction.number.as_ref(), fiction.class(), fiction.cutter.as_deref(), fiction.year), (None, None, Some("K37"), Some(2005)));
+        assert!(matches!("XYZ K37".parse::<CallNumber>(), Err(ParseCallNumberError::InvalidNumber(_))));
+    }
+
+    #[test]
+    fn test_shelf_order() {
+        let shelved = ["E K37", "51", "510.9", "516", "516 K37", "516 K4", "516 KIN", "516.35", "813.54 K37", "813.54 K37 1999", "813.54 K37 2005", "813.54 K37 2005 v.2", "813.54 K37 2005 v.10", "813.6", "B LIN", "FIC A1", "FIC K37"];
+        let mut call_numbers = shelved.iter().rev().map(|input| input.parse::<CallNumber>().unwrap()).collect::<Vec<_>>();
+        call_numbers.sort_by(CallNumber::cmp_shelf);
+        assert_eq!(call_numbers.iter().map(CallNumber::to_string).collect::<Vec<_>>(), shelved);
+
+        let mut raw = vec!["XYZ 1", "FIC KIN", "813.6", "E 123", "008.1 A1", "999 Z9", "813.54 k37"];
+        raw.sort_by(|a, b| shelf_cmp(a, b));
+        assert_eq!(raw, ["E 123", "008.1 A1", "813.54 k37", "813.6", "999 Z9", "FIC KIN", "XYZ 1"], "Expected pseudo-classes around numbers & invalid call numbers last");
+        assert_eq!(shelf_cmp("813.54 K37", "813.54 k37"), Ordering::Equal, "Expected Cutter numbers to ignore case");
+    }
+}
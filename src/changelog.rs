@@ -0,0 +1,168 @@
+use crate::{ code_label, Dewey, CHANGELOG };
+
+/// Change to a single class between two releases of the bundled dataset, or between two datasets (see [Dewey::diff])
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(tag = "kind", rename_all = "snake_case"))]
+pub enum DatasetChange {
+    /// A class was added
+    Added {
+        /// Code of the new class
+        code: &'static str,
+
+        /// Name of the new class
+        name: &'static str,
+    },
+
+    /// A class was renamed
+    Renamed {
+        /// Code of the class
+        code: &'static str,
+
+        /// Previous name of the class
+        from: &'static str,
+
+        /// New name of the class
+        to: &'static str,
+    },
+
+    /// A class was removed
+    Removed {
+        /// Code of the removed class
+        code: &'static str,
+
+        /// Last name of the removed class
+        name: &'static str,
+    },
+}
+
+impl DatasetChange {
+    /// Gets the code of the class this change concerns
+    ///
+    /// # Returns
+    ///
+    /// - `&'static str` - Code of the added, renamed, or removed class
+    pub fn code(&self) -> &'static str {
+        match self {
+            DatasetChange::Added { code, .. } | DatasetChange::Renamed { code, .. } | DatasetChange::Removed { code, .. } =>
+                code,
+        }
+    }
+}
+
+/// Entry of the bundled dataset's changelog (generated from `changelog.json`)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ChangelogEntry {
+    /// Crate version that introduced the change
+    pub version: &'static str,
+
+    /// The change itself
+    pub change: DatasetChange,
+}
+
+/// Parses a version into its major, minor & patch numbers, with missing ones as `0` (so `1.0` equals `1.0.0`)
+fn parse_version(version: &str) -> [u64; 3] {
+    let mut parsed = [0; 3];
+    let parts = version.trim().trim_start_matches('v').split(['.', '-', '+']);
+    for (number, part) in parsed.iter_mut().zip(parts) {
+        *number = part.parse().unwrap_or(0);
+    }
+    parsed
+}
+
+impl Dewey {
+    /// Gets every change to the bundled dataset made after a crate release, ie to react to an upgrade
+    ///
+    /// # Arguments
+    ///
+    /// - `version` (`impl AsRef<str>`) - Crate version to compare against (ie `1.0.2`)
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<ChangelogEntry>` - [Vec] of changes introduced by later versions, oldest first
+    pub fn changes_since(&self, version: impl AsRef<str>) -> Vec<ChangelogEntry> {
+        let version = parse_version(version.as_ref());
+        CHANGELOG.iter()
+            .filter(|entry| parse_version(entry.version) > version)
+            .copied()
+            .collect()
+    }
+
+    /// Compares this dataset with another, ie to review the delta between the embedded dataset and a fetched snapshot before a release
+    ///
+    /// Classes are matched by code, so a class that moved to another code is reported as removed & added.
+    ///
+    /// ```rust
+    /// use dewey_decimal::{ dataset::Entry, DatasetChange, Dewey, DeweySource };
+    ///
+    /// let snapshot = Dewey::builder()
+    ///     .source(DeweySource::Entries(vec![Entry::new("5", "Sciences", None), Entry::new("6", "Technology", None)]))
+    ///     .build()
+    ///     .unwrap();
+    /// let changes = Dewey::standard().diff(&snapshot);
+    /// assert!(changes.contains(&DatasetChange::Renamed { code: "5", from: "Science", to: "Sciences" }));
+    /// assert!(changes.iter().any(|change| matches!(change, DatasetChange::Removed { code: "51", .. })));
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// - `other` (`&Dewey`) - Newer dataset to compare against
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<DatasetChange>` - [Vec] of classes added, renamed, or removed in `other`, in shelf order
+    pub fn diff(&self, other: &Dewey) -> Vec<DatasetChange> {
+        let mut changes = self
+            .all_ref()
+            .filter_map(|class| match other.class_ref(&class.code) {
+                None => Some(DatasetChange::Removed { code: &class.code, name: &class.name }),
+                Some(renamed) if renamed.name != class.name =>
+                    Some(DatasetChange::Renamed { code: &class.code, from: &class.name, to: &renamed.name }),
+                Some(_) => None,
+            })
+            .chain(
+                other
+                    .all_ref()
+                    .filter(|class| self.class_ref(&class.code).is_none())
+                    .map(|class| DatasetChange::Added { code: &class.code, name: &class.name })
+            )
+            .collect::<Vec<_>>();
+        changes.sort_by_key(|change| code_label(change.code()));
+        changes
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_changes_since() {
+        assert!(parse_version("1.0.10") > parse_version("v1.0.2"));
+        assert_eq!(parse_version("1.1.0-beta.1"), [1, 1, 0]);
+        assert_eq!(parse_version("1.0"), parse_version("1.0.0"), "Expected missing components to be padded with zeros");
+        assert_eq!(Dewey::standard().changes_since("1.0"), Dewey::standard().changes_since("1.0.0"));
+
+        let current = env!("CARGO_PKG_VERSION");
+        assert!(Dewey::standard().changes_since(current).is_empty(), "Expected no changes after the current release");
+        assert_eq!(Dewey::standard().changes_since("0.0.0"), CHANGELOG.to_vec());
+    }
+
+    #[test]
+    fn test_diff() {
+        use crate::{ dataset::Entry, DeweySource };
+
+        let load = |entries: Vec<Entry>| Dewey::builder().source(DeweySource::Entries(entries)).build().unwrap();
+        let old = load(vec![Entry::new("5", "Science", None), Entry::new("51", "Mathematics", Some("5")), Entry::new("52", "Astronomy", Some("5"))]);
+        let new = load(vec![Entry::new("5", "Sciences", None), Entry::new("51", "Mathematics", Some("5")), Entry::new("516", "Geometry", Some("51"))]);
+
+        assert_eq!(old.diff(&new), vec![
+            DatasetChange::Renamed { code: "5", from: "Science", to: "Sciences" },
+            DatasetChange::Added { code: "516", name: "Geometry" },
+            DatasetChange::Removed { code: "52", name: "Astronomy" },
+        ]);
+        assert!(Dewey::standard().diff(&Dewey::standard()).is_empty(), "Expected no changes to the same dataset");
+        assert_eq!(new.diff(&old).len(), 3);
+    }
+}
@@ -0,0 +1,288 @@
+use std::{ fmt, str::FromStr };
+
+use crate::{ lenient_code, Class, CodeStyle, Dewey, DeweyError };
+
+/// Reason a string isn't a valid [DdcCode]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ParseCodeError {
+    /// The code is empty (or only padding)
+    Empty,
+
+    /// The code contains something other than digits & a decimal point
+    InvalidCharacter {
+        /// The offending character
+        character: char,
+
+        /// Byte offset of the character in the (trimmed) code
+        index: usize,
+    },
+
+    /// The decimal point isn't directly after the third digit, isn't followed by digits, or is missing from a code longer than three digits
+    MisplacedDecimalPoint,
+
+    /// The number is too large to be a section (above `999`)
+    OutOfRange(u16),
+}
+
+impl fmt::Display for ParseCodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseCodeError::Empty => write!(f, "empty DDC code"),
+            ParseCodeError::InvalidCharacter { character, index } =>
+                write!(f, "invalid character {character:?} at index {index} of DDC code"),
+            ParseCodeError::MisplacedDecimalPoint =>
+                write!(f, "the decimal point of a DDC code must follow the third digit, and be followed by digits"),
+            ParseCodeError::OutOfRange(number) => write!(f, "{number} is out of range for a DDC section (000–999)"),
+        }
+    }
+}
+
+impl std::error::Error for ParseCodeError {}
+
+/// Validated DDC code, in the trimmed form used throughout the crate (ie `5` for `5XX`, `813.54`)
+///
+/// Codes are digits, with an optional decimal segment after the third digit. `X` padding and surrounding whitespace are removed when parsing. Codes order in shelf order, and can be passed to every API taking a code.
+///
+/// ```rust
+/// use dewey_decimal::{ DdcCode, Dewey };
+///
+/// let code: DdcCode = "51X".parse().unwrap();
+/// assert_eq!(code.as_str(), "51");
+/// assert_eq!(Dewey::standard().get_class(&code).unwrap().name, "Mathematics");
+/// assert!("12a".parse::<DdcCode>().is_err());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "String", into = "String"))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+#[cfg_attr(feature = "diesel", derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow))]
+#[cfg_attr(feature = "diesel", diesel(sql_type = diesel::sql_types::Text))]
+pub struct DdcCode(String);
+
+impl DdcCode {
+    /// Parses & validates a code
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code to parse (ie `813.54` or `51X`)
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Self, ParseCodeError>` - The validated [DdcCode], or why the code is invalid
+    pub fn new(code: impl AsRef<str>) -> Result<Self, ParseCodeError> {
+        let code = code.as_ref().trim().trim_end_matches('X');
+        if code.is_empty() {
+            return Err(ParseCodeError::Empty);
+        }
+
+        if let Some((index, character)) = code.char_indices().find(|(_, c)| !c.is_ascii_digit() && *c != '.') {
+            return Err(ParseCodeError::InvalidCharacter { character, index });
+        }
+        match code.split_once('.') {
+            Some((integer, fraction)) if integer.len() != 3 || fraction.is_empty() || fraction.contains('.') =>
+                return Err(ParseCodeError::MisplacedDecimalPoint),
+            None if code.len() > 3 => return Err(ParseCodeError::MisplacedDecimalPoint),
+            _ => {}
+        }
+        Ok(Self(code.to_string()))
+    }
+
+    /// Parses a loosely written code, as found in real-world records
+    ///
+    /// Whitespace anywhere, `X` padding in either case, and segmentation marks (`/`, `'` & `′`) are dropped before validating, and a decimal point is added after the third digit of longer codes written without one. Misplaced decimal points & other characters are still rejected.
+    ///
+    /// ```rust
+    /// use dewey_decimal::DdcCode;
+    ///
+    /// assert_eq!(DdcCode::parse_lenient("005/13").unwrap().as_str(), "005.13");
+    /// assert_eq!(DdcCode::parse_lenient(" 09x ").unwrap().as_str(), "09");
+    /// assert!(DdcCode::parse_lenient("81.3").is_err());
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code to parse (ie ` 51 `, `09X` or `005/13`)
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Self, ParseCodeError>` - The validated [DdcCode], or why the cleaned-up code is invalid
+    pub fn parse_lenient(code: impl AsRef<str>) -> Result<Self, ParseCodeError> {
+        Self::new(lenient_code(code.as_ref()))
+    }
+
+    /// Gets the code as a string
+    ///
+    /// # Returns
+    ///
+    /// - `&str` - The trimmed code (ie `51`)
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Renders the code in canonical DDC notation, padded to three digits regardless of the crate-wide [CodeStyle]
+    ///
+    /// Main classes pad to `000`–`900`, divisions to `010`–`990`, and sections & subsections are unchanged (ie `5` → `500`, `51` → `510`, `516.3` → `516.3`).
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The padded code
+    pub fn normalize(&self) -> String {
+        CodeStyle::Padded.format(&self.0)
+    }
+
+    /// Gets the code as an integer, ie for datasets storing sections as numbers (`813`, or `5` for `005`)
+    ///
+    /// Only sections (three digits without a decimal part) have a numeric form. Main classes & divisions don't, as `500` & `510` are the sections of the same numbers rather than `5` & `51` themselves.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<u16>` - The number of the section, or [None] if the code isn't a section
+    pub fn to_number(&self) -> Option<u16> {
+        if self.0.len() == 3 { self.0.parse().ok() } else { None }
+    }
+
+    /// Gets the class with this code from the standard dataset
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Class>` - The matching [Class], or [None] if the code isn't assigned
+    pub fn class(&self) -> Option<Class> {
+        Dewey::standard().get_class(self)
+    }
+}
+
+impl FromStr for DdcCode {
+    type Err = ParseCodeError;
+
+    fn from_str(code: &str) -> Result<Self, Self::Err> {
+        Self::new(code)
+    }
+}
+
+impl TryFrom<&str> for DdcCode {
+    type Error = ParseCodeError;
+
+    fn try_from(code: &str) -> Result<Self, Self::Error> {
+        Self::new(code)
+    }
+}
+
+impl TryFrom<String> for DdcCode {
+    type Error = ParseCodeError;
+
+    fn try_from(code: String) -> Result<Self, Self::Error> {
+        Self::new(code)
+    }
+}
+
+/// Converts a section number into its code (ie `813`, or `5` into `005`)
+impl TryFrom<u16> for DdcCode {
+    type Error = ParseCodeError;
+
+    fn try_from(number: u16) -> Result<Self, Self::Error> {
+        if number > 999 {
+            return Err(ParseCodeError::OutOfRange(number));
+        }
+        Ok(Self(format!("{number:03}")))
+    }
+}
+
+impl From<DdcCode> for String {
+    fn from(code: DdcCode) -> Self {
+        code.0
+    }
+}
+
+impl AsRef<str> for DdcCode {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for DdcCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Gets the section with a number from the standard dataset (see [DdcCode::try_from])
+impl TryFrom<u16> for Class {
+    type Error = DeweyError;
+
+    fn try_from(number: u16) -> Result<Self, Self::Error> {
+        Dewey::standard().try_get_class(DdcCode::try_from(number)?)
+    }
+}
+
+impl Class {
+    /// See [DdcCode::to_number]
+    pub fn to_number(&self) -> Option<u16> {
+        self.ddc_code().to_number()
+    }
+
+    /// Gets the code of this class as a [DdcCode]
+    ///
+    /// Codes of the embedded dataset are always valid, as are those of datasets loaded with validation (see [crate::DeweyBuilder::validate]).
+    ///
+    /// # Returns
+    ///
+    /// - `DdcCode` - The code of this class
+    pub fn ddc_code(&self) -> DdcCode {
+        DdcCode(self.code.to_string())
+    }
+
+    /// Gets the code of this class in canonical DDC notation, regardless of the crate-wide [CodeStyle] (see [DdcCode::normalize])
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The padded code (ie `000`, `510` or `516`)
+    pub fn normalized_code(&self) -> String {
+        CodeStyle::Padded.format(&self.code)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_ddc_code() {
+        assert_eq!(DdcCode::new(" 5XX ").unwrap().as_str(), "5");
+        assert_eq!("813.54".parse::<DdcCode>().unwrap().to_string(), "813.54");
+        assert_eq!(DdcCode::new("12a"), Err(ParseCodeError::InvalidCharacter { character: 'a', index: 2 }));
+        assert_eq!(DdcCode::new("XXX"), Err(ParseCodeError::Empty));
+        for invalid in ["81.3", "813.", "813.5.4", "0081", "81354"] {
+            assert_eq!(DdcCode::new(invalid), Err(ParseCodeError::MisplacedDecimalPoint), "Expected {invalid} to be rejected");
+        }
+
+        let mut codes = ["814", "813.54", "8", "813"].map(|code| DdcCode::new(code).unwrap());
+        codes.sort();
+        assert_eq!(codes.map(String::from), ["8", "813", "813.54", "814"], "Expected shelf order");
+
+        let class = DdcCode::new("516").unwrap().class().unwrap();
+        assert_eq!(class.ddc_code(), DdcCode::new("516").unwrap());
+
+        for (input, expected) in [("004.678", "004.678"), (" 51 ", "51"), ("09X", "09"), ("005/13", "005.13"), ("005′13", "005.13"), ("5xx", "5"), ("813 .54", "813.54")] {
+            assert_eq!(DdcCode::parse_lenient(input).map(String::from).as_deref(), Ok(expected), "Unexpected result for {input:?}");
+        }
+        assert_eq!(DdcCode::parse_lenient("81.3"), Err(ParseCodeError::MisplacedDecimalPoint));
+        assert_eq!(DdcCode::parse_lenient("0081"), DdcCode::new("008.1"), "Expected the decimal point to be inserted");
+        assert!(DdcCode::parse_lenient("  ").is_err() && DdcCode::parse_lenient("51a").is_err());
+        assert_eq!(Dewey::standard().get_class(" 51 ").unwrap().code, "51");
+        assert_eq!(Dewey::standard().get_class("00/4").unwrap().code, "004");
+        assert_eq!(Dewey::standard().children_of(" 5X ").len(), Dewey::standard().children_of("5").len());
+
+        let normalized = ["0", "02", "025", "51X", "813.54"].map(|code| DdcCode::new(code).unwrap().normalize());
+        assert_eq!(normalized, ["000", "020", "025", "510", "813.54"]);
+        assert_eq!(Dewey::standard().get_class("0").unwrap().normalized_code(), "000");
+
+        assert_eq!(DdcCode::try_from(813).unwrap().as_str(), "813");
+        assert_eq!(DdcCode::try_from(5).unwrap().as_str(), "005");
+        assert_eq!(DdcCode::try_from(1000), Err(ParseCodeError::OutOfRange(1000)));
+        assert_eq!(["813", "005", "5", "51", "813.54"].map(|code| DdcCode::new(code).unwrap().to_number()), [Some(813), Some(5), None, None, None]);
+        assert_eq!(Class::try_from(516).unwrap().name, "Geometry");
+        assert_eq!(Class::try_from(500).unwrap().to_number(), Some(500), "Expected the section rather than the main class");
+        assert_eq!(Class::try_from(1000).map(|class| class.code), Err(DeweyError::InvalidCode(ParseCodeError::OutOfRange(1000))));
+        assert!(matches!(Class::try_from(8), Err(DeweyError::NotFound(_))));
+    }
+}
@@ -0,0 +1,72 @@
+//! Implementation of the `dax-dot-gay:dewey-decimal/classes` interface from `wit/dewey-decimal.wit`
+//!
+//! Build the component with `cargo rustc --release --lib --crate-type cdylib --target wasm32-wasip2 --features component`.
+
+use std::borrow::Cow;
+
+use crate::{ dataset::is_valid_code, Dewey };
+
+wit_bindgen::generate!({
+    world: "dewey",
+    path: "wit",
+});
+
+use exports::dax_dot_gay::dewey_decimal::classes::{ Class, Guest, ValidationError };
+
+struct Component;
+
+impl From<crate::Class> for Class {
+    fn from(class: crate::Class) -> Self {
+        Class { code: class.code.into_owned(), name: class.name.into_owned(), parent_code: class.parent_code.map(Cow::into_owned) }
+    }
+}
+
+impl Guest for Component {
+    fn lookup(code: String) -> Option<Class> {
+        let code = code.trim();
+        if !is_valid_code(code.trim_end_matches('X')) {
+            return None;
+        }
+        Dewey::standard().get_class(code).map(Class::from)
+    }
+
+    fn children(code: String) -> Vec<Class> {
+        Dewey::standard().get_direct_children(code.trim()).into_iter().map(Class::from).collect()
+    }
+
+    fn search(query: String, limit: u32) -> Vec<Class> {
+        Dewey::standard()
+            .search_options()
+            .query(query)
+            .limit(limit as usize)
+            .execute()
+            .into_iter()
+            .map(Class::from)
+            .collect()
+    }
+
+    fn validate(code: String) -> Result<Class, ValidationError> {
+        let trimmed = code.trim();
+        if !is_valid_code(trimmed.trim_end_matches('X')) {
+            return Err(ValidationError::InvalidCode(code));
+        }
+        Dewey::standard().get_class(trimmed).map(Class::from).ok_or(ValidationError::NotFound(code))
+    }
+}
+
+export!(Component);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_component() {
+        assert_eq!(Component::lookup("516".to_string()).unwrap().parent_code.as_deref(), Some("51"));
+        assert!(Component::lookup("abc".to_string()).is_none(), "Expected invalid codes to be rejected");
+        assert_eq!(Component::children(String::new()).len(), 10, "Expected the main classes");
+        assert!(Component::search("music".to_string(), 3).len() <= 3);
+        assert!(matches!(Component::validate("5x".to_string()), Err(ValidationError::InvalidCode(_))));
+        assert!(Component::validate("516".to_string()).is_ok());
+    }
+}
@@ -0,0 +1,163 @@
+use std::collections::HashSet;
+
+use crate::{ lenient_code, Class, Dewey };
+
+/// Letters that OCR commonly reads in place of digits on scanned cards, with the digit they stand for
+const OCR_LETTERS: &[(char, char)] = &[
+    ('O', '0'),
+    ('o', '0'),
+    ('D', '0'),
+    ('Q', '0'),
+    ('I', '1'),
+    ('l', '1'),
+    ('i', '1'),
+    ('|', '1'),
+    ('Z', '2'),
+    ('z', '2'),
+    ('S', '5'),
+    ('s', '5'),
+    ('G', '6'),
+    ('b', '6'),
+    ('T', '7'),
+    ('B', '8'),
+    ('g', '9'),
+    ('q', '9'),
+];
+
+/// Pairs of digits that OCR commonly confuses with one another
+const OCR_DIGITS: &[(char, char)] = &[('0', '8'), ('3', '8'), ('6', '8'), ('5', '6'), ('1', '7'), ('0', '9')];
+
+/// Kind of mistake a [Correction] undoes, from the most to the least plausible
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub enum CorrectionKind {
+    /// Letters read in place of look-alike digits (ie `B13` for `813`, or `5O` for `50`)
+    Ocr,
+
+    /// Two adjacent digits swapped (ie `183` for `813`)
+    Transposition,
+
+    /// A digit replaced with one OCR commonly confuses it with (ie `318` for `818`)
+    OcrSubstitution,
+
+    /// Any other digit replaced (ie `814` for `813`)
+    Substitution,
+}
+
+/// Valid class proposed for a mistyped code by [Dewey::correct]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct Correction {
+    /// The proposed [Class]
+    pub class: Class,
+
+    /// Kind of mistake undone to reach the class
+    pub kind: CorrectionKind,
+}
+
+/// Gets the codes one edit away from a code, with the kind of each edit, from the most to the least plausible
+fn candidates(code: &str) -> Vec<(String, CorrectionKind)> {
+    let mut candidates = Vec::new();
+    let ocr = code
+        .chars()
+        .map(|c| OCR_LETTERS.iter().find(|(letter, _)| *letter == c).map_or(c, |(_, digit)| *digit))
+        .collect::<String>();
+    if ocr != code {
+        candidates.push((lenient_code(&ocr), CorrectionKind::Ocr));
+    }
+
+    let code = lenient_code(&ocr).chars().collect::<Vec<_>>();
+    let digits = code.iter().enumerate().filter(|(_, c)| c.is_ascii_digit()).map(|(index, _)| index).collect::<Vec<_>>();
+    for pair in digits.windows(2) {
+        if code[pair[0]] != code[pair[1]] {
+            let mut swapped = code.clone();
+            swapped.swap(pair[0], pair[1]);
+            candidates.push((swapped.into_iter().collect(), CorrectionKind::Transposition));
+        }
+    }
+
+    let mut substitutions = Vec::new();
+    for &index in &digits {
+        for digit in ('0'..='9').filter(|digit| *digit != code[index]) {
+            let confused = OCR_DIGITS.iter().any(|pair| *pair == (code[index], digit) || *pair == (digit, code[index]));
+            let mut substituted = code.clone();
+            substituted[index] = digit;
+            let kind = if confused { CorrectionKind::OcrSubstitution } else { CorrectionKind::Substitution };
+            substitutions.push((substituted.into_iter().collect(), kind));
+        }
+    }
+    substitutions.sort_by_key(|(_, kind)| *kind);
+    candidates.extend(substitutions);
+    candidates
+}
+
+impl Dewey {
+    /// Proposes valid classes for a mistyped code, ie to recover call numbers from scanned cards instead of dropping them
+    ///
+    /// Candidates are one mistake away from the code: letters OCR reads in place of look-alike digits (ie `B` for `8` or `O` for `0`), two adjacent digits swapped, or a single digit replaced. Only assigned classes used by the arrangement policies are proposed (see [Dewey::is_assigned] & [Dewey::arrangement_policies]).
+    ///
+    /// ```rust
+    /// use dewey_decimal::{ CorrectionKind, Dewey };
+    ///
+    /// let dewey = Dewey::standard();
+    /// let corrections = dewey.correct("B13");
+    /// assert_eq!((corrections[0].class.code.as_ref(), corrections[0].kind), ("813", CorrectionKind::Ocr));
+    /// assert!(dewey.correct("813").is_empty());
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code that failed to look up
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<Correction>` - Proposed classes, ranked by the plausibility of the mistake (see [CorrectionKind]) then in shelf order (empty if the code is already assigned)
+    pub fn correct(&self, code: impl AsRef<str>) -> Vec<Correction> {
+        let code = code.as_ref().trim();
+        if self.is_assigned(code) {
+            return Vec::new();
+        }
+
+        let mut seen = HashSet::new();
+        let mut corrections = candidates(code)
+            .into_iter()
+            .filter(|(candidate, _)| self.is_assigned(candidate) && self.is_arranged(candidate))
+            .filter_map(|(candidate, kind)| self.class_ref(candidate).map(|class| (class, kind)))
+            .filter(|(class, _)| seen.insert(&class.code))
+            .collect::<Vec<_>>();
+        corrections.sort_by(|(a, a_kind), (b, b_kind)| a_kind.cmp(b_kind).then_with(|| a.cmp(b)));
+        corrections.into_iter().map(|(class, kind)| Correction { class: class.clone(), kind }).collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_correct() {
+        let dewey = Dewey::standard();
+        let codes = |code: &str| dewey.correct(code).into_iter().map(|correction| (correction.class.code.to_string(), correction.kind)).collect::<Vec<_>>();
+
+        assert_eq!(codes("B13")[0], ("813".to_string(), CorrectionKind::Ocr));
+        assert_eq!(codes("5l6")[0], ("516".to_string(), CorrectionKind::Ocr));
+        assert_eq!(codes("O04")[0], ("004".to_string(), CorrectionKind::Ocr));
+        assert_eq!(codes("008")[0].1, CorrectionKind::Transposition, "Expected 080 or 800 to come first for an unassigned code");
+
+        let corrections = codes("5166");
+        assert!(corrections.iter().all(|(_, kind)| *kind >= CorrectionKind::OcrSubstitution));
+        assert!(corrections.is_sorted_by_key(|(_, kind)| *kind), "Expected corrections ranked by plausibility");
+        assert!(dewey.correct("813").is_empty() && dewey.correct("").is_empty());
+
+        let corrections = codes("1B3");
+        assert_eq!(corrections.first().map(|(code, _)| code.as_str()), Some("183"));
+        assert!(corrections.iter().all(|(code, _)| dewey.is_assigned(code)), "Expected only assigned classes");
+        let unique = corrections.iter().map(|(code, _)| code).collect::<HashSet<_>>();
+        assert_eq!(unique.len(), corrections.len(), "Expected each class once");
+    }
+}
@@ -0,0 +1,485 @@
+//! Utilities for validating custom datasets (runtime-loaded data, overlays, etc)
+
+use std::{ collections::HashSet, fmt };
+
+use crate::Class;
+
+/// Flat representation of a single class, as checked by [check]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct Entry {
+    /// DDC code of the class
+    pub code: String,
+
+    /// Friendly name of the class
+    pub name: String,
+
+    /// DDC code of the parent class, if known
+    pub parent_code: Option<String>,
+}
+
+impl Entry {
+    /// Creates a new entry
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - DDC code of the class
+    /// - `name` (`impl AsRef<str>`) - Friendly name of the class
+    /// - `parent_code` (`Option<&str>`) - DDC code of the parent class, if known
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - A new [Entry]
+    pub fn new(code: impl AsRef<str>, name: impl AsRef<str>, parent_code: Option<&str>) -> Self {
+        Self {
+            code: code.as_ref().to_string(),
+            name: name.as_ref().to_string(),
+            parent_code: parent_code.map(String::from),
+        }
+    }
+}
+
+impl From<&Class> for Entry {
+    fn from(class: &Class) -> Self {
+        Self::new(&class.code, &class.name, class.parent_code())
+    }
+}
+
+impl From<Class> for Entry {
+    fn from(class: Class) -> Self {
+        Self::from(&class)
+    }
+}
+
+/// Hierarchy invariant broken by a dataset
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub enum Violation {
+    /// The code is empty or isn't in DDC notation (digits, with an optional decimal point after the third digit)
+    InvalidCode {
+        /// The offending code
+        code: String,
+    },
+
+    /// More than one class uses this code
+    DuplicateCode {
+        /// The duplicated code
+        code: String,
+    },
+
+    /// The class's parent doesn't exist in the dataset
+    MissingParent {
+        /// Code of the class
+        code: String,
+
+        /// Code of the missing parent
+        parent_code: String,
+    },
+
+    /// The class's code doesn't extend its parent's code
+    ParentMismatch {
+        /// Code of the class
+        code: String,
+
+        /// Code of the parent
+        parent_code: String,
+    },
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Violation::InvalidCode { code } => write!(f, "invalid code: {code:?}"),
+            Violation::DuplicateCode { code } => write!(f, "duplicate code: {code}"),
+            Violation::MissingParent { code, parent_code } =>
+                write!(f, "parent {parent_code} of {code} doesn't exist"),
+            Violation::ParentMismatch { code, parent_code } =>
+                write!(f, "code {code} doesn't extend its parent's code {parent_code}"),
+        }
+    }
+}
+
+/// Node of a dataset in the OpenLibrary JSON format (the format of the embedded dataset)
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct SourceNode {
+    /// Friendly name of this node
+    pub name: String,
+
+    /// Code of this node, padded with `X` (ie `0XX`, `00X`) or a range (ie `007–009`)
+    pub short: String,
+
+    /// OpenLibrary query matching this node
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub query: String,
+
+    /// Number of works in this node
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub count: u64,
+
+    /// Scope notes of this node, if the dataset provides them (kept in [Class] with the `notes` feature)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub notes: Option<String>,
+
+    /// Child nodes
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub children: Vec<SourceNode>,
+}
+
+impl SourceNode {
+    /// Gets the code of this node with its `X` padding removed, or [None] if it's a range
+    ///
+    /// # Returns
+    ///
+    /// - `Option<String>` - The trimmed code, or [None] for ranges (ie `007–009`)
+    pub fn code(&self) -> Option<String> {
+        let code = self.short.trim().trim_end_matches('X');
+        if code.contains(['–', '-']) { None } else { Some(code.to_string()) }
+    }
+
+    /// Flattens nested nodes into entries, skipping ranges
+    ///
+    /// # Arguments
+    ///
+    /// - `nodes` (`&[SourceNode]`) - Top-level nodes of the dataset
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<Entry>` - [Vec] of [Entry] instances, with parents taken from the nesting
+    pub fn entries(nodes: &[SourceNode]) -> Vec<Entry> {
+        fn flatten(output: &mut Vec<Entry>, node: &SourceNode, parent_code: Option<&str>) {
+            let Some(code) = node.code() else {
+                return;
+            };
+            output.push(Entry::new(&code, &node.name, parent_code));
+            for child in &node.children {
+                flatten(output, child, Some(&code));
+            }
+        }
+
+        let mut entries = Vec::new();
+        for node in nodes {
+            flatten(&mut entries, node, None);
+        }
+        entries
+    }
+}
+
+/// Checks whether a code is in DDC notation: up to three digits, with an optional decimal point & digits after the third digit (ie `5`, `813`, `813.54`, but not `0081`)
+pub(crate) fn is_valid_code(code: &str) -> bool {
+    let (integer, fraction) = match code.split_once('.') {
+        Some((integer, fraction)) if integer.len() == 3 && !fraction.is_empty() => (integer, fraction),
+        Some(_) => return false,
+        None if code.len() > 3 => return false,
+        None => (code, ""),
+    };
+    !integer.is_empty() && integer.chars().chain(fraction.chars()).all(|c| c.is_ascii_digit())
+}
+
+/// Gets the DDC number at the start of a code (ie `621.39` for `621.39 B47`)
+pub(crate) fn leading_code(code: &str) -> &str {
+    let digits = |text: &str| text.len() - text.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+    let integer = digits(code);
+    match code[integer..].strip_prefix('.') {
+        Some(rest) if integer == 3 && digits(rest) > 0 => &code[..integer + 1 + digits(rest)],
+        _ => &code[..integer],
+    }
+}
+
+/// Gets the proper prefixes of a code that are codes themselves, shortest first (ie `8`, `81`, `813`, `813.5` for `813.54`)
+pub(crate) fn prefixes(code: &str) -> impl DoubleEndedIterator<Item = &str> {
+    (1..code.len()).map(|len| &code[..len]).filter(|prefix| !prefix.ends_with('.'))
+}
+
+/// Checks a dataset against the hierarchy invariants
+///
+/// - Codes are in DDC notation (digits, with an optional decimal point after the third digit)
+/// - Codes are unique
+/// - Declared parents exist, and every child's code extends its parent's code
+/// - Classes without a declared parent are main classes, or have an existing ancestor
+///
+/// # Arguments
+///
+/// - `source` (`impl IntoIterator<Item = impl Into<Entry>>`) - Classes of the dataset ([Class] instances, or [Entry] instances for external data)
+///
+/// # Returns
+///
+/// - `Vec<Violation>` - Every [Violation] found (empty if the dataset is valid)
+pub fn check(source: impl IntoIterator<Item = impl Into<Entry>>) -> Vec<Violation> {
+    let entries = source.into_iter().map(Into::into).collect::<Vec<Entry>>();
+    let mut violations = Vec::new();
+    let mut codes = HashSet::new();
+
+    for entry in &entries {
+        if !is_valid_code(&entry.code) {
+            violations.push(Violation::InvalidCode { code: entry.code.clone() });
+        }
+        if !codes.insert(entry.code.as_str()) {
+            violations.push(Violation::DuplicateCode { code: entry.code.clone() });
+        }
+    }
+
+    for entry in &entries {
+        match &entry.parent_code {
+            Some(parent_code) => {
+                if !entry.code.starts_with(parent_code.as_str()) || entry.code == *parent_code {
+                    violations.push(Violation::ParentMismatch {
+                        code: entry.code.clone(),
+                        parent_code: parent_code.clone(),
+                    });
+                }
+                if !codes.contains(parent_code.as_str()) {
+                    violations.push(Violation::MissingParent {
+                        code: entry.code.clone(),
+                        parent_code: parent_code.clone(),
+                    });
+                }
+            }
+            None if entry.code.len() > 1 && is_valid_code(&entry.code) => {
+                let has_ancestor = prefixes(&entry.code).any(|prefix| codes.contains(prefix));
+                if !has_ancestor {
+                    violations.push(Violation::MissingParent {
+                        code: entry.code.clone(),
+                        parent_code: prefixes(&entry.code).next_back().unwrap_or_default().to_string(),
+                    });
+                }
+            }
+            None => (),
+        }
+    }
+
+    violations
+}
+
+/// Severity of a [Diagnostic]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Severity {
+    /// The dataset will work, but may not behave as intended
+    Warning,
+
+    /// The dataset breaks an invariant and shouldn't be used
+    Error,
+}
+
+/// Issue found in a dataset by [lint]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct Diagnostic {
+    /// How serious the issue is
+    pub severity: Severity,
+
+    /// Stable identifier of the rule that was broken (ie `E001`)
+    pub rule: &'static str,
+
+    /// Code of the class the issue concerns, if any
+    pub code: Option<String>,
+
+    /// Human-readable description of the issue
+    pub message: String,
+
+    /// Suggested fix, if one can be determined
+    pub suggestion: Option<String>,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let severity = match self.severity {
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        };
+        write!(f, "{severity}[{}]: {}", self.rule, self.message)?;
+        if let Some(suggestion) = &self.suggestion {
+            write!(f, " (suggestion: {suggestion})")?;
+        }
+        Ok(())
+    }
+}
+
+impl Diagnostic {
+    fn new(severity: Severity, rule: &'static str, code: Option<&str>, message: String, suggestion: Option<String>) -> Self {
+        Self { severity, rule, code: code.map(String::from), message, suggestion }
+    }
+}
+
+/// Lints a dataset, producing actionable diagnostics
+///
+/// Every [Violation] reported by [check] is an error. Additionally, the following are reported as warnings:
+///
+/// - `W001`: empty names
+/// - `W002`: names with leading or trailing whitespace
+///
+/// # Arguments
+///
+/// - `source` (`impl IntoIterator<Item = impl Into<Entry>>`) - Classes of the dataset
+///
+/// # Returns
+///
+/// - `Vec<Diagnostic>` - Every [Diagnostic] found, errors first
+pub fn lint(source: impl IntoIterator<Item = impl Into<Entry>>) -> Vec<Diagnostic> {
+    let entries = source.into_iter().map(Into::into).collect::<Vec<Entry>>();
+    let codes = entries.iter().map(|entry| entry.code.as_str()).collect::<HashSet<_>>();
+    let nearest_ancestor = |code: &str| {
+        prefixes(code).rev().find(|prefix| codes.contains(prefix)).map(str::to_string)
+    };
+
+    let mut diagnostics = check(entries.iter().cloned())
+        .into_iter()
+        .map(|violation| {
+            let message = violation.to_string();
+            match violation {
+                Violation::InvalidCode { code } => {
+                    let digits = code.chars().filter(|c| c.is_ascii_digit()).collect::<String>();
+                    let suggestion = (!digits.is_empty()).then(|| format!("use the code {digits}"));
+                    Diagnostic::new(Severity::Error, "E001", Some(&code), message, suggestion)
+                }
+                Violation::DuplicateCode { code } =>
+                    Diagnostic::new(Severity::Error, "E002", Some(&code), message, Some("remove or renumber the duplicate".to_string())),
+                Violation::MissingParent { code, parent_code } => {
+                    let suggestion = format!("add a class with the code {parent_code}");
+                    Diagnostic::new(Severity::Error, "E003", Some(&code), message, Some(suggestion))
+                }
+                Violation::ParentMismatch { code, .. } => {
+                    let suggestion = match nearest_ancestor(&code) {
+                        Some(ancestor) => format!("set the parent to {ancestor}"),
+                        None => "remove the parent".to_string(),
+                    };
+                    Diagnostic::new(Severity::Error, "E004", Some(&code), message, Some(suggestion))
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    for entry in &entries {
+        if entry.name.trim().is_empty() {
+            diagnostics.push(Diagnostic::new(
+                Severity::Warning,
+                "W001",
+                Some(&entry.code),
+                format!("class {} has an empty name", entry.code),
+                Some("add a caption".to_string())
+            ));
+        } else if entry.name.trim() != entry.name {
+            diagnostics.push(Diagnostic::new(
+                Severity::Warning,
+                "W002",
+                Some(&entry.code),
+                format!("name of class {} has leading or trailing whitespace", entry.code),
+                Some(format!("rename to {:?}", entry.name.trim()))
+            ));
+        }
+    }
+
+    diagnostics.sort_by_key(|diagnostic| std::cmp::Reverse(diagnostic.severity));
+    diagnostics
+}
+
+/// Lints a dataset in the OpenLibrary JSON format (see [SourceNode])
+///
+/// # Arguments
+///
+/// - `reader` (`impl std::io::Read`) - Source of the JSON document
+///
+/// # Returns
+///
+/// - `Vec<Diagnostic>` - Every [Diagnostic] found (a document that can't be parsed produces a single `E000` error)
+#[cfg(feature = "serde")]
+pub fn lint_json(reader: impl std::io::Read) -> Vec<Diagnostic> {
+    match serde_json::from_reader::<_, Vec<SourceNode>>(reader) {
+        Ok(nodes) => lint(SourceNode::entries(&nodes)),
+        Err(error) =>
+            vec![Diagnostic::new(Severity::Error, "E000", None, format!("invalid dataset JSON: {error}"), None)],
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Dewey;
+
+    #[test]
+    fn test_check() {
+        assert!(check(Dewey::standard().all()).is_empty(), "The standard dataset should be valid");
+
+        let violations = check([
+            Entry::new("5", "Science", None),
+            Entry::new("51", "Mathematics", Some("5")),
+            Entry::new("51", "Mathematics again", Some("5")),
+            Entry::new("52a", "Invalid", Some("5")),
+            Entry::new("61", "Wrong parent", Some("5")),
+            Entry::new("711", "Orphan", Some("71")),
+            Entry::new("811", "Orphan without parent", None),
+        ]);
+        assert_eq!(violations, vec![
+            Violation::DuplicateCode { code: "51".to_string() },
+            Violation::InvalidCode { code: "52a".to_string() },
+            Violation::ParentMismatch { code: "61".to_string(), parent_code: "5".to_string() },
+            Violation::MissingParent { code: "711".to_string(), parent_code: "71".to_string() },
+            Violation::MissingParent { code: "811".to_string(), parent_code: "81".to_string() },
+        ]);
+    }
+
+    #[test]
+    fn test_decimal_codes() {
+        assert!(["5", "813", "813.54", "008.1"].iter().all(|code| is_valid_code(code)));
+        assert!(!["", "81.3", "813.", ".5", "813.5.4", "813.5a", "0081"].iter().any(|code| is_valid_code(code)));
+        assert_eq!(prefixes("813.54").collect::<Vec<_>>(), vec!["8", "81", "813", "813.5"]);
+
+        let violations = check([
+            Entry::new("8", "Literature", None),
+            Entry::new("81", "American literature in English", Some("8")),
+            Entry::new("813", "American fiction in English", Some("81")),
+            Entry::new("813.54", "1945-1999", Some("813")),
+            Entry::new("813.62", "2000-", None),
+            Entry::new("914.54", "Orphan", None),
+        ]);
+        assert_eq!(violations, vec![
+            Violation::MissingParent { code: "914.54".to_string(), parent_code: "914.5".to_string() },
+        ]);
+    }
+
+    #[test]
+    fn test_lint() {
+        let diagnostics = lint([
+            Entry::new("5", "Science", None),
+            Entry::new("51", " Mathematics", Some("5")),
+            Entry::new("512", "", Some("51")),
+            Entry::new("5a3", "Invalid", Some("5")),
+            Entry::new("613", "Wrong parent", Some("5")),
+            Entry::new("6", "Technology", None),
+            Entry::new("61", "Medicine", Some("6")),
+        ]);
+        let summary = diagnostics
+            .iter()
+            .map(|d| (d.rule, d.code.as_deref().unwrap_or(""), d.suggestion.as_deref().unwrap_or("")))
+            .collect::<Vec<_>>();
+        assert_eq!(summary, vec![
+            ("E001", "5a3", "use the code 53"),
+            ("E004", "613", "set the parent to 61"),
+            ("W002", "51", "rename to \"Mathematics\""),
+            ("W001", "512", "add a caption"),
+        ]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_lint_json() {
+        let json = r#"[{"name": "Science", "short": "5XX", "children": [
+            {"name": "Mathematics", "short": "51X"},
+            {"name": "Unassigned", "short": "521–522"}
+        ]}]"#;
+        assert!(lint_json(json.as_bytes()).is_empty(), "Expected a valid dataset");
+        assert_eq!(lint_json(&b"{"[..])[0].rule, "E000");
+    }
+}
@@ -0,0 +1,258 @@
+use std::fmt;
+
+use crate::{ code_label, lenient_code, Class, DdcCode, Dewey, ParseCodeError };
+
+/// Error returned by the fallible (`try_*`) lookups on [Dewey]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DeweyError {
+    /// The code isn't valid DDC notation (empty, invalid character, misplaced decimal point)
+    InvalidCode(ParseCodeError),
+
+    /// The code is deeper than any class in the dataset, so it can never match
+    TooDeep {
+        /// The (trimmed) code that was looked up
+        code: String,
+
+        /// Number of digits in the code
+        depth: usize,
+
+        /// Number of digits in the deepest class of the dataset
+        max_depth: usize,
+    },
+
+    /// The code is valid, but no class in the dataset has it
+    NotFound(String),
+}
+
+impl fmt::Display for DeweyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DeweyError::InvalidCode(error) => write!(f, "{error}"),
+            DeweyError::TooDeep { code, depth, max_depth } =>
+                write!(f, "code {code} has {depth} digits, but the dataset is only {max_depth} digits deep"),
+            DeweyError::NotFound(code) => write!(f, "no class with code {code}"),
+        }
+    }
+}
+
+impl std::error::Error for DeweyError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DeweyError::InvalidCode(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParseCodeError> for DeweyError {
+    fn from(error: ParseCodeError) -> Self {
+        DeweyError::InvalidCode(error)
+    }
+}
+
+/// Builds the error for a valid code that didn't match any class of a dataset, given the number of digits in its deepest class
+fn missing_error(code: &DdcCode, max_depth: usize) -> DeweyError {
+    let depth = code_label(code.as_str()).len();
+    if depth > max_depth {
+        DeweyError::TooDeep { code: code.to_string(), depth, max_depth }
+    } else {
+        DeweyError::NotFound(code.to_string())
+    }
+}
+
+impl Dewey {
+    /// Builds the error for a valid code that didn't match any class
+    fn missing(&self, code: &DdcCode) -> DeweyError {
+        missing_error(code, self.max_code_depth())
+    }
+
+    /// Gets the number of digits in the deepest class of the dataset
+    fn max_code_depth(&self) -> usize {
+        self.all_ref().map(|class| code_label(&class.code).len()).max().unwrap_or(0)
+    }
+
+    /// Gets classes by exact code match in one go, failing separately for each invalid or missing code
+    ///
+    /// This is cheaper than calling [Dewey::try_get_class] in a loop, as the index is fetched once & the dataset is only scanned once for the error of the first missing code.
+    ///
+    /// # Arguments
+    ///
+    /// - `codes` (`impl IntoIterator<Item = impl AsRef<str>>`) - Codes to search for
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<Result<Class, DeweyError>>` - The [Class] matching each code or why there isn't one, in the order of the codes
+    pub fn get_classes(&self, codes: impl IntoIterator<Item = impl AsRef<str>>) -> Vec<Result<Class, DeweyError>> {
+        let index = self.exact_index();
+        let mut max_depth = None;
+        codes
+            .into_iter()
+            .map(|code| {
+                let code = DdcCode::new(code)?;
+                index
+                    .get(code.as_str())
+                    .or_else(|| index.get(&lenient_code(code.as_str())))
+                    .map(|class| (*class).clone())
+                    .ok_or_else(|| missing_error(&code, *max_depth.get_or_insert_with(|| self.max_code_depth())))
+            })
+            .collect()
+    }
+
+    /// Gets a class by exact code match, without copying it, failing if the code is invalid or not found
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code to search for
+    ///
+    /// # Returns
+    ///
+    /// - `Result<&'static Class, DeweyError>` - The [Class] that matches the provided code, or why there isn't one
+    pub fn try_class_ref(&self, code: impl AsRef<str>) -> Result<&'static Class, DeweyError> {
+        let code = DdcCode::new(code)?;
+        self.class_ref(&code).ok_or_else(|| self.missing(&code))
+    }
+
+    /// Gets a class by exact code match, failing if the code is invalid or not found
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code to search for
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Class, DeweyError>` - The [Class] that matches the provided code, or why there isn't one
+    pub fn try_get_class(&self, code: impl AsRef<str>) -> Result<Class, DeweyError> {
+        self.try_class_ref(code).cloned()
+    }
+
+    /// Gets the most specific class a code falls under (see [Dewey::classify]), failing if the code is invalid or doesn't start with a known class
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code or call number to classify
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Class, DeweyError>` - The most specific [Class] that is a prefix of the code, or why there isn't one
+    pub fn try_classify(&self, code: impl AsRef<str>) -> Result<Class, DeweyError> {
+        let code = DdcCode::new(crate::dataset::leading_code(code.as_ref().trim()))?;
+        self.classify(&code).ok_or(DeweyError::NotFound(code.to_string()))
+    }
+
+    /// Returns all classes matching the provided prefix, failing if the prefix is invalid or too deep to match anything
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code to search for
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Vec<Class>, DeweyError>` - [Vec] of [Class] instances matching the prefix (possibly empty), or why the prefix can't match
+    pub fn try_get_matches(&self, code: impl AsRef<str>) -> Result<Vec<Class>, DeweyError> {
+        let code = DdcCode::new(code)?;
+        let matches = self.get_matches(&code);
+        if matches.is_empty() && let error @ DeweyError::TooDeep { .. } = self.missing(&code) {
+            return Err(error);
+        }
+        Ok(matches)
+    }
+
+    /// Gets the direct children of the class with the provided code, without copying them, failing if the class doesn't exist
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code of the parent class
+    ///
+    /// # Returns
+    ///
+    /// - `Result<&'static [Class], DeweyError>` - Slice of [Class] instances that are direct children of the specified class, or why the class doesn't exist
+    pub fn try_children_of(&self, code: impl AsRef<str>) -> Result<&'static [Class], DeweyError> {
+        let class = self.try_class_ref(code)?;
+        Ok(self.children_of(&class.code))
+    }
+
+    /// Gets all the direct children of the class with the provided code, failing if the class doesn't exist
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code of the parent class
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Vec<Class>, DeweyError>` - [Vec] of [Class] instances that are direct children of the specified class, or why the class doesn't exist
+    pub fn try_get_direct_children(&self, code: impl AsRef<str>) -> Result<Vec<Class>, DeweyError> {
+        self.try_children_of(code).map(<[Class]>::to_vec)
+    }
+
+    /// Gets all children (not including the class itself), failing if the class doesn't exist
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code of the parent class
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Vec<Class>, DeweyError>` - [Vec] of all children of the class, or why the class doesn't exist
+    pub fn try_get_all_children(&self, code: impl AsRef<str>) -> Result<Vec<Class>, DeweyError> {
+        let class = self.try_class_ref(code)?;
+        Ok(self.get_all_children(&class.code))
+    }
+
+    /// Gets the parent of the selected class, failing if the class doesn't exist
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code of the class
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Option<Class>, DeweyError>` - Parent of the selected [Class] ([None] for main classes), or why the class doesn't exist
+    pub fn try_get_parent(&self, code: impl AsRef<str>) -> Result<Option<Class>, DeweyError> {
+        let class = self.try_class_ref(code)?;
+        Ok(class.parent_code().and_then(|parent| self.get_class(parent)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_try_lookups() {
+        let dewey = Dewey::standard();
+        assert_eq!(dewey.try_get_class("51X").unwrap().name, "Mathematics");
+        assert_eq!(
+            dewey.try_get_class("12a").map(|class| class.code),
+            Err(DeweyError::InvalidCode(ParseCodeError::InvalidCharacter { character: 'a', index: 2 })),
+            "Expected invalid codes to be rejected instead of panicking"
+        );
+        assert_eq!(dewey.try_get_class("").map(|class| class.code), Err(DeweyError::InvalidCode(ParseCodeError::Empty)));
+        assert!(matches!(dewey.try_get_class("516.123456"), Err(DeweyError::TooDeep { depth: 9, .. })));
+        assert!(matches!(dewey.try_get_matches("516.123456"), Err(DeweyError::TooDeep { .. })));
+
+        let unassigned = dewey.all_ref().find_map(|class| {
+            (0..10).map(|digit| format!("{}{digit}", class.code)).find(|code| code.len() <= 3 && dewey.get_class(code).is_none())
+        }).unwrap();
+        assert_eq!(dewey.try_get_class(&unassigned).map(|class| class.code), Err(DeweyError::NotFound(unassigned.clone())));
+        assert!(dewey.try_get_parent(&unassigned).is_err());
+
+        let codes = |classes: Vec<Class>| classes.into_iter().map(|class| class.code).collect::<Vec<_>>();
+        assert_eq!(dewey.try_classify("621.39 B47").unwrap().code, dewey.classify("621.39").unwrap().code);
+        assert!(dewey.try_get_parent("5").unwrap().is_none(), "Expected main classes to have no parent");
+        assert_eq!(dewey.try_get_parent("51").unwrap().unwrap().code, "5");
+        assert_eq!(codes(dewey.try_get_direct_children("5").unwrap()), codes(dewey.get_direct_children("5")));
+        assert_eq!(codes(dewey.try_get_all_children("5").unwrap()), codes(dewey.get_all_children("5")));
+    }
+
+    #[test]
+    fn test_get_classes() {
+        let dewey = Dewey::standard();
+        let codes = ["516", "12a", "51X", "516.123456", "5", "516"];
+        let results = dewey.get_classes(codes);
+        assert_eq!(results.len(), codes.len());
+        for (code, result) in codes.iter().zip(results) {
+            assert_eq!(result, dewey.try_get_class(code), "Expected the same result as a single lookup of {code}");
+        }
+        assert!(dewey.get_classes(Vec::<String>::new()).is_empty());
+    }
+}
@@ -0,0 +1,286 @@
+//! Exports of the bundled dataset for external tooling
+
+use std::{ fs::File, io::{ self, BufWriter, Write }, path::Path };
+
+use crate::{ Class, Dewey, VisitControl };
+
+/// Separator between captions in the ancestor paths written by [ml_jsonl]
+pub const PATH_SEPARATOR: &str = " > ";
+
+/// Columns written by [Dewey::write_csv] & [Dewey::write_tsv]
+pub const TABLE_COLUMNS: [&str; 6] = ["code", "name", "parent_code", "depth", "has_children", "display_code"];
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize)]
+struct MlRecord<'a> {
+    code: String,
+    path: String,
+    caption: &'a str,
+    notes: Vec<String>,
+}
+
+/// Writes one JSON object per line for every class, in shelf order, shaped for computing text embeddings
+///
+/// Each line has the following fields:
+///
+/// - `code`: code of the class, trimmed (see [CodeStyle](crate::CodeStyle))
+/// - `path`: captions of the class's ancestors and the class itself, joined with [PATH_SEPARATOR] (ie `Science > Mathematics > Geometry`)
+/// - `caption`: caption of the class
+/// - `notes`: scope notes of the class, ie what it includes (empty without the `notes` feature, or for classes without notes)
+///
+/// # Arguments
+///
+/// - `writer` (`impl Write`) - Destination of the JSON lines
+///
+/// # Returns
+///
+/// - `io::Result<()>` - An error if writing fails
+#[cfg(feature = "serde")]
+pub fn ml_jsonl(mut writer: impl Write) -> io::Result<()> {
+    let dewey = Dewey::standard();
+    for class in dewey.all() {
+        let path = dewey
+            .lineage(&class)
+            .iter()
+            .map(|ancestor| ancestor.name.as_ref())
+            .collect::<Vec<_>>()
+            .join(PATH_SEPARATOR);
+        #[cfg(feature = "notes")]
+        let notes = class.notes.iter().map(|notes| notes.to_string()).collect();
+        #[cfg(not(feature = "notes"))]
+        let notes = Vec::new();
+        let record = MlRecord { code: class.code.to_string(), path, caption: &class.name, notes };
+
+        serde_json::to_writer(&mut writer, &record)?;
+        writer.write_all(b"\n")?;
+    }
+    Ok(())
+}
+
+fn table_row(class: &Class) -> [String; 6] {
+    [
+        class.code.to_string(),
+        class.name.to_string(),
+        class.parent_code().unwrap_or_default().to_string(),
+        class.depth().to_string(),
+        class.has_children.to_string(),
+        class.display_code(),
+    ]
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Escapes quotes & backslashes in a DOT string
+fn dot_escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Quotes a DOT identifier
+fn dot_id(value: &str) -> String {
+    format!("\"{}\"", dot_escape(value))
+}
+
+impl Dewey {
+    /// Writes every class as CSV (RFC 4180), in shelf order, ie for seeding spreadsheets & reporting databases
+    ///
+    /// The first line is a header with the [TABLE_COLUMNS]: the code & parent code (empty for main classes), the name, the depth (see [Class::depth]), whether the class has children (`true` or `false`), and the code in the crate-wide [CodeStyle](crate::CodeStyle). The code & parent code are always trimmed, since padded codes aren't unique (ie `5`, `50` & `500` all pad to `500`).
+    ///
+    /// # Arguments
+    ///
+    /// - `writer` (`impl Write`) - Destination of the CSV
+    ///
+    /// # Returns
+    ///
+    /// - `io::Result<()>` - An error if writing fails
+    pub fn write_csv(&self, mut writer: impl Write) -> io::Result<()> {
+        writer.write_all(TABLE_COLUMNS.join(",").as_bytes())?;
+        writer.write_all(b"\r\n")?;
+        for class in self.all_ref() {
+            let row = table_row(class).map(|value| csv_field(&value));
+            writer.write_all(row.join(",").as_bytes())?;
+            writer.write_all(b"\r\n")?;
+        }
+        Ok(())
+    }
+
+    /// Writes every class as tab-separated values, with the same columns as [Dewey::write_csv]
+    ///
+    /// Fields aren't quoted, so tabs & line breaks in names are replaced with spaces.
+    ///
+    /// # Arguments
+    ///
+    /// - `writer` (`impl Write`) - Destination of the TSV
+    ///
+    /// # Returns
+    ///
+    /// - `io::Result<()>` - An error if writing fails
+    pub fn write_tsv(&self, mut writer: impl Write) -> io::Result<()> {
+        writeln!(writer, "{}", TABLE_COLUMNS.join("\t"))?;
+        for class in self.all_ref() {
+            let row = table_row(class).map(|value| value.replace(['\t', '\n', '\r'], " "));
+            writeln!(writer, "{}", row.join("\t"))?;
+        }
+        Ok(())
+    }
+
+    /// Exports every class to a CSV file, see [Dewey::write_csv]
+    ///
+    /// # Arguments
+    ///
+    /// - `path` (`impl AsRef<Path>`) - Path of the file to write
+    ///
+    /// # Returns
+    ///
+    /// - `io::Result<()>` - An error if writing fails
+    pub fn export_csv(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_csv(&mut writer)?;
+        writer.flush()
+    }
+
+    /// Writes a subtree as a Graphviz DOT digraph, ie for rendering classification posters & diagrams with `dot -Tsvg`
+    ///
+    /// Every class is a box labelled with its code (in the crate-wide [CodeStyle](crate::CodeStyle)) & name, with an edge from each class to its children, laid out left to right.
+    ///
+    /// ```rust
+    /// use dewey_decimal::Dewey;
+    ///
+    /// let mut output = Vec::new();
+    /// Dewey::standard().write_dot(&mut output, "51", 1).unwrap();
+    /// let dot = String::from_utf8(output).unwrap();
+    /// assert!(dot.starts_with("digraph ddc {") && dot.contains("\"51\" -> \"516\";"));
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// - `writer` (`impl Write`) - Destination of the graph
+    /// - `prefix` (`impl AsRef<str>`) - Code of the subtree's root (an empty string writes the entire hierarchy)
+    /// - `depth` (`usize`) - Number of levels to write below the root (`usize::MAX` for the whole subtree)
+    ///
+    /// # Returns
+    ///
+    /// - `io::Result<()>` - An error if writing fails
+    pub fn write_dot(&self, mut writer: impl Write, prefix: impl AsRef<str>, depth: usize) -> io::Result<()> {
+        writeln!(writer, "digraph ddc {{")?;
+        writeln!(writer, "    rankdir=LR;")?;
+        writeln!(writer, "    node [shape=box];")?;
+
+        let mut edges = Vec::new();
+        let mut result = Ok(());
+        self.walk(prefix.as_ref().trim_matches('X'), |class, level| {
+            let label = format!("{}\\n{}", class.display_code(), dot_escape(&class.name));
+            result = writeln!(writer, "    {} [label=\"{label}\"];", dot_id(&class.code));
+            if let Some(parent) = class.parent_code().filter(|_| level > 0) {
+                edges.push(format!("    {} -> {};", dot_id(parent), dot_id(&class.code)));
+            }
+
+            match result {
+                Err(_) => VisitControl::Stop,
+                Ok(()) if level >= depth => VisitControl::SkipChildren,
+                Ok(()) => VisitControl::Continue,
+            }
+        });
+        result?;
+
+        for edge in edges {
+            writeln!(writer, "{edge}")?;
+        }
+        writeln!(writer, "}}")
+    }
+
+    /// Exports a subtree to a Graphviz DOT file, see [Dewey::write_dot]
+    ///
+    /// # Arguments
+    ///
+    /// - `path` (`impl AsRef<Path>`) - Path of the file to write
+    /// - `prefix` (`impl AsRef<str>`) - Code of the subtree's root (an empty string writes the entire hierarchy)
+    /// - `depth` (`usize`) - Number of levels to write below the root
+    ///
+    /// # Returns
+    ///
+    /// - `io::Result<()>` - An error if writing fails
+    pub fn export_dot(&self, path: impl AsRef<Path>, prefix: impl AsRef<str>, depth: usize) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_dot(&mut writer, prefix, depth)?;
+        writer.flush()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_write_csv() {
+        let mut output = Vec::new();
+        Dewey::standard().write_csv(&mut output).unwrap();
+        let csv = String::from_utf8(output).unwrap();
+
+        let lines = csv.split_terminator("\r\n").collect::<Vec<_>>();
+        assert_eq!(lines[0], "code,name,parent_code,depth,has_children,display_code");
+        assert_eq!(lines.len(), Dewey::standard().all().len() + 1, "Expected a header & one row per class");
+        assert!(lines[1].starts_with("0,") && lines[1].ends_with(",,0,true,0"), "Expected a main class without parent");
+
+        let quoted = Dewey::standard().all_ref().find(|class| class.name.contains(',')).expect("Expected a name with a comma");
+        assert!(csv.contains(&format!("{},\"{}\",", quoted.code, quoted.name)), "Expected names with commas to be quoted");
+        assert_eq!(csv_field("a \"b\""), "\"a \"\"b\"\"\"");
+    }
+
+    #[test]
+    fn test_write_tsv() {
+        let mut output = Vec::new();
+        Dewey::standard().write_tsv(&mut output).unwrap();
+        let tsv = String::from_utf8(output).unwrap();
+
+        let row = tsv.lines().find(|line| line.starts_with("516\t")).expect("Expected a row for 516");
+        let class = Dewey::standard().get_class("516").unwrap();
+        assert_eq!(row, format!("516\t{}\t51\t2\t{}\t516", class.name, class.has_children));
+    }
+
+    #[test]
+    fn test_write_dot() {
+        let dewey = Dewey::standard();
+        let mut output = Vec::new();
+        dewey.write_dot(&mut output, "5", 1).unwrap();
+        let dot = String::from_utf8(output).unwrap();
+
+        let class = dewey.get_class("51").unwrap();
+        assert!(dot.contains(&format!("    \"51\" [label=\"51\\n{}\"];", class.name)), "Expected codes & names as labels");
+        assert_eq!(dot.matches(" -> ").count(), dewey.children_of("5").len(), "Expected edges to the divisions only");
+        assert!(!dot.contains("\"516\"") && dot.trim_end().ends_with('}'));
+
+        let mut whole = Vec::new();
+        dewey.write_dot(&mut whole, "", usize::MAX).unwrap();
+        let whole = String::from_utf8(whole).unwrap();
+        assert_eq!(whole.matches(" -> ").count(), dewey.all().len() - dewey.categories().len(), "Expected an edge to every class but the main classes");
+        assert_eq!(dot_id("a \"b\""), "\"a \\\"b\\\"\"");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_ml_jsonl() {
+        let mut output = Vec::new();
+        ml_jsonl(&mut output).unwrap();
+
+        let lines = String::from_utf8(output).unwrap();
+        assert_eq!(lines.lines().count(), Dewey::standard().all().len());
+
+        let record = lines
+            .lines()
+            .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+            .find(|record| record["code"] == "516")
+            .expect("Expected a record for 516");
+        let expected = ["5", "51", "516"].map(|code| Dewey::standard().get_class(code).unwrap().name).join(PATH_SEPARATOR);
+        assert_eq!(record["path"], expected);
+        #[cfg(feature = "notes")]
+        assert_eq!(record["notes"], serde_json::json!([Dewey::standard().get_class("516").unwrap().notes.unwrap()]));
+        #[cfg(not(feature = "notes"))]
+        assert_eq!(record["notes"], serde_json::json!([]));
+    }
+}
@@ -0,0 +1,116 @@
+//! Hierarchical facet counts, for faceted-search sidebars
+
+use std::collections::BTreeMap;
+
+use crate::{ dataset, Class, Dewey };
+
+/// Node of a facet tree produced by [Dewey::facets]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct FacetNode {
+    /// The [Class] this facet represents
+    pub class: Class,
+
+    /// Number of items classified under this class (including its subclasses)
+    pub count: usize,
+
+    /// Facets of subclasses containing at least one item, in shelf order
+    pub children: Vec<FacetNode>,
+}
+
+/// Computes facet counts for a set of items in the standard dataset, see [Dewey::facets]
+///
+/// # Arguments
+///
+/// - `codes` (`impl IntoIterator<Item = impl AsRef<str>>`) - Codes of the items (ie the current search results)
+/// - `max_depth` (`usize`) - Maximum depth of the tree, where main classes are `0` (see [Dewey::walk])
+///
+/// # Returns
+///
+/// - `Vec<FacetNode>` - Facets of the main classes containing at least one item, in shelf order
+pub fn compute(codes: impl IntoIterator<Item = impl AsRef<str>>, max_depth: usize) -> Vec<FacetNode> {
+    Dewey::standard().facets(codes, max_depth)
+}
+
+impl Dewey {
+    /// Computes facet counts for a set of items, in a single pass over their codes
+    ///
+    /// Each item counts towards its own class and every ancestor of it. Items classified deeper than `max_depth` count towards their ancestor at `max_depth`, and only classes containing at least one item are included. Codes are read up to the end of their leading DDC number (ie `621.39 B47` counts as `621.39`), and codes that don't match any class are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// - `codes` (`impl IntoIterator<Item = impl AsRef<str>>`) - Codes of the items (ie the current search results)
+    /// - `max_depth` (`usize`) - Maximum depth of the tree, where main classes are `0` (see [Dewey::walk])
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<FacetNode>` - Facets of the main classes containing at least one item, in shelf order
+    pub fn facets(&self, codes: impl IntoIterator<Item = impl AsRef<str>>, max_depth: usize) -> Vec<FacetNode> {
+        let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+
+        for code in codes {
+            let code = dataset::leading_code(code.as_ref());
+
+            dataset::prefixes(code)
+                .chain(std::iter::once(code))
+                .filter(|prefix| self.get_class(prefix).is_some())
+                .take(max_depth + 1)
+                .for_each(|prefix| *counts.entry(prefix.to_string()).or_default() += 1);
+        }
+
+        let mut roots = Vec::new();
+        let mut stack: Vec<FacetNode> = Vec::new();
+        let attach = |roots: &mut Vec<FacetNode>, stack: &mut Vec<FacetNode>, node: FacetNode| {
+            match stack.last_mut() {
+                Some(parent) => parent.children.push(node),
+                None => roots.push(node),
+            }
+        };
+
+        for (code, count) in counts {
+            while stack.last().is_some_and(|top| !code.starts_with(top.class.code.as_ref())) {
+                let node = stack.pop().unwrap();
+                attach(&mut roots, &mut stack, node);
+            }
+            let class = self.get_class(&code).expect("Only existing classes are counted");
+            stack.push(FacetNode { class, count, children: Vec::new() });
+        }
+        while let Some(node) = stack.pop() {
+            attach(&mut roots, &mut stack, node);
+        }
+
+        roots
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_compute() {
+        let facets = compute(["510", "516", "621.39", "62X", "5", "999999", "nonsense"], 1);
+        let summary = facets
+            .iter()
+            .map(|node| (node.class.code.as_ref(), node.count, node.children.iter().map(|c| (c.class.code.as_ref(), c.count)).collect()))
+            .collect::<Vec<(&str, usize, Vec<(&str, usize)>)>>();
+        assert_eq!(summary, vec![("5", 3, vec![("51", 2)]), ("6", 2, vec![("62", 2)]), ("9", 1, vec![("99", 1)])]);
+        assert!(facets[0].children[0].children.is_empty(), "Expected the tree to be pruned at max_depth");
+        assert!(compute(Vec::<String>::new(), 2).is_empty());
+
+        let science = Dewey::builder().include(['5']).build().unwrap();
+        let facets = science.facets(["516", "621.39"], 0);
+        assert_eq!(facets.iter().map(|node| (node.class.code.as_ref(), node.count)).collect::<Vec<_>>(), [("5", 1)], "Expected the handle's dataset");
+    }
+
+    #[cfg(feature = "ts-rs")]
+    #[test]
+    fn test_ts_decl() {
+        use ts_rs::TS;
+
+        let declaration = FacetNode::decl();
+        assert!(declaration.contains("class: Class") && declaration.contains("children: Array<FacetNode>"));
+    }
+}
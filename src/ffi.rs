@@ -0,0 +1,232 @@
+//! C-compatible API over the standard dataset
+//!
+//! Build a shared library with `cargo rustc --release --lib --crate-type cdylib --features ffi` (or `staticlib` for a static one), and include `include/dewey_decimal.h`, which is generated with `cbindgen --config cbindgen.toml --output include/dewey_decimal.h`.
+//!
+//! Every string & class returned by these functions is owned by the caller, and must be released with the matching `*_free` function.
+
+use std::{ ffi::{ c_char, CStr, CString }, ptr };
+
+use crate::{ Class, Dewey };
+
+/// Class returned over the C API
+#[repr(C)]
+pub struct DeweyClass {
+    /// Code of the class (ie `516`)
+    pub code: *mut c_char,
+
+    /// Name of the class
+    pub name: *mut c_char,
+
+    /// Code of the parent class, or `NULL` for main classes
+    pub parent_code: *mut c_char,
+}
+
+/// Array of classes returned over the C API
+#[repr(C)]
+pub struct DeweyClassList {
+    /// Pointer to the first class, or `NULL` if the list is empty
+    pub classes: *mut DeweyClass,
+
+    /// Number of classes in the list
+    pub len: usize,
+}
+
+/// Copies a string into a C string, dropping any interior NUL bytes
+fn to_c_string(value: impl Into<String>) -> *mut c_char {
+    let mut value = value.into();
+    value.retain(|c| c != '\0');
+    CString::new(value).unwrap_or_default().into_raw()
+}
+
+impl From<Class> for DeweyClass {
+    fn from(class: Class) -> Self {
+        DeweyClass {
+            code: to_c_string(class.code),
+            name: to_c_string(class.name),
+            parent_code: class.parent_code.map_or(ptr::null_mut(), to_c_string),
+        }
+    }
+}
+
+/// Reads a code passed over the C API
+///
+/// # Safety
+///
+/// `code` must be `NULL`, or a valid NUL-terminated string
+unsafe fn read_code<'a>(code: *const c_char) -> Option<&'a str> {
+    if code.is_null() {
+        return None;
+    }
+    unsafe { CStr::from_ptr(code) }.to_str().ok().map(str::trim)
+}
+
+/// Gets a class by code
+///
+/// # Safety
+///
+/// `code` must be `NULL`, or a valid NUL-terminated string. The returned class must be released with [dewey_class_free].
+///
+/// # Arguments
+///
+/// - `code` (`*const c_char`) - Code of the class (ie `516`)
+///
+/// # Returns
+///
+/// - `*mut DeweyClass` - The class, or `NULL` if the code is `NULL`, isn't UTF-8, or doesn't exist
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dewey_get_class(code: *const c_char) -> *mut DeweyClass {
+    unsafe { read_code(code) }
+        .and_then(|code| Dewey::standard().get_class(code))
+        .map_or(ptr::null_mut(), |class| Box::into_raw(Box::new(DeweyClass::from(class))))
+}
+
+/// Gets the most specific class a code or call number falls under, see [Dewey::classify]
+///
+/// # Safety
+///
+/// `code` must be `NULL`, or a valid NUL-terminated string. The returned class must be released with [dewey_class_free].
+///
+/// # Arguments
+///
+/// - `code` (`*const c_char`) - Code or call number to classify (ie `813.54 K37`)
+///
+/// # Returns
+///
+/// - `*mut DeweyClass` - The class, or `NULL` if the code is `NULL`, isn't UTF-8, or doesn't start with a known class
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dewey_classify(code: *const c_char) -> *mut DeweyClass {
+    unsafe { read_code(code) }
+        .and_then(|code| Dewey::standard().classify(code))
+        .map_or(ptr::null_mut(), |class| Box::into_raw(Box::new(DeweyClass::from(class))))
+}
+
+/// Gets the direct children of a class
+///
+/// # Safety
+///
+/// `code` must be `NULL`, or a valid NUL-terminated string. The returned list must be released with [dewey_class_list_free].
+///
+/// # Arguments
+///
+/// - `code` (`*const c_char`) - Code of the parent class, or an empty string for the main classes
+///
+/// # Returns
+///
+/// - `DeweyClassList` - The children, which is empty if the code is `NULL`, isn't UTF-8, or has no children
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dewey_children(code: *const c_char) -> DeweyClassList {
+    let children = unsafe { read_code(code) }.map(|code| Dewey::standard().get_direct_children(code)).unwrap_or_default();
+    if children.is_empty() {
+        return DeweyClassList { classes: ptr::null_mut(), len: 0 };
+    }
+
+    let classes = children.into_iter().map(DeweyClass::from).collect::<Box<[_]>>();
+    let len = classes.len();
+    DeweyClassList { classes: Box::into_raw(classes).cast(), len }
+}
+
+/// Releases a string returned by this API
+///
+/// # Safety
+///
+/// `value` must be `NULL`, or a string returned by this API that hasn't been released yet
+///
+/// # Arguments
+///
+/// - `value` (`*mut c_char`) - String to release
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dewey_string_free(value: *mut c_char) {
+    if !value.is_null() {
+        drop(unsafe { CString::from_raw(value) });
+    }
+}
+
+/// Releases the strings of a class, without releasing the class itself
+///
+/// # Safety
+///
+/// The strings of `class` must have been returned by this API, and not released yet
+unsafe fn free_class_strings(class: &mut DeweyClass) {
+    unsafe {
+        dewey_string_free(class.code);
+        dewey_string_free(class.name);
+        dewey_string_free(class.parent_code);
+    }
+}
+
+/// Releases a class returned by [dewey_get_class] or [dewey_classify]
+///
+/// # Safety
+///
+/// `class` must be `NULL`, or a class returned by this API that hasn't been released yet
+///
+/// # Arguments
+///
+/// - `class` (`*mut DeweyClass`) - Class to release
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dewey_class_free(class: *mut DeweyClass) {
+    if !class.is_null() {
+        let mut class = unsafe { Box::from_raw(class) };
+        unsafe { free_class_strings(&mut class) };
+    }
+}
+
+/// Releases a list returned by [dewey_children]
+///
+/// # Safety
+///
+/// `list` must have been returned by this API, and not released yet
+///
+/// # Arguments
+///
+/// - `list` (`DeweyClassList`) - List to release
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dewey_class_list_free(list: DeweyClassList) {
+    if list.classes.is_null() {
+        return;
+    }
+
+    let mut classes = unsafe { Box::from_raw(ptr::slice_from_raw_parts_mut(list.classes, list.len)) };
+    for class in classes.iter_mut() {
+        unsafe { free_class_strings(class) };
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn read(value: *const c_char) -> Option<String> {
+        (!value.is_null()).then(|| unsafe { CStr::from_ptr(value) }.to_string_lossy().into_owned())
+    }
+
+    #[test]
+    fn test_ffi() {
+        let code = CString::new("516").unwrap();
+        unsafe {
+            let class = dewey_get_class(code.as_ptr());
+            assert!(!class.is_null());
+            assert_eq!((read((*class).code).as_deref(), read((*class).parent_code).as_deref()), (Some("516"), Some("51")));
+            dewey_class_free(class);
+
+            let main_class = dewey_get_class(c"5".as_ptr());
+            assert!((*main_class).parent_code.is_null(), "Expected main classes to have no parent");
+            dewey_class_free(main_class);
+
+            assert!(dewey_get_class(ptr::null()).is_null() && dewey_get_class(c"abc".as_ptr()).is_null());
+
+            let classified = dewey_classify(c"813.54 K37".as_ptr());
+            assert_eq!(read((*classified).code).as_deref(), Some("813"));
+            dewey_class_free(classified);
+
+            let children = dewey_children(c"".as_ptr());
+            assert_eq!(children.len, 10, "Expected the main classes");
+            assert_eq!(read((*children.classes.add(9)).code).as_deref(), Some("9"));
+            dewey_class_list_free(children);
+
+            let empty = dewey_children(ptr::null());
+            assert!(empty.classes.is_null() && empty.len == 0);
+            dewey_class_list_free(empty);
+        }
+    }
+}
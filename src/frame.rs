@@ -0,0 +1,105 @@
+use polars::prelude::{ Column, DataFrame, PolarsResult };
+
+use crate::{ Class, Dewey };
+
+impl Dewey {
+    fn depth_of(&self, class: &Class) -> u32 {
+        self.lineage(class).len() as u32 - 1
+    }
+
+    fn classes_to_dataframe(&self, classes: &[Class]) -> PolarsResult<DataFrame> {
+        #[cfg_attr(not(feature = "count"), allow(unused_mut))]
+        let mut columns = vec![
+            Column::new("code".into(), classes.iter().map(|class| class.code.as_ref()).collect::<Vec<_>>()),
+            Column::new("name".into(), classes.iter().map(|class| class.name.as_ref()).collect::<Vec<_>>()),
+            Column::new(
+                "parent".into(),
+                classes.iter().map(|class| class.parent_code()).collect::<Vec<_>>()
+            ),
+            Column::new("depth".into(), classes.iter().map(|class| self.depth_of(class)).collect::<Vec<_>>()),
+            Column::new("child_count".into(), classes.iter().map(|class| class.child_count as u64).collect::<Vec<_>>()),
+            Column::new(
+                "descendant_count".into(),
+                classes.iter().map(|class| class.descendant_count as u64).collect::<Vec<_>>()
+            )
+        ];
+        #[cfg(feature = "count")]
+        columns.push(Column::new("work_count".into(), classes.iter().map(|class| class.work_count).collect::<Vec<_>>()));
+
+        DataFrame::new(columns)
+    }
+
+    /// Converts every class to a DataFrame, in shelf order
+    ///
+    /// Columns are `code` (trimmed, as padded codes aren't unique), `name`, `parent` (null for main classes), `depth` (`0` for main classes), `child_count`, `descendant_count`, and `work_count` (with the `count` feature).
+    ///
+    /// # Returns
+    ///
+    /// - `PolarsResult<DataFrame>` - A [DataFrame] with one row per class
+    pub fn to_dataframe(&self) -> PolarsResult<DataFrame> {
+        self.classes_to_dataframe(&self.all())
+    }
+
+    /// Converts a subtree (including its root) to a DataFrame, see [Dewey::to_dataframe]
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code of the subtree's root
+    ///
+    /// # Returns
+    ///
+    /// - `PolarsResult<DataFrame>` - A [DataFrame] with one row per class of the subtree (empty if the class doesn't exist)
+    pub fn subtree_to_dataframe(&self, code: impl AsRef<str>) -> PolarsResult<DataFrame> {
+        self.classes_to_dataframe(&self.get_matches(code))
+    }
+
+    /// Summarizes each main class in a DataFrame
+    ///
+    /// Columns are `code`, `name`, `classes` (number of classes in the subtree, including its root), `leaves` (classes without children), and `max_depth` (depth of the deepest class).
+    ///
+    /// # Returns
+    ///
+    /// - `PolarsResult<DataFrame>` - A [DataFrame] with one row per main class, in shelf order
+    pub fn stats_to_dataframe(&self) -> PolarsResult<DataFrame> {
+        let categories = self.categories();
+        let subtrees = categories.iter().map(|class| self.get_matches(&class.code)).collect::<Vec<_>>();
+
+        DataFrame::new(vec![
+            Column::new("code".into(), categories.iter().map(|class| class.code.as_ref()).collect::<Vec<_>>()),
+            Column::new("name".into(), categories.iter().map(|class| class.name.as_ref()).collect::<Vec<_>>()),
+            Column::new("classes".into(), subtrees.iter().map(|subtree| subtree.len() as u64).collect::<Vec<_>>()),
+            Column::new(
+                "leaves".into(),
+                subtrees.iter().map(|subtree| subtree.iter().filter(|class| !class.has_children).count() as u64).collect::<Vec<_>>()
+            ),
+            Column::new(
+                "max_depth".into(),
+                subtrees
+                    .iter()
+                    .map(|subtree| subtree.iter().map(|class| self.depth_of(class)).max().unwrap_or(0))
+                    .collect::<Vec<_>>()
+            )
+        ])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_dataframes() {
+        let dewey = Dewey::standard();
+        let frame = dewey.to_dataframe().unwrap();
+        assert_eq!(frame.height(), dewey.all().len());
+
+        let subtree = dewey.subtree_to_dataframe("51").unwrap();
+        assert_eq!(subtree.height(), dewey.get_matches("51").len());
+        assert_eq!(subtree.column("parent").unwrap().str().unwrap().get(0), Some("5"));
+        assert_eq!(subtree.column("depth").unwrap().u32().unwrap().get(0), Some(1));
+
+        let stats = dewey.stats_to_dataframe().unwrap();
+        assert_eq!(stats.height(), 10);
+        assert_eq!(stats.column("max_depth").unwrap().u32().unwrap().into_iter().max(), Some(Some(2)));
+    }
+}
@@ -0,0 +1,209 @@
+use std::path::Path;
+
+use tantivy::{
+    collector::TopDocs,
+    doc,
+    query::QueryParser,
+    schema::{ Field, IndexRecordOption, Schema, TextFieldIndexing, TextOptions, Value, STORED, STRING },
+    tokenizer::{ AsciiFoldingFilter, Language, LowerCaser, RemoveLongFilter, SimpleTokenizer, Stemmer, TextAnalyzer },
+    Index,
+    IndexReader,
+    IndexWriter,
+    TantivyDocument,
+};
+
+use crate::{ search, Class, Dewey };
+
+/// Name of the tokenizer of class names: Tantivy's English stemmer, also folding diacritics (like [search::fold])
+const TOKENIZER: &str = "en_stem_folded";
+
+/// Memory budget of the writer used to build an index (Tantivy's minimum)
+const WRITER_MEMORY: usize = 15_000_000;
+
+/// Tantivy index over the codes & names of a dataset's classes, ranked with BM25
+///
+/// Names are folded like in [Dewey::search] (so `geometrie` matches `Géométrie`, and `and` matches `&`) & tokenized with Tantivy's English stemmer (so `gardening` matches `Garden crops`), while codes are indexed as-is (so `516` only matches the class `516`). Classes that aren't in use are left out, like in [Dewey::search].
+///
+/// ```rust
+/// use dewey_decimal::{ Dewey, FullTextIndex };
+///
+/// let index = FullTextIndex::build(Dewey::standard()).unwrap();
+/// let results = index.search("algebra", 5).unwrap();
+/// assert!(results.iter().any(|(class, _)| class.code == "512"));
+/// ```
+pub struct FullTextIndex {
+    dewey: Dewey,
+    index: Index,
+    reader: IndexReader,
+    code: Field,
+    name: Field,
+}
+
+impl FullTextIndex {
+    fn schema() -> Schema {
+        let mut schema = Schema::builder();
+        schema.add_text_field("code", STRING | STORED);
+        schema.add_text_field(
+            "name",
+            TextOptions::default().set_indexing_options(
+                TextFieldIndexing::default()
+                    .set_tokenizer(TOKENIZER)
+                    .set_index_option(IndexRecordOption::WithFreqsAndPositions)
+            )
+        );
+        schema.build()
+    }
+
+    fn open(dewey: Dewey, index: Index) -> tantivy::Result<Self> {
+        let analyzer = TextAnalyzer::builder(SimpleTokenizer::default())
+            .filter(RemoveLongFilter::limit(40))
+            .filter(LowerCaser)
+            .filter(AsciiFoldingFilter)
+            .filter(Stemmer::new(Language::English))
+            .build();
+        index.tokenizers().register(TOKENIZER, analyzer);
+
+        let schema = index.schema();
+        let (code, name) = (schema.get_field("code")?, schema.get_field("name")?);
+        let reader = index.reader()?;
+        Ok(Self { dewey, index, reader, code, name })
+    }
+
+    fn fill(dewey: Dewey, index: Index) -> tantivy::Result<Self> {
+        let index = Self::open(dewey, index)?;
+        let mut writer: IndexWriter = index.index.writer_with_num_threads(1, WRITER_MEMORY)?;
+        for class in dewey.classes().iter().map(|item: (Vec<u8>, &Class)| item.1) {
+            if !search::is_unused(&class.name) {
+                writer.add_document(doc!(index.code => class.code.as_ref(), index.name => search::fold(&class.name)))?;
+            }
+        }
+        writer.commit()?;
+        index.reader.reload()?;
+        Ok(index)
+    }
+
+    /// Builds an in-memory index of a dataset
+    ///
+    /// # Arguments
+    ///
+    /// - `dewey` (`Dewey`) - Dataset to index
+    ///
+    /// # Returns
+    ///
+    /// - `tantivy::Result<Self>` - The built [FullTextIndex], or Tantivy's error
+    pub fn build(dewey: Dewey) -> tantivy::Result<Self> {
+        Self::fill(dewey, Index::create_in_ram(Self::schema()))
+    }
+
+    /// Builds an index of a dataset & persists it to a directory, so it can be reopened with [FullTextIndex::open_in_dir]
+    ///
+    /// # Arguments
+    ///
+    /// - `dewey` (`Dewey`) - Dataset to index
+    /// - `path` (`impl AsRef<Path>`) - Existing, empty directory to write the index to
+    ///
+    /// # Returns
+    ///
+    /// - `tantivy::Result<Self>` - The built [FullTextIndex], or Tantivy's error (ie if the directory isn't empty)
+    pub fn create_in_dir(dewey: Dewey, path: impl AsRef<Path>) -> tantivy::Result<Self> {
+        Self::fill(dewey, Index::create_in_dir(path, Self::schema())?)
+    }
+
+    /// Opens an index persisted with [FullTextIndex::create_in_dir]
+    ///
+    /// Results are resolved against `dewey`, so hits for codes it doesn't have are skipped if the index is stale.
+    ///
+    /// # Arguments
+    ///
+    /// - `dewey` (`Dewey`) - Dataset the index was built from
+    /// - `path` (`impl AsRef<Path>`) - Directory of the index
+    ///
+    /// # Returns
+    ///
+    /// - `tantivy::Result<Self>` - The opened [FullTextIndex], or Tantivy's error (ie if the directory has no index, or one with another schema)
+    pub fn open_in_dir(dewey: Dewey, path: impl AsRef<Path>) -> tantivy::Result<Self> {
+        Self::open(dewey, Index::open_in_dir(path)?)
+    }
+
+    /// Searches the index, ranking hits with BM25
+    ///
+    /// Queries use Tantivy's query syntax (ie `+fiction -american`, `"applied psychology"`, or `code:516`), searching both names & codes by default. Malformed queries are parsed leniently rather than rejected.
+    ///
+    /// # Arguments
+    ///
+    /// - `query` (`impl AsRef<str>`) - Query to search for
+    /// - `limit` (`usize`) - Maximum number of hits to return
+    ///
+    /// # Returns
+    ///
+    /// - `tantivy::Result<Vec<(Class, f32)>>` - Matching [Class] instances & their scores, best first, or Tantivy's error
+    pub fn search(&self, query: impl AsRef<str>, limit: usize) -> tantivy::Result<Vec<(Class, f32)>> {
+        if limit == 0 {
+            return Ok(Vec::new());
+        }
+
+        let parser = QueryParser::for_index(&self.index, vec![self.name, self.code]);
+        let (query, _) = parser.parse_query_lenient(query.as_ref());
+        let searcher = self.reader.searcher();
+
+        let mut hits = Vec::new();
+        for (score, address) in searcher.search(&query, &TopDocs::with_limit(limit))? {
+            let document: TantivyDocument = searcher.doc(address)?;
+            let class = document.get_first(self.code).and_then(|code| code.as_str()).and_then(|code| self.dewey.class_ref(code));
+            if let Some(class) = class {
+                hits.push((class.clone(), score));
+            }
+        }
+        Ok(hits)
+    }
+}
+
+impl Dewey {
+    /// Searches class names & codes through a full-text index ranked with BM25, see [FullTextIndex::search]
+    ///
+    /// The in-memory index is built the first time this is called. Use a [FullTextIndex] directly to persist the index, limit results, or get scores.
+    ///
+    /// # Arguments
+    ///
+    /// - `query` (`impl AsRef<str>`) - Query to search for, in Tantivy's query syntax
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<Class>` - [Vec] of matching [Class] instances, best first
+    pub fn search_ft(&self, query: impl AsRef<str>) -> Vec<Class> {
+        let index = self.dataset().fulltext.get_or_init(|| FullTextIndex::build(*self).expect("Failed to build full-text index"));
+        index
+            .search(query, self.dataset().names.len())
+            .expect("Failed to search full-text index")
+            .into_iter()
+            .map(|(class, _)| class)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_search_ft() {
+        let dewey = Dewey::standard();
+        assert_eq!(dewey.search_ft("applied psychology")[0].code, "158");
+        assert_eq!(dewey.search_ft("code:516")[0].code, "516");
+        assert!(dewey.search_ft("gardening").iter().any(|class| class.code == "635"), "Expected names to be stemmed");
+        assert_eq!(dewey.search_ft("+information +and +général")[0].code, "0", "Expected names & queries to be folded");
+        assert!(!dewey.search_ft("photography").iter().any(|class| class.code == "775"), "Expected unused classes to be skipped");
+        assert!(dewey.search_ft("zzzzz").is_empty() && dewey.search_ft("\"unbalanced (").len() < dewey.dataset().names.len());
+
+        let directory = std::env::temp_dir().join(format!("dewey-fulltext-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        let built = FullTextIndex::create_in_dir(dewey, &directory).unwrap().search("psychology", 3).unwrap();
+        let reopened = FullTextIndex::open_in_dir(dewey, &directory).unwrap().search("psychology", 3).unwrap();
+        std::fs::remove_dir_all(&directory).unwrap();
+        assert_eq!(built.len(), 3);
+        assert_eq!(
+            built.iter().map(|(class, _)| &class.code).collect::<Vec<_>>(),
+            reopened.iter().map(|(class, _)| &class.code).collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,105 @@
+//! `arbitrary::Arbitrary` implementations for types that can't derive it
+
+use std::sync::LazyLock;
+
+use arbitrary::{ Arbitrary, Result, Unstructured };
+
+use crate::{ Class, DdcCode, Dewey, DeweyQuery, HasQuery, NoQuery, SearchOptions };
+
+static CLASSES: LazyLock<Vec<Class>> = LazyLock::new(|| Dewey::standard().all());
+
+/// Generates a code that's either an existing class's code, a variation of one (ie `51X`, `516.3`), or arbitrary text
+fn arbitrary_code(u: &mut Unstructured<'_>) -> Result<String> {
+    let code = &u.choose(&CLASSES)?.code;
+    Ok(match u.int_in_range(0..=3)? {
+        0 => code.to_string(),
+        1 => format!("{code:X<3}"),
+        2 => format!("{code}.{}", u.int_in_range(0..=999u16)?),
+        _ => String::arbitrary(u)?,
+    })
+}
+
+impl<'a> Arbitrary<'a> for DdcCode {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let digits = |u: &mut Unstructured<'a>, len: usize| -> Result<String> {
+            (0..len).map(|_| Ok(char::from(b'0' + u.int_in_range(0..=9)?))).collect()
+        };
+
+        let len = u.int_in_range(1..=3)?;
+        let mut code = digits(u, len)?;
+        if len == 3 && bool::arbitrary(u)? {
+            let len = u.int_in_range(1..=6)?;
+            code = format!("{code}.{}", digits(u, len)?);
+        }
+        Ok(DdcCode::new(code).expect("Generated codes are valid"))
+    }
+}
+
+impl<'a> Arbitrary<'a> for Class {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u.choose(&CLASSES).cloned()
+    }
+}
+
+impl<'a> Arbitrary<'a> for DeweyQuery {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut query = DeweyQuery::new();
+        if bool::arbitrary(u)? {
+            query = query.within(arbitrary_code(u)?);
+        }
+        if bool::arbitrary(u)? {
+            query = query.name_contains(String::arbitrary(u)?);
+        }
+        if bool::arbitrary(u)? {
+            query = query.max_depth(u.int_in_range(0..=4)?);
+        }
+        if bool::arbitrary(u)? {
+            query = query.limit(u.int_in_range(0..=100)?);
+        }
+        Ok(query)
+    }
+}
+
+impl<'a> Arbitrary<'a> for SearchOptions<NoQuery> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut options = Dewey::standard().search_options().synonyms(bool::arbitrary(u)?);
+        if bool::arbitrary(u)? {
+            options = options.within(arbitrary_code(u)?);
+        }
+        if bool::arbitrary(u)? {
+            options = options.limit(u.int_in_range(0..=100)?);
+        }
+        Ok(options)
+    }
+}
+
+impl<'a> Arbitrary<'a> for SearchOptions<HasQuery> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let options = SearchOptions::<NoQuery>::arbitrary(u)?;
+        Ok(options.query(String::arbitrary(u)?))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_arbitrary() {
+        let data = (0..=255u8).cycle().take(4096).collect::<Vec<_>>();
+        let mut u = Unstructured::new(&data);
+
+        for _ in 0..16 {
+            let code = DdcCode::arbitrary(&mut u).unwrap();
+            assert_eq!(DdcCode::new(code.as_str()), Ok(code), "Expected generated codes to round-trip");
+
+            let class = Class::arbitrary(&mut u).unwrap();
+            assert!(Dewey::standard().get_class(&class.code).is_some(), "Expected a class from the dataset");
+
+            let limit = 5;
+            let results = DeweyQuery::arbitrary(&mut u).unwrap().limit(limit).run();
+            assert!(results.len() <= limit);
+            SearchOptions::<HasQuery>::arbitrary(&mut u).unwrap().execute();
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use std::{ cmp::Reverse, collections::HashMap };
+
+use crate::{ search::tokenize, Class, Dewey };
+
+/// Computes the optimal string alignment distance between two words (Levenshtein distance, counting adjacent transpositions as a single edit)
+fn edit_distance(a: &[char], b: &[char]) -> usize {
+    let mut rows = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    rows[0] = (0..=b.len()).collect();
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut distance = (rows[i - 1][j] + 1).min(rows[i][j - 1] + 1).min(rows[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(rows[i - 2][j - 2] + 1);
+            }
+            rows[i][j] = distance;
+        }
+    }
+    rows[a.len()][b.len()]
+}
+
+/// Gets the maximum number of edits tolerated for a query word, so short words need to be (nearly) exact
+fn max_distance(len: usize) -> usize {
+    match len {
+        0..=3 => 0,
+        4..=7 => 1,
+        _ => 2,
+    }
+}
+
+impl Dewey {
+    /// Searches class names by keyword, tolerating misspellings (ie `pyschology` finds `Psychology`)
+    ///
+    /// Each keyword of the query matches the indexed keywords within a few edits of it (none for words of up to 3 letters, 1 for up to 7, and 2 otherwise), counting adjacent transpositions as a single edit. See [Dewey::search] for exact keyword search.
+    ///
+    /// Results are ranked by the number of keywords matched, then by the total number of edits, then by how short the name is, and finally in shelf order.
+    ///
+    /// # Arguments
+    ///
+    /// - `query` (`impl AsRef<str>`) - Keywords to search for
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<Class>` - [Vec] of matching [Class] instances, best first
+    pub fn search_fuzzy(&self, query: impl AsRef<str>) -> Vec<Class> {
+        // Per class: number of query keywords matched & total edits
+        let mut scores: HashMap<&'static str, (usize, usize)> = HashMap::new();
+        for token in tokenize(query.as_ref()) {
+            let token = token.chars().collect::<Vec<_>>();
+            let limit = max_distance(token.len());
+
+            let mut best: HashMap<&'static str, usize> = HashMap::new();
+            for (keyword, codes) in self.dataset().keywords {
+                let keyword = keyword.chars().collect::<Vec<_>>();
+                if keyword.len().abs_diff(token.len()) > limit {
+                    continue;
+                }
+
+                let distance = edit_distance(&token, &keyword);
+                if distance <= limit {
+                    for code in *codes {
+                        best.entry(code).and_modify(|best| *best = (*best).min(distance)).or_insert(distance);
+                    }
+                }
+            }
+
+            for (code, distance) in best {
+                let score = scores.entry(code).or_default();
+                *score = (score.0 + 1, score.1 + distance);
+            }
+        }
+
+        let mut ranked = scores
+            .into_iter()
+            .filter_map(|(code, (matched, distance))| {
+                let class = self.class_ref(code)?;
+                Some(((Reverse(matched), distance, tokenize(&class.name).len(), &class.code), class))
+            })
+            .collect::<Vec<_>>();
+        ranked.sort_by_key(|(key, _)| *key);
+
+        ranked.into_iter().map(|(_, class)| class.clone()).collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_edit_distance() {
+        let distance = |a: &str, b: &str| edit_distance(&a.chars().collect::<Vec<_>>(), &b.chars().collect::<Vec<_>>());
+        assert_eq!(distance("pyschology", "psychology"), 1, "Expected transpositions to count as one edit");
+        assert_eq!(distance("filosophy", "philosophy"), 2);
+        assert_eq!(distance("", "art"), 3);
+    }
+
+    #[test]
+    fn test_search_fuzzy() {
+        let dewey = Dewey::standard();
+        assert!(dewey.search_fuzzy("pyschology").iter().any(|class| class.code == "150"), "Expected Psychology");
+        assert!(dewey.search_fuzzy("filosophy")[0].name.contains("Philosophy"), "Expected Philosophy first");
+        assert!(dewey.search_fuzzy("xqzv").is_empty(), "Expected no matches");
+    }
+}
@@ -0,0 +1,115 @@
+//! `async-graphql` output type for [Class], resolving related classes from the standard dataset
+//!
+//! Resolvers that share a name with an inherent method of [Class] are prefixed with `graphql_`, and renamed in the schema.
+//!
+//! ```rust
+//! use async_graphql::{ EmptyMutation, EmptySubscription, Object, Schema };
+//! use dewey_decimal::{ Class, Dewey };
+//!
+//! struct Query;
+//!
+//! #[Object]
+//! impl Query {
+//!     async fn class(&self, code: String) -> Option<Class> {
+//!         Dewey::standard().get_class(code)
+//!     }
+//! }
+//!
+//! let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+//! assert!(schema.sdl().contains("hasChildren: Boolean!"));
+//! ```
+
+use async_graphql::Object;
+
+use crate::{ Class, Dewey };
+
+/// Dewey Decimal class
+#[Object(name = "Class")]
+impl Class {
+    /// DDC code of this class, trimmed (ie `51`, `516`)
+    async fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// Friendly name of this class
+    async fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Whether this class has children
+    async fn has_children(&self) -> bool {
+        self.has_children
+    }
+
+    /// Number of direct children of this class
+    async fn child_count(&self) -> usize {
+        self.child_count
+    }
+
+    /// Total number of descendants of this class
+    async fn descendant_count(&self) -> usize {
+        self.descendant_count
+    }
+
+    /// DDC code of the parent class, or null for main classes
+    #[graphql(name = "parentCode")]
+    async fn graphql_parent_code(&self) -> Option<&str> {
+        self.parent_code()
+    }
+
+    /// Parent class, or null for main classes
+    #[graphql(name = "parent")]
+    async fn graphql_parent(&self) -> Option<Class> {
+        Dewey::standard().get_parent(&self.code)
+    }
+
+    /// Direct children of this class
+    #[graphql(name = "children")]
+    async fn graphql_children(&self) -> Vec<Class> {
+        Dewey::standard().get_direct_children(&self.code)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{ pin::pin, task::{ Context, Poll, Waker } };
+
+    use async_graphql::{ value, EmptyMutation, EmptySubscription, Schema };
+
+    use super::*;
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn class(&self, code: String) -> Option<Class> {
+            Dewey::standard().get_class(code)
+        }
+    }
+
+    /// Polls a future that never waits to completion
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = pin!(future);
+        let mut context = Context::from_waker(Waker::noop());
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+                return output;
+            }
+        }
+    }
+
+    #[test]
+    fn test_graphql() {
+        let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+        let response = block_on(schema.execute(r#"{ class(code: "51X") { code name hasChildren parent { code } children { code } } }"#));
+        assert!(response.errors.is_empty(), "Unexpected errors: {:?}", response.errors);
+
+        let data = response.data.into_json().unwrap();
+        assert_eq!(data["class"]["name"], "Mathematics");
+        assert_eq!(data["class"]["parent"], serde_json::json!({ "code": "5" }));
+        assert_eq!(data["class"]["children"].as_array().unwrap().len(), Dewey::standard().get_class("51").unwrap().child_count);
+
+        let missing = block_on(schema.execute(r#"{ class(code: "008") { code } }"#));
+        assert_eq!(missing.data, value!({ "class": null }));
+    }
+}
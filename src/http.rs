@@ -0,0 +1,176 @@
+//! Ready-made `axum` service exposing a dataset over HTTP, returning the `serde` representations of classes
+//!
+//! | Route                       | Response                                                      |
+//! |-----------------------------|---------------------------------------------------------------|
+//! | `GET /classes`              | The main classes                                              |
+//! | `GET /classes/{code}`       | The class with a code (see [Dewey::try_get_class])            |
+//! | `GET /classes/{code}/children` | The direct children of a class (see [Dewey::try_get_direct_children]) |
+//! | `GET /search?q=&limit=`     | Classes matching keywords, ranked (see [Dewey::search])       |
+//!
+//! Invalid codes respond with `400 Bad Request` and unknown ones with `404 Not Found`, with a JSON body of the form `{"error": "..."}`.
+//!
+//! With the `utoipa` feature, the routes are documented in [ApiDoc], which can be merged into an application's OpenAPI document.
+//!
+//! The router can be served as is with `axum::serve`, or nested in a larger application:
+//!
+//! ```rust
+//! use axum::Router;
+//! use dewey_decimal::Dewey;
+//!
+//! let app: Router = Router::new().nest("/ddc", Dewey::standard().router());
+//! ```
+
+use axum::{ extract::{ Path, Query, State }, http::StatusCode, response::{ IntoResponse, Response }, routing::get, Json, Router };
+
+use crate::{ Class, Dewey, DeweyError };
+
+/// Query string of `GET /search`
+#[derive(serde::Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::IntoParams))]
+#[cfg_attr(feature = "utoipa", into_params(parameter_in = Query))]
+struct SearchParams {
+    /// Keywords to search for
+    q: String,
+
+    /// Maximum number of results
+    limit: Option<usize>,
+}
+
+/// Body of error responses
+#[derive(serde::Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct ErrorBody {
+    /// Description of the error
+    pub error: String,
+}
+
+/// OpenAPI document of the routes of [Dewey::router]
+#[cfg(feature = "utoipa")]
+#[derive(utoipa::OpenApi)]
+#[openapi(paths(categories, class, children, search), components(schemas(Class, ErrorBody)))]
+pub struct ApiDoc;
+
+/// Responds with a class (or classes), or with the error of the lookup
+fn respond<T: serde::Serialize>(result: Result<T, DeweyError>) -> Response {
+    match result {
+        Ok(value) => Json(value).into_response(),
+        Err(error) => {
+            let status = match error {
+                DeweyError::InvalidCode(_) => StatusCode::BAD_REQUEST,
+                DeweyError::TooDeep { .. } | DeweyError::NotFound(_) => StatusCode::NOT_FOUND,
+            };
+            (status, Json(ErrorBody { error: error.to_string() })).into_response()
+        }
+    }
+}
+
+#[cfg_attr(feature = "utoipa", utoipa::path(get, path = "/classes", responses((status = 200, description = "The main classes", body = [Class]))))]
+async fn categories(State(dewey): State<Dewey>) -> Json<Vec<Class>> {
+    Json(dewey.categories())
+}
+
+#[cfg_attr(feature = "utoipa", utoipa::path(
+    get,
+    path = "/classes/{code}",
+    params(("code" = String, Path, description = "Code of the class")),
+    responses(
+        (status = 200, description = "The class", body = Class),
+        (status = 400, description = "The code is invalid", body = ErrorBody),
+        (status = 404, description = "No class has the code", body = ErrorBody),
+    )
+))]
+async fn class(State(dewey): State<Dewey>, Path(code): Path<String>) -> Response {
+    respond(dewey.try_get_class(code))
+}
+
+#[cfg_attr(feature = "utoipa", utoipa::path(
+    get,
+    path = "/classes/{code}/children",
+    params(("code" = String, Path, description = "Code of the parent class")),
+    responses(
+        (status = 200, description = "The direct children of the class", body = [Class]),
+        (status = 400, description = "The code is invalid", body = ErrorBody),
+        (status = 404, description = "No class has the code", body = ErrorBody),
+    )
+))]
+async fn children(State(dewey): State<Dewey>, Path(code): Path<String>) -> Response {
+    respond(dewey.try_get_direct_children(code))
+}
+
+#[cfg_attr(feature = "utoipa", utoipa::path(
+    get,
+    path = "/search",
+    params(SearchParams),
+    responses((status = 200, description = "Classes matching the keywords, best first", body = [Class]))
+))]
+async fn search(State(dewey): State<Dewey>, Query(params): Query<SearchParams>) -> Json<Vec<Class>> {
+    let mut results = dewey.search(params.q);
+    if let Some(limit) = params.limit {
+        results.truncate(limit);
+    }
+    Json(results)
+}
+
+impl Dewey {
+    /// Builds an `axum` router serving this dataset (see the `http` module for its routes)
+    ///
+    /// # Returns
+    ///
+    /// - `Router` - The router, with this dataset as its state
+    pub fn router(&self) -> Router {
+        Router::new()
+            .route("/classes", get(categories))
+            .route("/classes/{code}", get(class))
+            .route("/classes/{code}/children", get(children))
+            .route("/search", get(search))
+            .with_state(*self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use axum::{ body::{ self, Body }, http::Request };
+    use tower::ServiceExt;
+
+    use super::*;
+
+    async fn get_json(uri: &str) -> (StatusCode, serde_json::Value) {
+        let response = Dewey::standard().router().oneshot(Request::get(uri).body(Body::empty()).unwrap()).await.unwrap();
+        let status = response.status();
+        let body = body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, serde_json::from_slice(&body).unwrap_or_default())
+    }
+
+    #[tokio::test]
+    async fn test_router() {
+        let (status, class) = get_json("/classes/516").await;
+        assert_eq!((status, class["name"].as_str()), (StatusCode::OK, Some("Geometry")));
+
+        let (status, children) = get_json("/classes/51/children").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(children.as_array().unwrap().len(), Dewey::standard().children_of("51").len());
+        assert_eq!(get_json("/classes").await.1.as_array().unwrap().len(), 10);
+
+        let (status, results) = get_json("/search?q=geometry&limit=1").await;
+        assert_eq!((status, results[0]["name"].as_str(), results.as_array().unwrap().len()), (StatusCode::OK, Some("Geometry"), 1));
+
+        let (status, error) = get_json("/classes/12a").await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(error["error"].as_str().unwrap().contains("invalid character"));
+        assert_eq!(get_json("/classes/008").await.0, StatusCode::NOT_FOUND);
+        assert_eq!(get_json("/classes/008/children").await.0, StatusCode::NOT_FOUND);
+        assert_eq!(get_json("/search").await.0, StatusCode::BAD_REQUEST, "Expected the query to be required");
+    }
+
+    #[cfg(feature = "utoipa")]
+    #[test]
+    fn test_api_doc() {
+        use utoipa::OpenApi;
+
+        let document = serde_json::to_value(ApiDoc::openapi()).unwrap();
+        let paths = document["paths"].as_object().unwrap();
+        assert_eq!(paths.keys().collect::<Vec<_>>(), ["/classes", "/classes/{code}", "/classes/{code}/children", "/search"]);
+        assert_eq!(paths["/search"]["get"]["parameters"][0]["name"], "q");
+        assert!(document["components"]["schemas"]["Class"].is_object() && document["components"]["schemas"]["ErrorBody"].is_object());
+    }
+}
@@ -0,0 +1,169 @@
+use std::fmt;
+
+use crate::{ Class, Dewey };
+
+/// URL of OpenLibrary's ISBN API, followed by the ISBN & `.json`
+const ISBN_API: &str = "https://openlibrary.org/isbn/";
+
+/// Errors produced when classifying a book by ISBN
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IsbnError {
+    /// The ISBN isn't a valid ISBN-10 or ISBN-13 (wrong length, invalid characters, or wrong check digit)
+    InvalidIsbn(String),
+
+    /// The request to OpenLibrary failed, or returned something other than an edition
+    Request(String),
+
+    /// OpenLibrary has no edition with this ISBN
+    NotFound(String),
+
+    /// The edition has no DDC number
+    Unclassified(String),
+
+    /// The edition's DDC numbers don't fall under any known class
+    UnknownClass(String),
+}
+
+impl fmt::Display for IsbnError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IsbnError::InvalidIsbn(isbn) => write!(f, "invalid ISBN: {isbn:?}"),
+            IsbnError::Request(error) => write!(f, "failed to query OpenLibrary: {error}"),
+            IsbnError::NotFound(isbn) => write!(f, "no edition found for ISBN {isbn}"),
+            IsbnError::Unclassified(isbn) => write!(f, "edition with ISBN {isbn} has no DDC number"),
+            IsbnError::UnknownClass(number) => write!(f, "DDC number doesn't match any class: {number}"),
+        }
+    }
+}
+
+impl std::error::Error for IsbnError {}
+
+/// Classification of a book found by [Dewey::classify_isbn]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IsbnClassification {
+    /// The normalized ISBN (digits only, with an uppercase `X` check digit for ISBN-10s)
+    pub isbn: String,
+
+    /// The DDC number recorded by OpenLibrary (ie `813.54`)
+    pub number: String,
+
+    /// The most specific [Class] the number falls under (see [Dewey::classify])
+    pub class: Class,
+}
+
+/// Normalizes & validates an ISBN-10 or ISBN-13, ignoring hyphens & spaces
+pub(crate) fn normalize_isbn(isbn: &str) -> Option<String> {
+    let isbn = isbn.chars().filter(|c| !matches!(c, '-' | ' ')).collect::<String>().to_ascii_uppercase();
+    let digits = isbn.chars().map(|c| if c == 'X' { Some(10) } else { c.to_digit(10) }).collect::<Option<Vec<_>>>()?;
+
+    let valid = match digits.len() {
+        10 => {
+            !digits[..9].contains(&10) && digits.iter().enumerate().map(|(index, digit)| (10 - index as u32) * digit).sum::<u32>() % 11 == 0
+        }
+        13 => {
+            !digits.contains(&10) && digits.iter().enumerate().map(|(index, digit)| if index % 2 == 0 { *digit } else { digit * 3 }).sum::<u32>() % 10 == 0
+        }
+        _ => false,
+    };
+    valid.then_some(isbn)
+}
+
+/// Gets the DDC numbers of an OpenLibrary edition record
+fn edition_numbers(edition: &serde_json::Value) -> Vec<String> {
+    edition["dewey_decimal_class"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|number| number.as_str())
+        .map(|number| number.replace(['/', '\''], "").trim().to_string())
+        .filter(|number| !number.is_empty())
+        .collect()
+}
+
+impl Dewey {
+    fn classify_edition(&self, isbn: String, edition: &serde_json::Value) -> Result<IsbnClassification, IsbnError> {
+        let numbers = edition_numbers(edition);
+        if numbers.is_empty() {
+            return Err(IsbnError::Unclassified(isbn));
+        }
+
+        numbers.iter()
+            .find_map(|number| Some(IsbnClassification { isbn: isbn.clone(), number: number.clone(), class: self.classify(number)? }))
+            .ok_or_else(|| IsbnError::UnknownClass(numbers.join(", ")))
+    }
+
+    /// Looks up a book on OpenLibrary by ISBN, and resolves its DDC number to a class, using the provided HTTP client
+    ///
+    /// # Arguments
+    ///
+    /// - `client` (`&reqwest::Client`) - Client to send the request with (ie one configured with a user agent or proxy)
+    /// - `isbn` (`impl AsRef<str>`) - ISBN-10 or ISBN-13, with or without hyphens
+    ///
+    /// # Returns
+    ///
+    /// - `Result<IsbnClassification, IsbnError>` - The book's DDC number & the [Class] it falls under, or why it couldn't be classified
+    pub async fn classify_isbn_with(&self, client: &reqwest::Client, isbn: impl AsRef<str>) -> Result<IsbnClassification, IsbnError> {
+        let isbn = normalize_isbn(isbn.as_ref()).ok_or_else(|| IsbnError::InvalidIsbn(isbn.as_ref().to_string()))?;
+        let response = client
+            .get(format!("{ISBN_API}{isbn}.json"))
+            .send()
+            .await
+            .map_err(|error| IsbnError::Request(error.to_string()))?;
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Err(IsbnError::NotFound(isbn));
+        }
+
+        let edition = response
+            .error_for_status()
+            .map_err(|error| IsbnError::Request(error.to_string()))?
+            .json::<serde_json::Value>()
+            .await
+            .map_err(|error| IsbnError::Request(error.to_string()))?;
+        self.classify_edition(isbn, &edition)
+    }
+
+    /// Looks up a book on OpenLibrary by ISBN, and resolves its DDC number to a class, ie to find the shelf a book goes on
+    ///
+    /// When OpenLibrary records several DDC numbers, the first one matching a known class is used. See [Dewey::classify_isbn_with] to use a custom HTTP client.
+    ///
+    /// # Arguments
+    ///
+    /// - `isbn` (`impl AsRef<str>`) - ISBN-10 or ISBN-13, with or without hyphens
+    ///
+    /// # Returns
+    ///
+    /// - `Result<IsbnClassification, IsbnError>` - The book's DDC number & the [Class] it falls under, or why it couldn't be classified
+    pub async fn classify_isbn(&self, isbn: impl AsRef<str>) -> Result<IsbnClassification, IsbnError> {
+        self.classify_isbn_with(&reqwest::Client::new(), isbn).await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_normalize_isbn() {
+        assert_eq!(normalize_isbn("978-0-14-303943-3").as_deref(), Some("9780143039433"));
+        assert_eq!(normalize_isbn("0143039431").as_deref(), Some("0143039431"));
+        assert_eq!(normalize_isbn("0-8044-2957-x").as_deref(), Some("080442957X"), "Expected an ISBN-10 with an X check digit");
+        for invalid in ["978-0-14-303943-4", "0143039432", "X143039433", "12345", "978014303943a"] {
+            assert!(normalize_isbn(invalid).is_none(), "Expected {invalid} to be rejected");
+        }
+    }
+
+    #[test]
+    fn test_classify_edition() {
+        let dewey = Dewey::standard();
+        let edition = serde_json::json!({ "dewey_decimal_class": ["FIC", "813/.54"] });
+        let classification = dewey.classify_edition("9780143039433".to_string(), &edition).unwrap();
+        assert_eq!((classification.number.as_str(), classification.class.code.as_ref()), ("813.54", "813"), "Expected the first known number");
+
+        let unclassified = dewey.classify_edition("9780143039433".to_string(), &serde_json::json!({ "title": "Untitled" }));
+        assert_eq!(unclassified.unwrap_err(), IsbnError::Unclassified("9780143039433".to_string()));
+
+        let unknown = dewey.classify_edition("9780143039433".to_string(), &serde_json::json!({ "dewey_decimal_class": ["[E]"] }));
+        assert_eq!(unknown.unwrap_err(), IsbnError::UnknownClass("[E]".to_string()));
+    }
+}
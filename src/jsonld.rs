@@ -0,0 +1,111 @@
+use std::{ fs::File, io::{ self, BufWriter, Write }, path::Path };
+
+use serde_json::{ json, Value };
+
+use crate::{ Class, Dewey };
+
+/// Namespace of the SKOS vocabulary, used for the hierarchy links schema.org lacks
+const SKOS: &str = "http://www.w3.org/2004/02/skos/core#";
+
+impl Dewey {
+    fn jsonld_term(&self, class: &Class, base: &str) -> Value {
+        let mut term = json!({
+            "@id": format!("{base}{}", class.code),
+            "@type": ["CategoryCode", "DefinedTerm"],
+            "codeValue": class.code,
+            "termCode": class.code,
+            "name": class.name,
+            "inCodeSet": { "@id": base },
+            "skos:inScheme": { "@id": base },
+        });
+        if let Some(parent) = class.parent_code() {
+            term["skos:broader"] = json!({ "@id": format!("{base}{parent}") });
+        }
+        term
+    }
+
+    /// Builds a JSON-LD document describing the classification with schema.org terms, ie for embedding as structured data in `<script type="application/ld+json">` elements
+    ///
+    /// The document's `@graph` holds the scheme, a `CategoryCodeSet` with the IRI `base` listing the main classes in `hasCategoryCode`, followed by every class as a `CategoryCode` & `DefinedTerm` with the IRI `base` followed by its trimmed code (ie `https://example.org/ddc/51`). Classes have a `codeValue` & `termCode` (their trimmed code, as padded codes aren't unique), a `name`, and link to the scheme with `inCodeSet` & `skos:inScheme`, and to their parent with `skos:broader`.
+    ///
+    /// # Arguments
+    ///
+    /// - `base` (`&str`) - IRI of the scheme, used as the prefix of class IRIs (ie `https://example.org/ddc/`)
+    ///
+    /// # Returns
+    ///
+    /// - `Value` - The JSON-LD document
+    pub fn to_jsonld(&self, base: &str) -> Value {
+        let top = self.children_of("").iter().map(|class| json!({ "@id": format!("{base}{}", class.code) })).collect::<Vec<_>>();
+        let scheme = json!({
+            "@id": base,
+            "@type": ["CategoryCodeSet", "DefinedTermSet"],
+            "name": "Dewey Decimal Classification",
+            "hasCategoryCode": top,
+        });
+
+        let graph = std::iter::once(scheme).chain(self.all_ref().map(|class| self.jsonld_term(class, base))).collect::<Vec<_>>();
+        json!({
+            "@context": { "@vocab": "https://schema.org/", "skos": SKOS },
+            "@graph": graph,
+        })
+    }
+
+    /// Writes the classification as a JSON-LD document, see [Dewey::to_jsonld]
+    ///
+    /// # Arguments
+    ///
+    /// - `writer` (`impl Write`) - Destination of the document
+    /// - `base` (`&str`) - IRI of the scheme, used as the prefix of class IRIs (ie `https://example.org/ddc/`)
+    ///
+    /// # Returns
+    ///
+    /// - `io::Result<()>` - An error if writing fails
+    pub fn write_jsonld(&self, mut writer: impl Write, base: &str) -> io::Result<()> {
+        serde_json::to_writer(&mut writer, &self.to_jsonld(base))?;
+        writeln!(writer)
+    }
+
+    /// Exports the classification as a JSON-LD document to a file, see [Dewey::to_jsonld]
+    ///
+    /// # Arguments
+    ///
+    /// - `path` (`impl AsRef<Path>`) - Path of the file to write
+    /// - `base` (`&str`) - IRI of the scheme, used as the prefix of class IRIs
+    ///
+    /// # Returns
+    ///
+    /// - `io::Result<()>` - An error if writing fails
+    pub fn export_jsonld(&self, path: impl AsRef<Path>, base: &str) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_jsonld(&mut writer, base)?;
+        writer.flush()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const BASE: &str = "https://example.org/ddc/";
+
+    #[test]
+    fn test_jsonld() {
+        let dewey = Dewey::standard();
+        let document = dewey.to_jsonld(BASE);
+        let graph = document["@graph"].as_array().unwrap();
+        assert_eq!(graph.len(), dewey.all().len() + 1, "Expected the scheme & one term per class");
+        assert_eq!(graph[0]["hasCategoryCode"].as_array().unwrap().len(), dewey.categories().len());
+        assert_eq!(document["@context"]["@vocab"], "https://schema.org/");
+
+        let geometry = graph.iter().find(|term| term["@id"] == "https://example.org/ddc/516").expect("Expected a term for 516");
+        assert_eq!((&geometry["name"], &geometry["codeValue"]), (&json!("Geometry"), &json!("516")));
+        assert_eq!(geometry["skos:broader"]["@id"], "https://example.org/ddc/51");
+        assert_eq!(geometry["inCodeSet"]["@id"], BASE);
+        assert!(graph[1].get("skos:broader").is_none(), "Expected main classes without a broader class");
+
+        let mut output = Vec::new();
+        dewey.write_jsonld(&mut output, BASE).unwrap();
+        assert_eq!(serde_json::from_slice::<Value>(&output).unwrap(), document);
+    }
+}
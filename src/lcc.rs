@@ -0,0 +1,199 @@
+use crate::{ dataset, Class, Dewey };
+
+/// Inclusive range of LCC class numbers, compared by their integer part
+type NumberRange = Option<(u32, u32)>;
+
+/// Summary-level correspondences between DDC classes and Library of Congress Classification ranges
+///
+/// Each entry is a DDC code, an LCC class or subclass, and an optional range of class numbers within it. Entries without a range cover the whole (sub)class.
+const CROSSWALK: &[(&str, &str, NumberRange)] = &[
+    ("0", "A", None),
+    ("00", "AZ", None),
+    ("004", "QA", Some((75, 76))),
+    ("01", "Z", Some((1001, 8999))),
+    ("02", "Z", Some((665, 718))),
+    ("03", "AE", None),
+    ("05", "AP", None),
+    ("06", "AM", None),
+    ("07", "PN", Some((4699, 5650))),
+    ("08", "AC", None),
+    ("09", "Z", Some((41, 115))),
+    ("1", "B", None),
+    ("11", "BD", Some((95, 131))),
+    ("12", "BD", Some((143, 237))),
+    ("13", "BF", Some((1001, 1999))),
+    ("15", "BF", Some((1, 990))),
+    ("16", "BC", None),
+    ("17", "BJ", None),
+    ("18", "B", Some((108, 708))),
+    ("19", "B", Some((790, 5802))),
+    ("2", "BL", None),
+    ("21", "BL", Some((51, 65))),
+    ("22", "BS", None),
+    ("23", "BT", None),
+    ("24", "BV", Some((4485, 5099))),
+    ("25", "BV", Some((4000, 4470))),
+    ("26", "BV", Some((590, 1652))),
+    ("27", "BR", None),
+    ("28", "BX", None),
+    ("29", "BL", Some((660, 2790))),
+    ("3", "H", None),
+    ("30", "HM", None),
+    ("31", "HA", None),
+    ("32", "J", None),
+    ("33", "HB", None),
+    ("34", "K", None),
+    ("35", "JF", None),
+    ("36", "HV", None),
+    ("37", "L", None),
+    ("38", "HE", None),
+    ("39", "GT", None),
+    ("4", "P", None),
+    ("41", "P", Some((101, 410))),
+    ("42", "PE", None),
+    ("43", "PF", Some((3001, 5999))),
+    ("44", "PC", Some((2001, 3761))),
+    ("45", "PC", Some((1001, 1977))),
+    ("46", "PC", Some((4001, 4977))),
+    ("47", "PA", Some((2001, 2915))),
+    ("48", "PA", Some((201, 1179))),
+    ("5", "Q", None),
+    ("51", "QA", Some((1, 939))),
+    ("52", "QB", None),
+    ("53", "QC", None),
+    ("54", "QD", None),
+    ("55", "QE", None),
+    ("56", "QE", Some((701, 996))),
+    ("57", "QH", None),
+    ("58", "QK", None),
+    ("59", "QL", None),
+    ("6", "T", None),
+    ("61", "R", None),
+    ("62", "TA", None),
+    ("63", "S", None),
+    ("64", "TX", None),
+    ("65", "HD", Some((28, 70))),
+    ("66", "TP", None),
+    ("67", "TS", None),
+    ("68", "TT", None),
+    ("69", "TH", None),
+    ("7", "N", None),
+    ("71", "SB", Some((469, 476))),
+    ("72", "NA", None),
+    ("73", "NB", None),
+    ("74", "NC", None),
+    ("75", "ND", None),
+    ("76", "NE", None),
+    ("77", "TR", None),
+    ("78", "M", None),
+    ("79", "GV", None),
+    ("8", "PN", None),
+    ("81", "PS", None),
+    ("82", "PR", None),
+    ("83", "PT", Some((1, 4897))),
+    ("84", "PQ", Some((1, 3999))),
+    ("85", "PQ", Some((4001, 5999))),
+    ("86", "PQ", Some((6001, 8929))),
+    ("87", "PA", Some((6001, 8595))),
+    ("88", "PA", Some((3050, 5665))),
+    ("9", "D", None),
+    ("91", "G", None),
+    ("92", "CT", None),
+    ("93", "D", Some((51, 90))),
+    ("94", "D", Some((901, 2009))),
+    ("95", "DS", None),
+    ("96", "DT", None),
+    ("97", "E", None),
+    ("98", "F", Some((2201, 3799))),
+    ("99", "DU", None),
+];
+
+/// Splits an LCC call number into its class letters & the integer part of its class number (ie `QA` & `76` for `QA76.73 .R87`)
+fn parse_lcc(call_number: &str) -> Option<(String, Option<u32>)> {
+    let call_number = call_number.trim();
+    let letters = call_number.chars().take_while(char::is_ascii_alphabetic).collect::<String>().to_ascii_uppercase();
+    if letters.is_empty() || letters.len() > 3 {
+        return None;
+    }
+
+    let digits = call_number[letters.len()..].trim_start().chars().take_while(char::is_ascii_digit).collect::<String>();
+    Some((letters, digits.parse().ok()))
+}
+
+impl Dewey {
+    /// Gets the Library of Congress Classification range corresponding to a class, at the summary level
+    ///
+    /// Classes without a correspondence of their own use their closest ancestor's (ie `516` uses `51`'s `QA1-939`).
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code of the class
+    ///
+    /// # Returns
+    ///
+    /// - `Option<String>` - The LCC (sub)class, followed by the range of class numbers if it only covers part of it (ie `QA1-939` or `QC`), or [None] if the class doesn't exist
+    pub fn to_lcc(&self, code: impl AsRef<str>) -> Option<String> {
+        let class = self.class_ref(code)?;
+        let (_, letters, range) = std::iter::once(class.code.as_ref())
+            .chain(dataset::prefixes(&class.code).rev())
+            .find_map(|code| CROSSWALK.iter().find(|(ddc, _, _)| *ddc == code))?;
+
+        Some(match range {
+            Some((start, end)) => format!("{letters}{start}-{end}"),
+            None => letters.to_string(),
+        })
+    }
+
+    /// Gets the DDC class corresponding to a Library of Congress call number or class, at the summary level (ie `51` for `QA37.3`)
+    ///
+    /// Numbered ranges within a subclass take precedence over whole subclasses, which take precedence over their main class (ie `QE721` matches paleontology before earth sciences, and `PZ7` falls back to `P`).
+    ///
+    /// # Arguments
+    ///
+    /// - `call_number` (`impl AsRef<str>`) - LCC call number or class (ie `QA76.73 .R87` or `QA`)
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Class>` - The corresponding [Class], or [None] if the call number isn't LCC or has no correspondence
+    pub fn from_lcc(&self, call_number: impl AsRef<str>) -> Option<Class> {
+        let (letters, number) = parse_lcc(call_number.as_ref())?;
+        CROSSWALK.iter()
+            .filter(|(_, class, range)| match range {
+                Some((start, end)) => *class == letters && number.is_some_and(|number| (*start..=*end).contains(&number)),
+                None => letters.starts_with(class),
+            })
+            .max_by_key(|(ddc, class, range)| {
+                let width = range.map_or(u32::MAX, |(start, end)| end - start);
+                (class.len(), std::cmp::Reverse(width), ddc.len())
+            })
+            .and_then(|(ddc, _, _)| self.get_class(ddc))
+    }
+}
+
+impl Class {
+    /// See [Dewey::to_lcc]
+    pub fn to_lcc(&self) -> Option<String> {
+        Dewey::standard().to_lcc(&self.code)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_crosswalk() {
+        let dewey = Dewey::standard();
+        for (code, lcc) in [("51", "QA1-939"), ("516", "QA1-939"), ("53", "QC"), ("004", "QA75-76"), ("5", "Q")] {
+            assert_eq!(dewey.to_lcc(code).as_deref(), Some(lcc), "Unexpected LCC for {code}");
+        }
+        assert_eq!(dewey.get_class("81").unwrap().to_lcc().as_deref(), Some("PS"));
+        assert!(dewey.to_lcc("008").is_none(), "This code is unused!");
+
+        for (lcc, code) in [("QA76", "004"), ("QA37.3", "51"), ("QE721 .F6", "56"), ("QE500", "55"), ("PS3545", "81"), ("PZ7", "4"), ("ps", "81")] {
+            assert_eq!(dewey.from_lcc(lcc).map(|class| class.code).as_deref(), Some(code), "Unexpected DDC for {lcc}");
+        }
+        assert!(dewey.from_lcc("813.54").is_none() && dewey.from_lcc("").is_none(), "Expected non-LCC input to be rejected");
+        assert!(CROSSWALK.iter().all(|(code, _, _)| dewey.get_class(code).is_some()), "Expected every DDC code to exist");
+    }
+}
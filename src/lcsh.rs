@@ -0,0 +1,336 @@
+use crate::{ dataset, search, Class, Dewey };
+
+/// Common Library of Congress Subject Headings for DDC main classes, divisions & frequently used sections
+///
+/// Each entry is a DDC code & a heading, with subdivisions separated by `--`. Classes can have several headings, listed from the most to the least general, but each heading maps to a single class.
+const LCSH_HEADINGS: &[(&str, &str)] = &[
+    ("0", "Information science"),
+    ("0", "Knowledge, Theory of"),
+    ("00", "Information theory"),
+    ("004", "Computer science"),
+    ("004", "Electronic data processing"),
+    ("005", "Computer programming"),
+    ("005", "Computer software"),
+    ("006", "Artificial intelligence"),
+    ("01", "Bibliography"),
+    ("02", "Library science"),
+    ("02", "Libraries"),
+    ("03", "Encyclopedias and dictionaries"),
+    ("05", "Periodicals"),
+    ("06", "Learned institutions and societies"),
+    ("06", "Museums"),
+    ("07", "Journalism"),
+    ("07", "Mass media"),
+    ("08", "Quotations"),
+    ("09", "Manuscripts"),
+    ("09", "Rare books"),
+    ("1", "Philosophy"),
+    ("11", "Metaphysics"),
+    ("11", "Ontology"),
+    ("12", "Epistemology"),
+    ("13", "Parapsychology"),
+    ("13", "Occultism"),
+    ("133", "Astrology"),
+    ("15", "Psychology"),
+    ("155", "Developmental psychology"),
+    ("16", "Logic"),
+    ("17", "Ethics"),
+    ("18", "Philosophy, Ancient"),
+    ("19", "Philosophy, Modern"),
+    ("2", "Religion"),
+    ("21", "Natural theology"),
+    ("22", "Bible"),
+    ("23", "Theology, Doctrinal"),
+    ("23", "Christianity"),
+    ("24", "Christian life"),
+    ("25", "Pastoral theology"),
+    ("26", "Church"),
+    ("27", "Church history"),
+    ("28", "Christian sects"),
+    ("29", "Religions"),
+    ("294", "Hinduism"),
+    ("294", "Buddhism"),
+    ("296", "Judaism"),
+    ("297", "Islam"),
+    ("3", "Social sciences"),
+    ("30", "Sociology"),
+    ("305", "Social groups"),
+    ("306", "Culture"),
+    ("31", "Statistics"),
+    ("32", "Political science"),
+    ("323", "Civil rights"),
+    ("324", "Elections"),
+    ("33", "Economics"),
+    ("332", "Finance"),
+    ("332", "Banks and banking"),
+    ("34", "Law"),
+    ("342", "Constitutional law"),
+    ("345", "Criminal law"),
+    ("35", "Public administration"),
+    ("355", "Military art and science"),
+    ("36", "Social problems"),
+    ("36", "Social service"),
+    ("364", "Crime"),
+    ("364", "Criminology"),
+    ("368", "Insurance"),
+    ("37", "Education"),
+    ("372", "Education, Elementary"),
+    ("378", "Education, Higher"),
+    ("38", "Commerce"),
+    ("38", "Transportation"),
+    ("384", "Telecommunication"),
+    ("39", "Manners and customs"),
+    ("39", "Folklore"),
+    ("4", "Language and languages"),
+    ("41", "Linguistics"),
+    ("42", "English language"),
+    ("423", "English language -- Dictionaries"),
+    ("428", "English language -- Textbooks for foreign speakers"),
+    ("43", "German language"),
+    ("44", "French language"),
+    ("45", "Italian language"),
+    ("46", "Spanish language"),
+    ("46", "Portuguese language"),
+    ("47", "Latin language"),
+    ("48", "Greek language"),
+    ("49", "Chinese language"),
+    ("49", "Japanese language"),
+    ("49", "Russian language"),
+    ("5", "Science"),
+    ("50", "Natural history"),
+    ("51", "Mathematics"),
+    ("512", "Algebra"),
+    ("515", "Calculus"),
+    ("515", "Mathematical analysis"),
+    ("516", "Geometry"),
+    ("519", "Probabilities"),
+    ("52", "Astronomy"),
+    ("53", "Physics"),
+    ("530", "Quantum theory"),
+    ("54", "Chemistry"),
+    ("55", "Earth sciences"),
+    ("55", "Geology"),
+    ("551", "Meteorology"),
+    ("56", "Paleontology"),
+    ("56", "Fossils"),
+    ("57", "Biology"),
+    ("572", "Biochemistry"),
+    ("576", "Genetics"),
+    ("576", "Evolution (Biology)"),
+    ("577", "Ecology"),
+    ("579", "Microbiology"),
+    ("58", "Botany"),
+    ("58", "Plants"),
+    ("59", "Zoology"),
+    ("59", "Animals"),
+    ("598", "Birds"),
+    ("599", "Mammals"),
+    ("6", "Technology"),
+    ("61", "Medicine"),
+    ("610", "Nursing"),
+    ("613", "Health"),
+    ("613", "Nutrition"),
+    ("615", "Pharmacology"),
+    ("616", "Diseases"),
+    ("62", "Engineering"),
+    ("621", "Mechanical engineering"),
+    ("621", "Electrical engineering"),
+    ("624", "Civil engineering"),
+    ("629", "Automobiles"),
+    ("629", "Aeronautics"),
+    ("63", "Agriculture"),
+    ("636", "Domestic animals"),
+    ("636", "Pets"),
+    ("64", "Home economics"),
+    ("641", "Cooking"),
+    ("641", "Food"),
+    ("649", "Child rearing"),
+    ("649", "Parenting"),
+    ("65", "Management"),
+    ("65", "Business"),
+    ("657", "Accounting"),
+    ("658", "Marketing"),
+    ("66", "Chemical engineering"),
+    ("67", "Manufactures"),
+    ("68", "Handicraft"),
+    ("69", "Building"),
+    ("69", "Construction industry"),
+    ("7", "Arts"),
+    ("70", "Art"),
+    ("71", "Landscape architecture"),
+    ("71", "City planning"),
+    ("72", "Architecture"),
+    ("73", "Sculpture"),
+    ("74", "Drawing"),
+    ("74", "Decorative arts"),
+    ("741", "Comic books, strips, etc."),
+    ("741", "Graphic novels"),
+    ("745", "Handicraft -- Design"),
+    ("746", "Textile crafts"),
+    ("75", "Painting"),
+    ("76", "Prints"),
+    ("76", "Printmaking"),
+    ("77", "Photography"),
+    ("78", "Music"),
+    ("781", "Music theory"),
+    ("782", "Vocal music"),
+    ("79", "Recreation"),
+    ("79", "Amusements"),
+    ("791", "Motion pictures"),
+    ("792", "Theater"),
+    ("793", "Games"),
+    ("794", "Chess"),
+    ("794", "Video games"),
+    ("796", "Sports"),
+    ("8", "Literature"),
+    ("80", "Rhetoric"),
+    ("808", "Authorship"),
+    ("808", "Creative writing"),
+    ("81", "American literature"),
+    ("811", "American poetry"),
+    ("812", "American drama"),
+    ("813", "American fiction"),
+    ("814", "American essays"),
+    ("82", "English literature"),
+    ("821", "English poetry"),
+    ("822", "English drama"),
+    ("823", "English fiction"),
+    ("83", "German literature"),
+    ("84", "French literature"),
+    ("85", "Italian literature"),
+    ("86", "Spanish literature"),
+    ("86", "Portuguese literature"),
+    ("87", "Latin literature"),
+    ("88", "Greek literature"),
+    ("89", "Russian literature"),
+    ("89", "Chinese literature"),
+    ("89", "Japanese literature"),
+    ("9", "History"),
+    ("90", "World history"),
+    ("90", "Civilization"),
+    ("91", "Geography"),
+    ("91", "Voyages and travels"),
+    ("912", "Atlases"),
+    ("912", "Maps"),
+    ("92", "Biography"),
+    ("929", "Genealogy"),
+    ("93", "History, Ancient"),
+    ("93", "Archaeology"),
+    ("94", "Europe -- History"),
+    ("940", "World War, 1914-1918"),
+    ("940", "World War, 1939-1945"),
+    ("941", "Great Britain -- History"),
+    ("943", "Germany -- History"),
+    ("944", "France -- History"),
+    ("945", "Italy -- History"),
+    ("946", "Spain -- History"),
+    ("947", "Russia -- History"),
+    ("95", "Asia -- History"),
+    ("951", "China -- History"),
+    ("952", "Japan -- History"),
+    ("954", "India -- History"),
+    ("956", "Middle East -- History"),
+    ("96", "Africa -- History"),
+    ("97", "North America -- History"),
+    ("971", "Canada -- History"),
+    ("972", "Mexico -- History"),
+    ("973", "United States -- History"),
+    ("973", "United States -- History -- Civil War, 1861-1865"),
+    ("98", "South America -- History"),
+    ("98", "Latin America -- History"),
+    ("99", "Australia -- History"),
+    ("99", "Oceania -- History"),
+];
+
+/// Splits a heading into its folded main heading & subdivisions (see [search::fold]), accepting `--` or dashes as separators
+fn heading_parts(heading: &str) -> Vec<String> {
+    heading
+        .replace(['—', '–'], "--")
+        .split("--")
+        .map(|part| search::fold(part.trim_end_matches('.')))
+        .filter(|part| !part.is_empty())
+        .collect()
+}
+
+impl Dewey {
+    /// Gets common Library of Congress Subject Headings for a class or call number, ie for enriching records that arrive with only a call number
+    ///
+    /// Call numbers resolve to their most specific class (see [Dewey::classify]), and classes without headings of their own use their closest ancestor's (ie `813.54 H456` gets `813`'s `American fiction`, and `517` gets `51`'s `Mathematics`).
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code of the class, or a call number
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<&'static str>` - The headings, with subdivisions separated by `--` & the most general first (empty if neither the class nor its ancestors have any, or the class doesn't exist)
+    pub fn suggested_subjects(&self, code: impl AsRef<str>) -> Vec<&'static str> {
+        let Some(class) = self.classify(code) else {
+            return Vec::new();
+        };
+        std::iter::once(class.code.as_ref())
+            .chain(dataset::prefixes(&class.code).rev())
+            .map(|code| LCSH_HEADINGS.iter().filter(|(ddc, _)| *ddc == code).map(|(_, heading)| *heading).collect::<Vec<_>>())
+            .find(|headings| !headings.is_empty())
+            .unwrap_or_default()
+    }
+
+    /// Gets the class corresponding to a Library of Congress Subject Heading, at the summary level (ie `813` for `American fiction`)
+    ///
+    /// Headings are compared regardless of case, diacritics & trailing periods. Subdivisions the mapping doesn't know are dropped from the end until a heading matches (ie `United States -- History -- Revolution, 1775-1783` falls back to `United States -- History`, and `Cooking, French` doesn't match at all).
+    ///
+    /// # Arguments
+    ///
+    /// - `heading` (`impl AsRef<str>`) - Subject heading, with subdivisions separated by `--` (ie `Geometry -- Textbooks`)
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Class>` - The corresponding [Class], or [None] if the heading has no correspondence
+    pub fn from_lcsh(&self, heading: impl AsRef<str>) -> Option<Class> {
+        let parts = heading_parts(heading.as_ref());
+        (1..=parts.len())
+            .rev()
+            .find_map(|len| LCSH_HEADINGS.iter().find(|(_, heading)| heading_parts(heading) == parts[..len]))
+            .and_then(|(code, _)| self.get_class(code))
+    }
+}
+
+impl Class {
+    /// See [Dewey::suggested_subjects]
+    pub fn suggested_subjects(&self) -> Vec<&'static str> {
+        Dewey::standard().suggested_subjects(&self.code)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_lcsh() {
+        let dewey = Dewey::standard();
+        assert_eq!(dewey.suggested_subjects("813"), ["American fiction"]);
+        assert_eq!(dewey.suggested_subjects("813.54 H456"), ["American fiction"]);
+        assert_eq!(dewey.get_class("517").unwrap().suggested_subjects(), ["Mathematics"]);
+        assert_eq!(dewey.suggested_subjects("64"), ["Home economics"]);
+        assert!(dewey.suggested_subjects("abc").is_empty());
+
+        for (heading, code) in [
+            ("American fiction", "813"),
+            ("ÉVOLUTION (BIOLOGY)", "576"),
+            ("geometry", "516"),
+            ("Geometry -- Textbooks.", "516"),
+            ("United States—History—Revolution, 1775-1783", "973"),
+            ("United States -- History -- Civil War, 1861-1865 -- Campaigns", "973"),
+            ("Cooking", "641"),
+        ] {
+            assert_eq!(dewey.from_lcsh(heading).map(|class| class.code).as_deref(), Some(code), "Unexpected class for {heading}");
+        }
+        assert!(dewey.from_lcsh("Cooking, French").is_none() && dewey.from_lcsh("").is_none(), "Expected unknown headings not to match");
+
+        assert!(LCSH_HEADINGS.iter().all(|(code, _)| dewey.get_class(code).is_some()), "Expected every DDC code to exist");
+        for (index, (_, heading)) in LCSH_HEADINGS.iter().enumerate() {
+            assert!(LCSH_HEADINGS[..index].iter().all(|(_, other)| heading_parts(other) != heading_parts(heading)), "Duplicate heading {heading}");
+        }
+    }
+}
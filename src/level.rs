@@ -0,0 +1,168 @@
+use crate::{ code_label, lenient_code, Class, Dewey };
+
+/// Tier of a class in the DDC hierarchy
+///
+/// Tiers order from broadest to most specific, so `Level::MainClass < Level::Section`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum Level {
+    /// One of the ten main classes (ie `5`, shown as `500`)
+    MainClass,
+
+    /// One of the hundred divisions (ie `51`, shown as `510`)
+    Division,
+
+    /// One of the thousand sections (ie `516`)
+    Section,
+
+    /// Anything below a section, written with a decimal point (ie `516.3` or `813.54`)
+    Subsection,
+}
+
+impl Level {
+    /// Gets the tier at a depth in the hierarchy
+    ///
+    /// # Arguments
+    ///
+    /// - `depth` (`usize`) - Depth, where main classes are `0` (see [Class::depth])
+    ///
+    /// # Returns
+    ///
+    /// - `Level` - The matching [Level] (every depth past sections is a [Level::Subsection])
+    pub fn from_depth(depth: usize) -> Self {
+        match depth {
+            0 => Level::MainClass,
+            1 => Level::Division,
+            2 => Level::Section,
+            _ => Level::Subsection,
+        }
+    }
+}
+
+impl Class {
+    /// Gets the depth of this class in the hierarchy, counted from its digits so decimal codes work as expected
+    ///
+    /// # Returns
+    ///
+    /// - `usize` - `0` for main classes, `1` for divisions, `2` for sections, `3` for `516.3`, `4` for `813.54`, etc
+    pub fn depth(&self) -> usize {
+        code_label(&self.code).len().saturating_sub(1)
+    }
+
+    /// Gets the tier of this class in the hierarchy
+    ///
+    /// # Returns
+    ///
+    /// - `Level` - The [Level] matching [Class::depth]
+    pub fn level(&self) -> Level {
+        Level::from_depth(self.depth())
+    }
+
+    /// Looks up the ancestor of this class at a tier in the standard dataset, see [Dewey::ancestor_at]
+    #[deprecated(note = "only looks in `Dewey::standard()`, use `dewey.ancestor_at(&class.code, level)` on the class's dataset instead")]
+    pub fn ancestor_at(&self, level: Level) -> Option<Class> {
+        Dewey::standard().ancestor_at(&self.code, level)
+    }
+
+    /// Looks up the main class this class falls under in the standard dataset (ie `0` for `005`), see [Dewey::ancestor_at]
+    #[deprecated(note = "only looks in `Dewey::standard()`, use `dewey.ancestor_at(&class.code, Level::MainClass)` on the class's dataset instead")]
+    pub fn main_class(&self) -> Option<Class> {
+        Dewey::standard().ancestor_at(&self.code, Level::MainClass)
+    }
+
+    /// Looks up the division this class falls under in the standard dataset (ie `00` for `005`), see [Dewey::ancestor_at]
+    #[deprecated(note = "only looks in `Dewey::standard()`, use `dewey.ancestor_at(&class.code, Level::Division)` on the class's dataset instead")]
+    pub fn division(&self) -> Option<Class> {
+        Dewey::standard().ancestor_at(&self.code, Level::Division)
+    }
+
+    /// Looks up the section this class falls under in the standard dataset (ie `005` for `005` itself, or `813` for `813.54`), see [Dewey::ancestor_at]
+    #[deprecated(note = "only looks in `Dewey::standard()`, use `dewey.ancestor_at(&class.code, Level::Section)` on the class's dataset instead")]
+    pub fn section(&self) -> Option<Class> {
+        Dewey::standard().ancestor_at(&self.code, Level::Section)
+    }
+}
+
+impl Dewey {
+    /// Gets all classes of a tier, ie every division
+    ///
+    /// # Arguments
+    ///
+    /// - `level` (`Level`) - Tier to get
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<Class>` - [Vec] of [Class] instances at that tier, in shelf order
+    pub fn at_level(&self, level: Level) -> Vec<Class> {
+        self.all_ref().filter(|class| class.level() == level).cloned().collect()
+    }
+
+    /// Gets the ancestor of a code at a tier, or the class itself if it's at that tier (ie rolling `516.3` up to its division `51`)
+    ///
+    /// The code itself doesn't have to be in this dataset, so local subsections roll up to their tiers too.
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code to roll up
+    /// - `level` (`Level`) - Tier of the ancestor, which can't be [Level::Subsection] (subsections can be nested, so use [Dewey::ancestors] instead)
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Class>` - The ancestor, or [None] if the code is above that tier or the ancestor isn't in this dataset
+    pub fn ancestor_at(&self, code: impl AsRef<str>, level: Level) -> Option<Class> {
+        let code = lenient_code(code.as_ref());
+        if level == Level::Subsection || level > Level::from_depth(code_label(&code).len().saturating_sub(1)) {
+            return None;
+        }
+        // Decimal points only come after the third digit, so tiers up to sections are plain prefixes
+        code.get(..level as usize + 1).and_then(|code| self.get_class(code))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_levels() {
+        let dewey = Dewey::standard();
+        for (code, depth, level) in [("5", 0, Level::MainClass), ("51", 1, Level::Division), ("516", 2, Level::Section)] {
+            let class = dewey.get_class(code).unwrap();
+            assert_eq!((class.depth(), class.level()), (depth, level), "Unexpected level for {code}");
+        }
+
+        let decimal = Class::local("813.54", "American fiction, 1945-1999", Some("813".to_string()));
+        assert_eq!((decimal.depth(), decimal.level()), (4, Level::Subsection), "Expected the decimal point not to count");
+
+        assert_eq!(dewey.at_level(Level::MainClass).len(), dewey.categories().len());
+        let divisions = dewey.at_level(Level::Division);
+        assert!(!divisions.is_empty() && divisions.iter().all(|class| class.code.len() == 2), "Expected only divisions");
+        assert!(Level::MainClass < Level::Subsection);
+
+        let tiers = [Level::MainClass, Level::Division, Level::Section].map(|level| dewey.ancestor_at("005", level).unwrap().code);
+        assert_eq!(tiers, ["0", "00", "005"]);
+        assert_eq!(dewey.ancestor_at("813.54", Level::Section).unwrap().code, "813");
+        assert_eq!(dewey.ancestor_at("813.54", Level::Division).unwrap().name, dewey.get_class("81").unwrap().name);
+        assert_eq!(dewey.ancestor_at(" 51X ", Level::MainClass).unwrap().code, "5");
+        assert!(dewey.ancestor_at("5", Level::Division).is_none(), "Expected no division above main classes");
+        assert!(dewey.ancestor_at("813.54", Level::Subsection).is_none());
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_class_tiers() {
+        let section = Dewey::standard().get_class("005").unwrap();
+        let tiers = [section.main_class(), section.division(), section.section()].map(|class| class.unwrap().code);
+        assert_eq!(tiers, ["0", "00", "005"]);
+        assert_eq!(section.ancestor_at(Level::Division), section.division());
+    }
+
+    #[test]
+    fn test_ancestor_at_handle() {
+        let science = Dewey::builder().include(['5']).build().unwrap();
+        assert_eq!(science.ancestor_at("516.3", Level::Division).unwrap().code, "51");
+        assert!(science.ancestor_at("813.54", Level::Section).is_none(), "Expected classes outside the dataset not to be found");
+    }
+}
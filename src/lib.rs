@@ -9,48 +9,476 @@
 //! ## Usage
 //! 
 //! ```rust
-//! use dewey_decimal::{Dewey, Class};
+//! use dewey_decimal::Dewey;
 //! 
 //! fn main() {
 //!     // Get the class representing "Computer science, knowledge & systems"
-//!     let comp_sci = Class::get("00").unwrap();
+//!     let comp_sci = Dewey::standard().get_class("00").unwrap();
 //! 
 //!     // Gets all children in this class
-//!     let cs_classes = comp_sci.all_children()
+//!     let cs_classes = Dewey::standard().get_all_children(&comp_sci.code);
 //! }
 //! ```
 //! 
+//! Hardcoded codes can be validated at compile time with the `dewey!` macro from the companion `dewey-decimal-macros` crate (ie `const FICTION: Class = dewey!("813");`), which expands to a [Class] constant & fails the build for unknown or malformed codes.
+//!
+//! Notation from the auxiliary tables (T1–T6), which is added to schedule numbers to build more specific ones, can be looked up with [Table] & [TableEntry], and added to base numbers with a [NumberBuilder].
+//!
 //! ## Features
 //!
 //! `dewey-decimal` supports several serialization utilities, which can be activated with feature flags
 //!
 //! | Feature           | Description                                                                       |
 //! |-------------------|-----------------------------------------------------------------------------------|
-//! | `serde`           | Supports `serde` serialization & deserialization on [Class] (enabled by default)  |
-//! | `specta`          | Supports `specta::Type` on [Class]                                                |
+//! | `serde`           | Supports `serde` serialization & deserialization on [Class], saving/loading [Overlay]s, and validating codes in `#[serde(with)]` fields (see the `serde` module) (enabled by default) |
+//! | `specta`          | Supports `specta::Type` on [Class] and the other public data types, and exporting TypeScript bindings (see `bindings::export_typescript`) |
 //! | `schemars`        | Supports `schemars::JsonSchema` on [Class]                                        |
+//! | `rkyv`            | Supports `rkyv` zero-copy serialization on [Class], [DdcCode] & [CallNumber] |
+//! | `bincode`         | Supports `bincode::Encode` & `bincode::Decode` on [Class]                        |
+//! | `borsh`           | Supports `borsh` serialization & deserialization on [Class]                      |
+//! | `sqlx`            | Supports `sqlx::Type`, `Encode` & `Decode` on [DdcCode] & [Class], stored as their code in text columns (classes are looked up in the standard dataset when decoding) |
+//! | `rusqlite`        | Supports seeding SQLite databases with a `classes` table through `rusqlite` (see `Dewey::export_sqlite` & `Dewey::seed_sqlite`) |
+//! | `diesel`          | Supports using [DdcCode] in `diesel` `Text` columns (`AsExpression`, `FromSqlRow`, `ToSql` & `FromSql`) |
+//! | `async-graphql`   | Implements `async_graphql::OutputType` for [Class] (`code`, `name`, `hasChildren`, `parent`, `children`, etc) |
 //! | `bevy_reflect`    | Supports `bevy_reflect::Reflect` on [Class]                                       |
+//! | `bevy`            | Includes `DeweyPlugin`, a Bevy plugin inserting the dataset as a `DeweyIndex` resource & registering [Class] for reflection (see the `bevy` module) |
+//! | `count`           | Includes OpenLibrary's work counts in [Class] (`work_count`) & [RawNode] (`count`) |
+//! | `query`           | Includes OpenLibrary's query strings in [Class] & [RawNode] (`query`), and links to OpenLibrary searches (`Class::openlibrary_url`) |
+//! | `notes`           | Includes scope notes ("class here" & "including" notes) in [Class] (`notes`), from the source dataset or the supplemental `notes.json` |
+//! | `fuzzy`           | Supports searching class names while tolerating misspellings (see `Dewey::search_fuzzy`) |
+//! | `regex`           | Supports searching class names & codes with regular expressions (see `Dewey::search_regex`) |
+//! | `data-openlibrary` | Embeds OpenLibrary's Library Explorer summaries (enabled by default, see `Dewey::edition`) |
+//! | `data-ddc23-summaries` | Embeds OCLC's DDC 23 three-level summaries instead, which can't be redistributed with the crate and must be supplied in the OpenLibrary JSON format through `DEWEY_SOURCE_FILE` (a compile error without it, see the README) |
+//! | `offline`         | Never fetches the dataset at build time, always embedding the vendored copy (see `Dewey::dataset_source`) |
+//! | `bisac`           | Includes a mapping between BISAC subject headings and DDC classes (see `Dewey::from_bisac` & `Dewey::bisac_subjects`) |
+//! | `isbn`            | Supports classifying books by ISBN through the OpenLibrary API, asynchronously (see `Dewey::classify_isbn`) |
+//! | `lcc`             | Includes a summary-level crosswalk between DDC classes and Library of Congress Classification ranges (see `Dewey::to_lcc` & `Dewey::from_lcc`) |
+//! | `lcsh`            | Includes a summary-level mapping between DDC classes and common Library of Congress Subject Headings (see `Dewey::suggested_subjects` & `Dewey::from_lcsh`) |
+//! | `locales`         | Includes German, French & Spanish names of the main classes & divisions (see `Class::name_in`) |
+//! | `udc`             | Includes a mapping of DDC main classes & divisions to Universal Decimal Classification notation, marking where the schemes diverge (see `Dewey::to_udc`) |
+//! | `marc`            | Supports reading DDC numbers & editions from MARC 082 fields (see `Dewey::from_marc_082`) |
+//! | `marcxml`         | Supports exporting classes as MARC 21 classification records in MARCXML (see `Dewey::write_marcxml`) |
+//! | `tantivy`         | Supports full-text search over class names & codes with a Tantivy index ranked with BM25, which can be persisted to disk (see `Dewey::search_ft` & `FullTextIndex`) |
+//! | `scan`            | Supports scanning documents for class names with an Aho-Corasick automaton (see `Dewey::scan_text`) |
+//! | `avro`            | Supports exporting classes to Avro object container files (see `Dewey::write_avro`) |
+//! | `skos`            | Supports exporting the classification as a SKOS concept scheme in Turtle or RDF/XML (see `Dewey::write_skos`) |
+//! | `jsonld`          | Supports exporting the classification as schema.org `CategoryCode` & `DefinedTerm` entities in JSON-LD, ie for structured data (see `Dewey::to_jsonld`) |
+//! | `site`            | Supports generating a self-contained static HTML browser of the classification, with a collapsible tree & client-side search (see `Dewey::generate_site`) |
+//! | `http`            | Includes a ready-made `axum` router serving classes, children & search results as JSON (see `Dewey::router` & the `http` module) |
+//! | `utoipa`          | Supports `utoipa::ToSchema` on [Class], and documents the routes of the `http` feature in an OpenAPI document (see `http::ApiDoc`) |
+//! | `tauri`           | Includes ready-made Tauri commands (`get_class`, `children`, `search` & `tree`), and generating typed TypeScript wrappers for them (see the `tauri` module & `bindings::export_tauri_bindings`) |
+//! | `polars`          | Supports converting classes to `polars` DataFrames (see `Dewey::to_dataframe`) |
+//! | `mmap`            | Supports exporting datasets to data files & memory-mapping them (see `MappedDataset`) |
+//! | `onnx`            | Includes `OnnxBackend`, a [SuggestBackend] running a local ONNX text-classification model (see `Dewey::suggest_for_text_with`) |
+//! | `rayon`           | Supports iterating over classes in parallel with `rayon`, without copying them (see `Dewey::par_iter` & `Dewey::par_children`) |
+//! | `cache`           | Caches the results of prefix, name & keyword queries in a bounded LRU cache (see `Dewey::cache_stats`) |
+//! | `wasm`            | Exposes `getClass`, `children` & `search` to JavaScript with `wasm-bindgen`, for `wasm32-unknown-unknown` (see the `wasm` module) |
+//! | `ffi`             | Exposes a C API (`dewey_get_class`, `dewey_children`, etc) for linking from C & C++, with a header in `include/dewey_decimal.h` (see the `ffi` module) |
+//! | `cli`             | Builds the `dewey` binary, for looking up classes from the terminal (`dewey get 813`, `dewey children 5`, `dewey search quantum`, `dewey tree 51 --depth 2`) |
+//! | `component`       | Implements the WebAssembly component interface in `wit/dewey-decimal.wit` (lookup, children, search & validation) |
+//! | `arbitrary`       | Supports `arbitrary::Arbitrary` on [DdcCode], [Class] (drawn from the dataset), [DeweyQuery], [SearchOptions], and the option enums, for fuzzing & property tests |
+//! | `proptest`        | Supports `proptest::arbitrary::Arbitrary` on [Class] & [DdcCode], generating only assigned codes, with strategies for classes, codes & prefixes (see the `strategy` module) |
+//! | `python`          | Exposes `get_class`, `children`, `parent` & `search` to Python with `pyo3`, building a `dewey_decimal` extension module with `maturin` (see the `python` module) |
+//! | `ts-rs`           | Supports `ts_rs::TS` on [Class] and the other public data types (facets, diagnostics, matches, etc) |
+//!
+//! ## Build configuration
+//!
+//! The embedded dataset can be sourced & pruned at compile time with the following environment variables:
+//!
+//! | Variable          | Description                                                                       |
+//! |-------------------|-----------------------------------------------------------------------------------|
+//! | `DEWEY_INCLUDE`   | Comma-separated main classes to embed (ie `5,6`). All classes are embedded if unset |
+//! | `DEWEY_MAX_DEPTH` | Depth of the deepest classes to embed (ie `2` to stop at sections, see `Class::depth`). Every class the source has is embedded if unset, including decimal subsections |
+//! | `DEWEY_OFFLINE`   | Set to `1` to skip fetching the dataset and embed the vendored copy, like the `offline` feature |
+//! | `DEWEY_SOURCE_URL` | URL to fetch the dataset from instead of OpenLibrary's `master` branch (ie a pinned commit or an internal mirror). Failing to fetch or parse it fails the build |
+//! | `DEWEY_SOURCE_FILE` | Path of a local dataset in the OpenLibrary JSON format to embed, taking precedence over fetching (relative to the crate's directory) |
+
+use std::{ borrow::Cow, collections::HashMap, sync::LazyLock };
 
 use trie_rs::map::Trie;
 pub use trie_rs;
 
+mod arrangement;
+mod assignment;
+mod audience;
+mod autocomplete;
+#[cfg(feature = "avro")]
+mod avro;
+#[cfg(feature = "bevy")]
+pub mod bevy;
+#[cfg(feature = "specta")]
+pub mod bindings;
+#[cfg(feature = "bisac")]
+mod bisac;
+mod blob;
+mod builder;
+#[cfg(feature = "cache")]
+mod cache;
+mod call_number;
+mod changelog;
+mod code;
+#[cfg(feature = "component")]
+mod component;
+mod correction;
+pub mod dataset;
+mod error;
+pub mod export;
+pub mod facets;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "polars")]
+mod frame;
+#[cfg(feature = "tantivy")]
+mod fulltext;
+#[cfg(feature = "arbitrary")]
+mod fuzz;
+#[cfg(feature = "fuzzy")]
+mod fuzzy;
+#[cfg(feature = "async-graphql")]
+mod graphql;
+#[cfg(feature = "http")]
+pub mod http;
+#[cfg(feature = "isbn")]
+mod isbn;
+#[cfg(feature = "jsonld")]
+mod jsonld;
+#[cfg(feature = "lcc")]
+mod lcc;
+#[cfg(feature = "lcsh")]
+mod lcsh;
+mod level;
+#[cfg(feature = "locales")]
+mod locale;
+mod lookup;
+#[cfg(feature = "marc")]
+mod marc;
+#[cfg(feature = "marcxml")]
+mod marcxml;
+#[cfg(feature = "mmap")]
+mod mapped;
+mod names;
+#[cfg(feature = "onnx")]
+mod onnx;
+mod overlay;
+#[cfg(feature = "rayon")]
+mod parallel;
+mod path;
+#[cfg(feature = "regex")]
+mod pattern;
+mod precompiled;
+mod pseudo;
+#[cfg(feature = "python")]
+pub mod python;
+mod query;
+mod range;
+mod registry;
+mod relative_index;
+pub mod render;
+#[cfg(feature = "scan")]
+mod scan;
+mod scheme;
+mod search;
+mod see_also;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "site")]
+mod site;
+#[cfg(feature = "skos")]
+mod skos;
+#[cfg(any(feature = "sqlx", feature = "diesel"))]
+mod sql;
+#[cfg(feature = "rusqlite")]
+mod sqlite;
+mod stats;
+#[cfg(feature = "proptest")]
+pub mod strategy;
+mod style;
+mod suggest;
+mod synonyms;
+mod synthesis;
+mod tables;
+#[cfg(feature = "tauri")]
+pub mod tauri;
+mod tree;
+#[cfg(feature = "udc")]
+mod udc;
+mod walk;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+pub use arrangement::{ ArrangementPolicies, BiographyArrangement, FictionArrangement, LawArrangement };
+pub use assignment::CodeGap;
+pub use audience::Audience;
+pub use autocomplete::Autocomplete;
+#[cfg(feature = "bisac")]
+pub use bisac::BisacSubject;
+#[cfg(feature = "avro")]
+pub use avro::AVRO_SCHEMA;
+pub use builder::{ DatasetEdition, DatasetSource, DeweyBuilder, DeweySource, LoadError };
+#[cfg(feature = "cache")]
+pub use cache::{ CacheStats, DEFAULT_CACHE_CAPACITY };
+pub use call_number::{ shelf_cmp, CallNumber, ParseCallNumberError };
+pub use changelog::{ ChangelogEntry, DatasetChange };
+pub use code::{ DdcCode, ParseCodeError };
+pub use correction::{ Correction, CorrectionKind };
+pub use error::DeweyError;
+#[cfg(feature = "tantivy")]
+pub use fulltext::FullTextIndex;
+#[cfg(feature = "mmap")]
+pub use mapped::{ MappedDataset, MAPPED_FORMAT_VERSION };
+#[cfg(feature = "isbn")]
+pub use isbn::{ IsbnClassification, IsbnError };
+pub use level::Level;
+#[cfg(feature = "locales")]
+pub use locale::Locale;
+pub use lookup::{ LookupMode, LookupOptions };
+#[cfg(feature = "marc")]
+pub use marc::{ DdcEdition, MarcClassification, MarcError };
+pub use names::LookupResult;
+#[cfg(feature = "onnx")]
+pub use onnx::{ OnnxBackend, OnnxError, DEFAULT_FEATURE_DIMENSIONS };
+pub use overlay::{ Overlay, OverlayError, OverlaySnapshot };
+pub use path::{ ParsePathError, CODE_PATH_SEPARATOR };
+pub use precompiled::CACHE_FORMAT_VERSION;
+#[cfg(feature = "serde")]
+pub use overlay::OVERLAY_SCHEMA_VERSION;
+pub use pseudo::{ PseudoClass, PseudoClasses, ShelfMark, ShelfPosition };
+pub use query::DeweyQuery;
+pub use registry::Registry;
+pub use relative_index::IndexEntry;
+#[cfg(feature = "scan")]
+pub use scan::{ ClassCandidate, TextMatch };
+pub use scheme::ClassificationScheme;
+pub use search::{ HasQuery, NoQuery, SearchOptions };
+#[cfg(feature = "skos")]
+pub use skos::SkosFormat;
+pub use stats::{ CategoryStats, DatasetStats };
+pub use style::CodeStyle;
+pub use suggest::{ SuggestBackend, Suggestion };
+pub use synonyms::Synonyms;
+pub use synthesis::{ NumberBuildError, NumberBuilder, SynthesizedNumber };
+pub use tables::{ ParseTableError, Table, TableEntry };
+pub use tree::ClassTree;
+#[cfg(feature = "udc")]
+pub use udc::UdcMapping;
+pub use walk::{ TraversalOrder, VisitControl };
+
 include!(concat!(env!("OUT_DIR"), "/classes.rs"));
 
-static CLASSES: std::sync::LazyLock<Trie<u8, Class>> = std::sync::LazyLock::new(||
-    make_class_static()
-);
+/// Marks splitting a DDC number into segments where it may be abridged (ie `005/13` or `005′13`)
+const SEGMENT_MARKS: [char; 3] = ['/', '\'', '′'];
+
+/// Cleans up a loosely written code, dropping whitespace, `X` padding (in either case) & segmentation marks
+///
+/// A decimal point is added after the third digit of longer codes written without one (ie `005/13` becomes `005.13`). Anything else is kept, so malformed codes stay malformed.
+pub(crate) fn lenient_code(code: &str) -> String {
+    let mut code = code
+        .trim()
+        .trim_matches(['X', 'x'])
+        .chars()
+        .filter(|c| !c.is_whitespace() && !SEGMENT_MARKS.contains(c))
+        .collect::<String>();
+    if code.len() > 3 && code.is_char_boundary(3) && !code.contains('.') {
+        code.insert(3, '.');
+    }
+    code
+}
+
+/// Converts a code into a trie label, dropping the decimal point after the third digit and mapping any other non-digit to a label byte that never matches
+///
+/// Codes are cleaned up with [lenient_code] first, so ` 51 `, `09X` and `005/13` all find their class.
+pub(crate) fn code_label(code: &str) -> Vec<u8> {
+    lenient_code(code)
+        .char_indices()
+        .filter(|(index, c)| !(*index == 3 && *c == '.'))
+        .map(|(_, c)| c.to_digit(10).map_or(u8::MAX, |digit| digit as u8))
+        .collect()
+}
+
+/// Classes of a dataset, along with precomputed indexes
+pub(crate) struct Dataset {
+    pub(crate) classes: Trie<u8, Class>,
+    pub(crate) children: HashMap<String, Vec<Class>>,
+    pub(crate) raw: &'static [RawNode],
+    pub(crate) source: DatasetSource,
+    pub(crate) names: &'static [(&'static str, &'static str)],
+    pub(crate) keywords: &'static [(&'static str, &'static [&'static str])],
+    /// Classes keyed by their cleaned-up code, for constant-time exact lookups (built on first use, see [Dewey::class_ref])
+    ///
+    /// Built at runtime rather than generated by the build script: embedded classes only exist once they're decoded from the blob, & loaded or mapped datasets need the same index.
+    pub(crate) exact: std::sync::OnceLock<HashMap<String, &'static Class>>,
+    /// Optional arrangements in use with this dataset, see [Dewey::set_arrangement_policies]
+    pub(crate) policies: std::sync::RwLock<ArrangementPolicies>,
+    /// Pseudo-classes recognized with this dataset, see [Dewey::set_pseudo_classes]
+    pub(crate) pseudo_classes: std::sync::RwLock<PseudoClasses>,
+    #[cfg(feature = "cache")]
+    pub(crate) cache: cache::QueryCache,
+    #[cfg(feature = "scan")]
+    pub(crate) scanner: std::sync::OnceLock<scan::Scanner>,
+    #[cfg(feature = "tantivy")]
+    pub(crate) fulltext: std::sync::OnceLock<fulltext::FullTextIndex>,
+}
+
+impl Dataset {
+    fn standard() -> Self {
+        let classes = blob::embedded_classes();
+        let children = CHILD_CODES.iter()
+            .map(|(parent, codes)| {
+                let mut children = codes
+                    .iter()
+                    .filter_map(|code| classes.exact_match(code_label(code)).cloned())
+                    .collect::<Vec<_>>();
+                children.sort();
+                (parent.to_string(), children)
+            })
+            .collect();
+
+        Self {
+            classes,
+            children,
+            raw: RAW_TREE,
+            source: EMBEDDED_SOURCE,
+            names: NAME_INDEX,
+            keywords: KEYWORD_INDEX,
+            exact: Default::default(),
+            policies: Default::default(),
+            pseudo_classes: std::sync::RwLock::new(PseudoClasses::builtin()),
+            #[cfg(feature = "cache")]
+            cache: Default::default(),
+            #[cfg(feature = "scan")]
+            scanner: Default::default(),
+            #[cfg(feature = "tantivy")]
+            fulltext: Default::default(),
+        }
+    }
+}
+
+static STANDARD: LazyLock<Dataset> = LazyLock::new(Dataset::standard);
+
+fn standard_dataset() -> &'static Dataset {
+    &STANDARD
+}
+
+/// Handle to a Dewey Decimal dataset, used for getting [Class] instances
+///
+/// Handles are cheap to copy. Use [Dewey::standard] to get a handle to the embedded dataset, or [Dewey::builder] to load a dataset at runtime.
+///
+/// ```rust
+/// use dewey_decimal::Dewey;
+///
+/// let dewey = Dewey::standard();
+/// assert_eq!(dewey.get_class("51").unwrap().name, "Mathematics");
+/// ```
+#[derive(Clone, Copy)]
+pub struct Dewey {
+    backing: Backing,
+}
+
+/// Dataset behind a [Dewey] handle
+#[derive(Clone, Copy)]
+enum Backing {
+    /// A dataset built lazily, the first time it's used
+    Lazy(fn() -> &'static Dataset),
+
+    /// A dataset loaded at runtime (see [DeweyBuilder])
+    Loaded(&'static Dataset),
+
+    /// A memory-mapped data file, decoded the first time it's used (see [MappedDataset::into_dewey])
+    #[cfg(feature = "mmap")]
+    Mapped(&'static MappedDataset),
+}
+
+/// Handle to the embedded dataset, keeping the original unit-struct style (`Dewey.get_class(...)`) compiling, use [Dewey::standard] instead
+///
+/// It isn't marked `#[deprecated]`, since it shares its name with the [Dewey] type & the warning would fire on every import of the type too.
+#[doc(hidden)]
+#[allow(non_upper_case_globals)]
+pub const Dewey: Dewey = Dewey::standard();
+
+impl std::fmt::Debug for Dewey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Dewey").finish_non_exhaustive()
+    }
+}
 
-/// Stateless struct for getting [Class] instances
-pub struct Dewey;
+impl Default for Dewey {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
 
 impl Dewey {
-    /// Gets the underlying prefix trie ([crate::trie_rs::map::Trie])
+    /// Gets a handle to the embedded dataset
+    ///
+    /// The underlying trie is built lazily, the first time it's used.
+    ///
+    /// # Returns
+    ///
+    /// - `Dewey` - A handle to the standard classes
+    pub const fn standard() -> Self {
+        Self { backing: Backing::Lazy(standard_dataset) }
+    }
+
+    pub(crate) fn loaded(dataset: &'static Dataset) -> Self {
+        Self { backing: Backing::Loaded(dataset) }
+    }
+
+    #[cfg(feature = "mmap")]
+    pub(crate) fn mapped(dataset: &'static MappedDataset) -> Self {
+        Self { backing: Backing::Mapped(dataset) }
+    }
+
+    pub(crate) fn dataset(&self) -> &'static Dataset {
+        match self.backing {
+            Backing::Lazy(dataset) => dataset(),
+            Backing::Loaded(dataset) => dataset,
+            #[cfg(feature = "mmap")]
+            Backing::Mapped(dataset) => dataset.dataset(),
+        }
+    }
+
+    pub(crate) fn classes(&self) -> &'static Trie<u8, Class> {
+        &self.dataset().classes
+    }
+
+    #[cfg(feature = "cache")]
+    fn cached(&self, key: impl FnOnce() -> String, compute: impl FnOnce() -> Vec<Class>) -> Vec<Class> {
+        self.dataset().cache.get_or_insert(key(), compute)
+    }
+
+    #[cfg(not(feature = "cache"))]
+    fn cached(&self, _key: impl FnOnce() -> String, compute: impl FnOnce() -> Vec<Class>) -> Vec<Class> {
+        compute()
+    }
+
+    /// Gets a copy of the underlying prefix trie ([crate::trie_rs::map::Trie])
+    ///
+    /// This clones every class, so prefer [Dewey::map_ref] unless an owned trie is needed.
     ///
     /// # Returns
     ///
     /// - `Trie<u8, Class>` - The underlying prefix trie
     pub fn map(&self) -> Trie<u8, Class> {
-        CLASSES.to_owned()
+        self.classes().to_owned()
+    }
+
+    /// Gets the underlying prefix trie ([crate::trie_rs::map::Trie]), without copying it
+    ///
+    /// # Returns
+    ///
+    /// - `&'static Trie<u8, Class>` - The underlying prefix trie
+    pub fn map_ref(&self) -> &'static Trie<u8, Class> {
+        self.classes()
+    }
+
+    /// Gets the source dataset in its original nested structure
+    ///
+    /// Unlike the trie, this includes every node the source provides (such as ranges like `007–009`), untrimmed.
+    ///
+    /// # Returns
+    ///
+    /// - `&'static [RawNode]` - The main class nodes of the source dataset
+    pub fn raw_tree(&self) -> &'static [RawNode] {
+        self.dataset().raw
     }
 
     /// Gets a [Vec] of all classes
@@ -59,17 +487,20 @@ impl Dewey {
     /// 
     /// - `Vec<Class>` - Gigantic [Vec] of [Class] instances
     pub fn all(&self) -> Vec<Class> {
-        self.map().iter().map(|item: (Vec<u8>, &Class)| item.1.clone())
-            .collect()
+        self.all_ref().cloned().collect()
+    }
+
+    /// Iterates over all classes in shelf order, without copying them
+    ///
+    /// # Returns
+    ///
+    /// - `impl Iterator<Item = &'static Class>` - Iterator over every [Class]
+    pub fn all_ref(&self) -> impl Iterator<Item = &'static Class> {
+        self.classes().iter().map(|item: (Vec<u8>, &'static Class)| item.1)
     }
 
     fn as_label(&self, code: impl AsRef<str>) -> Vec<u8> {
-        code.as_ref()
-            .to_string()
-            .trim_matches('X')
-            .chars()
-            .map(|c| c.to_string().parse::<u8>().unwrap())
-            .collect()
+        code_label(code.as_ref())
     }
 
     /// Gets a class by exact code match
@@ -82,7 +513,49 @@ impl Dewey {
     ///
     /// - `Option<Class>` - The [Class] that matches the provided code, or [None] if not found.
     pub fn get_class(&self, code: impl AsRef<str>) -> Option<Class> {
-        self.map().exact_match(self.as_label(code)).cloned()
+        self.class_ref(code).cloned()
+    }
+
+    /// Gets a class by exact code match, without copying it
+    ///
+    /// Exact matches go through a hash index rather than the trie, so they take constant time. The index is built the first time it's needed.
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code to search for
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&'static Class>` - The [Class] that matches the provided code, or [None] if not found.
+    pub fn class_ref(&self, code: impl AsRef<str>) -> Option<&'static Class> {
+        let code = code.as_ref();
+        let index = self.exact_index();
+        index.get(code).or_else(|| index.get(&lenient_code(code))).copied()
+    }
+
+    fn exact_index(&self) -> &'static HashMap<String, &'static Class> {
+        let dataset = self.dataset();
+        dataset.exact.get_or_init(|| self.all_ref().map(|class| (lenient_code(&class.code), class)).collect())
+    }
+
+    /// Gets the most specific class a code falls under (longest-prefix match)
+    ///
+    /// Useful for normalizing call numbers from messy catalog exports: only the DDC number at the start of the input is read (ie `621.39` for `621.39 B47`), and numbers deeper than the dataset resolve to their closest known ancestor (ie `781.6555` to `781`).
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code or call number to classify
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Class>` - The most specific [Class] that is a prefix of the code, or [None] if the code doesn't start with a known class
+    pub fn classify(&self, code: impl AsRef<str>) -> Option<Class> {
+        let code = dataset::leading_code(code.as_ref().trim());
+        self.classes()
+            .common_prefix_search(self.as_label(code))
+            .map(|item: (Vec<u8>, &'static Class)| item.1)
+            .last()
+            .cloned()
     }
 
     /// Returns all classes matching the provided prefix
@@ -93,12 +566,53 @@ impl Dewey {
     ///
     /// # Returns
     ///
-    /// - `Vec<Class>` - [Vec] of [Class] instances matching the prefix
+    /// - `Vec<Class>` - [Vec] of [Class] instances matching the prefix, in shelf order
     pub fn get_matches(&self, code: impl AsRef<str>) -> Vec<Class> {
-        self.map()
+        let label = self.as_label(code);
+        self.cached(
+            || format!("matches:{label:?}"),
+            || {
+                self.classes()
+                    .predictive_search(&label)
+                    .map(|item: (Vec<u8>, &Class)| item.1.clone())
+                    .collect()
+            }
+        )
+    }
+
+    /// Iterates over all classes matching the provided prefix, without copying them
+    ///
+    /// Unlike [Dewey::get_matches], results are never cached, as there's nothing to copy.
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code to search for
+    ///
+    /// # Returns
+    ///
+    /// - `impl Iterator<Item = &'static Class>` - Iterator over the [Class] instances matching the prefix, in shelf order
+    pub fn matches_ref<C: AsRef<str>>(&self, code: C) -> impl Iterator<Item = &'static Class> + use<C> {
+        self.classes()
             .predictive_search(self.as_label(code))
-            .map(|item: (Vec<u8>, &Class)| item.1.clone())
-            .collect()
+            .map(|item: (Vec<u8>, &'static Class)| item.1)
+    }
+
+    /// Gets the direct children of the class with the provided code, without copying them
+    ///
+    /// Children are precomputed at build time, so this is a single hash lookup.
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code to search for (an empty string gets the main classes)
+    ///
+    /// # Returns
+    ///
+    /// - `&'static [Class]` - Slice of [Class] instances that are direct children of the specified class, in shelf order
+    pub fn children_of(&self, code: impl AsRef<str>) -> &'static [Class] {
+        self.dataset()
+            .children
+            .get(lenient_code(code.as_ref()).as_str())
+            .map_or(&[], Vec::as_slice)
     }
 
     /// Gets all the direct children of the class with the provided code
@@ -109,15 +623,9 @@ impl Dewey {
     ///
     /// # Returns
     ///
-    /// - `Vec<Class>` - [Vec] of [Class] instances that are direct children of the specified prefix
+    /// - `Vec<Class>` - [Vec] of [Class] instances that are direct children of the specified prefix, in shelf order
     pub fn get_direct_children(&self, code: impl AsRef<str>) -> Vec<Class> {
-        let code = code.as_ref().to_string();
-        self.get_matches(code.clone())
-            .into_iter()
-            .filter_map(|c| {
-                if c.code.len() == code.len() + 1 { Some(c) } else { None }
-            })
-            .collect()
+        self.children_of(code).to_vec()
     }
 
     /// Gets all children (not including the exact match itself)
@@ -128,18 +636,35 @@ impl Dewey {
     ///
     /// # Returns
     ///
-    /// - `Vec<Class>` - [Vec] of all children of this prefix
+    /// - `Vec<Class>` - [Vec] of all children of this prefix, in shelf order
     pub fn get_all_children(&self, code: impl AsRef<str>) -> Vec<Class> {
-        let code = code.as_ref().to_string();
-        self.get_matches(code.clone())
+        let code = lenient_code(code.as_ref());
+        self.get_matches(&code)
             .into_iter()
-            .filter_map(|c| {
-                if c.code == code { None } else { Some(c) }
-            })
+            .filter(|c| c.code != code)
             .collect()
     }
 
-    /// Gets the parent of the selected prefix, if any
+    /// Iterates over all children (not including the exact match itself), without copying them
+    ///
+    /// Results are streamed from the trie, so taking only the first few is cheap.
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code to search for
+    ///
+    /// # Returns
+    ///
+    /// - `impl Iterator<Item = &'static Class>` - Iterator over all children of this prefix, in shelf order
+    pub fn all_children_ref<C: AsRef<str>>(&self, code: C) -> impl Iterator<Item = &'static Class> + use<C> {
+        let label = self.as_label(code);
+        self.classes()
+            .predictive_search(label.clone())
+            .filter(move |item: &(Vec<u8>, &'static Class)| item.0 != label)
+            .map(|item| item.1)
+    }
+
+    /// Gets the parent of the selected class, if any
     ///
     /// # Arguments
     ///
@@ -147,31 +672,299 @@ impl Dewey {
     ///
     /// # Returns
     ///
-    /// - `Option<Class>` - Parent of the selected [Class], if any
+    /// - `Option<Class>` - Parent of the selected [Class], or [None] if the class is a main class or doesn't exist
     pub fn get_parent(&self, code: impl AsRef<str>) -> Option<Class> {
-        let mut code = code.as_ref().to_string();
-        if code.len() > 1 {
-            let _ = code.pop();
-            self.get_class(code)
-        } else {
-            None
+        self.get_class(code)?
+            .parent_code()
+            .and_then(|parent| self.get_class(parent))
+    }
+
+    /// Gets the other classes sharing a parent with the selected class
+    ///
+    /// Main classes are siblings of each other.
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code to search for
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<Class>` - [Vec] of sibling [Class] instances in shelf order, not including the class itself (empty if the class doesn't exist)
+    pub fn get_siblings(&self, code: impl AsRef<str>) -> Vec<Class> {
+        let Some(class) = self.class_ref(code) else {
+            return Vec::new();
+        };
+        self.children_of(class.parent_code().unwrap_or(""))
+            .iter()
+            .filter(|sibling| sibling.code != class.code)
+            .cloned()
+            .collect()
+    }
+
+    /// Iterates over the ancestors of a class, parent first
+    fn ancestors_of(&self, class: &Class) -> impl Iterator<Item = &'static Class> + use<> {
+        let dewey = *self;
+        std::iter::successors(
+            class.parent_code().and_then(|code| dewey.class_ref(code)),
+            move |class| class.parent_code().and_then(|code| dewey.class_ref(code))
+        )
+    }
+
+    /// Gets a class and its ancestors, main class first
+    pub(crate) fn lineage(&self, class: &Class) -> Vec<Class> {
+        let mut lineage = vec![class.clone()];
+        lineage.extend(self.ancestors_of(class).cloned());
+        lineage.reverse();
+        lineage
+    }
+
+    /// Iterates over the ancestors of the class with the provided code, without copying them
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code of the class
+    ///
+    /// # Returns
+    ///
+    /// - `impl Iterator<Item = &'static Class>` - Iterator from the class's parent up to its main class (empty if the class is a main class or doesn't exist)
+    pub fn ancestors(&self, code: impl AsRef<str>) -> impl Iterator<Item = &'static Class> {
+        self.class_ref(code).into_iter().flat_map(|class| self.ancestors_of(class))
+    }
+
+    /// Gets the path from a main class down to the class with the provided code, ie for UI breadcrumbs
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code of the class
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<Class>` - The class's main class first, ending with the class itself (empty if the class doesn't exist)
+    pub fn breadcrumb(&self, code: impl AsRef<str>) -> Vec<Class> {
+        self.class_ref(code).map_or_else(Vec::new, |class| self.lineage(class))
+    }
+
+    /// Checks whether a class is a (strict) ancestor of another, ie whether `005` falls under `00`
+    ///
+    /// # Arguments
+    ///
+    /// - `ancestor` (`impl AsRef<str>`) - Code of the potential ancestor
+    /// - `code` (`impl AsRef<str>`) - Code of the potential descendant
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether both classes exist and `ancestor` is above `code` in the hierarchy
+    pub fn is_ancestor_of(&self, ancestor: impl AsRef<str>, code: impl AsRef<str>) -> bool {
+        self.class_ref(ancestor).is_some_and(|ancestor| self.ancestors(code).any(|class| std::ptr::eq(class, ancestor)))
+    }
+
+    /// Checks whether a class is a (strict) descendant of another (see [Dewey::is_ancestor_of])
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code of the potential descendant
+    /// - `ancestor` (`impl AsRef<str>`) - Code of the potential ancestor
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether both classes exist and `code` is below `ancestor` in the hierarchy
+    pub fn is_descendant_of(&self, code: impl AsRef<str>, ancestor: impl AsRef<str>) -> bool {
+        self.is_ancestor_of(ancestor, code)
+    }
+
+    /// Gets the lowest common ancestor of two classes, ie `00` for `005` & `009`
+    ///
+    /// A class counts as its own ancestor, so the common ancestor of `00` & `005` is `00`.
+    ///
+    /// # Arguments
+    ///
+    /// - `a` (`impl AsRef<str>`) - Code of the first class
+    /// - `b` (`impl AsRef<str>`) - Code of the second class
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Class>` - The most specific [Class] both classes fall under, or [None] if they're in different main classes or either doesn't exist
+    pub fn common_ancestor(&self, a: impl AsRef<str>, b: impl AsRef<str>) -> Option<Class> {
+        self.breadcrumb(a)
+            .into_iter()
+            .zip(self.breadcrumb(b))
+            .take_while(|(a, b)| a.code == b.code)
+            .last()
+            .map(|(class, _)| class)
+    }
+
+    /// Gets the number of steps between two classes in the hierarchy, going up to their lowest common ancestor & back down (see [Dewey::common_ancestor])
+    ///
+    /// Main classes count as children of an implicit root, so classes in different main classes are still connected (ie `0` & `5` are two steps apart).
+    ///
+    /// # Arguments
+    ///
+    /// - `a` (`impl AsRef<str>`) - Code of the first class
+    /// - `b` (`impl AsRef<str>`) - Code of the second class
+    ///
+    /// # Returns
+    ///
+    /// - `Option<usize>` - The path length between the classes (`0` for the same class), or [None] if either doesn't exist
+    pub fn distance(&self, a: impl AsRef<str>, b: impl AsRef<str>) -> Option<usize> {
+        let (a, b, common) = self.paths(a, b)?;
+        Some(a + b - 2 * common)
+    }
+
+    /// Scores how related two classes are, from `0.0` (in different main classes) to `1.0` (the same class)
+    ///
+    /// This is the Wu-Palmer similarity: twice the depth of the lowest common ancestor, divided by the sum of the classes' depths, with main classes at depth `1`. Siblings deep in the tree score higher than siblings near the top (ie `005` & `006` are closer than `0` & `1`).
+    ///
+    /// # Arguments
+    ///
+    /// - `a` (`impl AsRef<str>`) - Code of the first class
+    /// - `b` (`impl AsRef<str>`) - Code of the second class
+    ///
+    /// # Returns
+    ///
+    /// - `Option<f64>` - The similarity of the classes, or [None] if either doesn't exist
+    pub fn similarity(&self, a: impl AsRef<str>, b: impl AsRef<str>) -> Option<f64> {
+        let (a, b, common) = self.paths(a, b)?;
+        Some((2 * common) as f64 / (a + b) as f64)
+    }
+
+    /// Gets the lengths of the breadcrumbs of two classes, and of their common part
+    fn paths(&self, a: impl AsRef<str>, b: impl AsRef<str>) -> Option<(usize, usize, usize)> {
+        let (a, b) = (self.breadcrumb(a), self.breadcrumb(b));
+        if a.is_empty() || b.is_empty() {
+            return None;
         }
+        let common = a.iter().zip(&b).take_while(|(a, b)| a.code == b.code).count();
+        Some((a.len(), b.len(), common))
     }
 
     /// Gets the top-level categories (codes `0` through `9`)
     ///
+    /// Main classes excluded at build time through `DEWEY_INCLUDE` are skipped.
+    ///
     /// # Returns
     ///
     /// - `Vec<Class>` - [Vec] of top-level classes
     pub fn categories(&self) -> Vec<Class> {
-        "0123456789"
-            .chars()
-            .map(|c| self.get_class(c.to_string()).unwrap())
-            .collect()
+        self.children_of("").to_vec()
+    }
+}
+
+/// Classes are equal when their codes are, regardless of the dataset they came from
+impl PartialEq for Class {
+    fn eq(&self, other: &Self) -> bool {
+        self.code == other.code
     }
 }
 
+impl Eq for Class {}
+
+impl std::hash::Hash for Class {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.code.hash(state);
+    }
+}
+
+impl PartialOrd for Class {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Classes order in shelf order: numerically by code, with every class directly before its children (`5`, `50`, `500`, `501`, ..., `51`, `510`, `516`, `516.3`, `517`)
+impl Ord for Class {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        code_label(&self.code).cmp(&code_label(&other.code)).then_with(|| self.code.cmp(&other.code))
+    }
+}
+
+/// Decodes classes field by field like the derived implementation would, except that strings are always owned (a derived `BorrowDecode` can't produce `Cow<'static, str>` from borrowed input)
+#[cfg(feature = "bincode")]
+impl<Context> bincode::Decode<Context> for Class {
+    fn decode<D: bincode::de::Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, bincode::error::DecodeError> {
+        Ok(Class {
+            code: Cow::Owned(String::decode(decoder)?),
+            name: Cow::Owned(String::decode(decoder)?),
+            has_children: bool::decode(decoder)?,
+            child_count: usize::decode(decoder)?,
+            descendant_count: usize::decode(decoder)?,
+            parent_code: Option::<String>::decode(decoder)?.map(Cow::Owned),
+            #[cfg(feature = "count")]
+            work_count: u64::decode(decoder)?,
+            #[cfg(feature = "query")]
+            query: Cow::Owned(String::decode(decoder)?),
+            #[cfg(feature = "notes")]
+            notes: Option::<String>::decode(decoder)?.map(Cow::Owned),
+        })
+    }
+}
+
+#[cfg(feature = "bincode")]
+bincode::impl_borrow_decode!(Class);
+
 impl Class {
+    /// Creates a class that isn't part of the source dataset, without any metadata
+    pub(crate) fn local(code: impl AsRef<str>, name: impl AsRef<str>, parent_code: Option<String>) -> Self {
+        Class {
+            code: Cow::Owned(code.as_ref().to_string()),
+            name: Cow::Owned(name.as_ref().to_string()),
+            has_children: false,
+            child_count: 0,
+            descendant_count: 0,
+            parent_code: parent_code.map(Cow::Owned),
+            #[cfg(feature = "count")]
+            work_count: 0,
+            #[cfg(feature = "query")]
+            query: Cow::Borrowed(""),
+            #[cfg(feature = "notes")]
+            notes: None,
+        }
+    }
+
+    /// Creates a class from its fields in const context, for the `dewey!` macro of `dewey-decimal-macros` (not part of the public API)
+    ///
+    /// Metadata of disabled features is ignored.
+    #[doc(hidden)]
+    #[allow(clippy::too_many_arguments)]
+    pub const fn __constant(
+        code: &'static str,
+        name: &'static str,
+        has_children: bool,
+        child_count: usize,
+        descendant_count: usize,
+        parent_code: Option<&'static str>,
+        work_count: u64,
+        query: &'static str,
+        notes: Option<&'static str>
+    ) -> Self {
+        #[cfg(not(feature = "count"))]
+        let _ = work_count;
+        #[cfg(not(feature = "query"))]
+        let _ = query;
+        #[cfg(not(feature = "notes"))]
+        let _ = notes;
+
+        Class {
+            code: Cow::Borrowed(code),
+            name: Cow::Borrowed(name),
+            has_children,
+            child_count,
+            descendant_count,
+            parent_code: match parent_code {
+                Some(parent_code) => Some(Cow::Borrowed(parent_code)),
+                None => None,
+            },
+            #[cfg(feature = "count")]
+            work_count,
+            #[cfg(feature = "query")]
+            query: Cow::Borrowed(query),
+            #[cfg(feature = "notes")]
+            notes: match notes {
+                Some(notes) => Some(Cow::Borrowed(notes)),
+                None => None,
+            },
+        }
+    }
+
     /// Gets a class based on a provided code (exact match)
     ///
     /// # Arguments
@@ -180,29 +973,124 @@ impl Class {
     ///
     /// # Returns
     ///
-    /// - `Option<Self>` - A new [Class] if found, otherwise [None]
+    /// - `Option<Self>` - A new [Class] from [Dewey::standard] if found, otherwise [None]
+    #[deprecated(note = "use `Dewey::standard().get_class(code)` instead")]
     pub fn get(code: impl AsRef<str>) -> Option<Self> {
-        Dewey.get_class(code)
+        Dewey::standard().get_class(code)
     }
 
-    /// See [Dewey::get_matches]
+    /// Looks up the matches of this class in the standard dataset, see [Dewey::get_matches]
+    #[deprecated(note = "only looks in `Dewey::standard()`, use `dewey.get_matches(&class.code)` on the class's dataset instead")]
     pub fn matches(&self) -> Vec<Class> {
-        Dewey.get_matches(self.code.clone())
+        Dewey::standard().get_matches(self.code.clone())
     }
 
-    /// See [Dewey::get_all_children]
+    /// Looks up the descendants of this class in the standard dataset, see [Dewey::get_all_children]
+    #[deprecated(note = "only looks in `Dewey::standard()`, use `dewey.get_all_children(&class.code)` on the class's dataset instead")]
     pub fn all_children(&self) -> Vec<Class> {
-        Dewey.get_all_children(self.code.clone())
+        Dewey::standard().get_all_children(self.code.clone())
     }
 
-    /// See [Dewey::get_direct_children]
+    /// Looks up the direct children of this class in the standard dataset, see [Dewey::get_direct_children]
+    #[deprecated(note = "only looks in `Dewey::standard()`, use `dewey.get_direct_children(&class.code)` on the class's dataset instead")]
     pub fn children(&self) -> Vec<Class> {
-        Dewey.get_direct_children(self.code.clone())
+        Dewey::standard().get_direct_children(self.code.clone())
     }
 
-    /// See [Dewey::get_parent]
+    /// Looks up the matches of this class in the standard dataset, see [Dewey::matches_ref]
+    #[deprecated(note = "only looks in `Dewey::standard()`, use `dewey.matches_ref(&class.code)` on the class's dataset instead")]
+    pub fn matches_iter(&self) -> impl Iterator<Item = &'static Class> + use<> {
+        Dewey::standard().matches_ref(self.code.clone())
+    }
+
+    /// Looks up the descendants of this class in the standard dataset, see [Dewey::all_children_ref]
+    #[deprecated(note = "only looks in `Dewey::standard()`, use `dewey.all_children_ref(&class.code)` on the class's dataset instead")]
+    pub fn all_children_iter(&self) -> impl Iterator<Item = &'static Class> + use<> {
+        Dewey::standard().all_children_ref(self.code.clone())
+    }
+
+    /// Looks up the direct children of this class in the standard dataset, see [Dewey::children_of]
+    #[deprecated(note = "only looks in `Dewey::standard()`, use `dewey.children_of(&class.code)` on the class's dataset instead")]
+    pub fn children_iter(&self) -> impl Iterator<Item = &'static Class> + use<> {
+        Dewey::standard().children_of(&self.code).iter()
+    }
+
+    /// Looks up the parent of this class in the standard dataset, see [Dewey::get_parent]
+    #[deprecated(note = "only looks in `Dewey::standard()`, use `dewey.get_parent(&class.code)` on the class's dataset instead")]
     pub fn parent(&self) -> Option<Class> {
-        Dewey.get_parent(self.code.clone())
+        Dewey::standard().get_parent(&self.code)
+    }
+
+    /// Looks up the siblings of this class in the standard dataset, see [Dewey::get_siblings]
+    #[deprecated(note = "only looks in `Dewey::standard()`, use `dewey.get_siblings(&class.code)` on the class's dataset instead")]
+    pub fn siblings(&self) -> Vec<Class> {
+        Dewey::standard().get_siblings(&self.code)
+    }
+
+    /// Looks up the ancestors of this class in the standard dataset, see [Dewey::ancestors]
+    #[deprecated(note = "only looks in `Dewey::standard()`, use `dewey.ancestors(&class.code)` on the class's dataset instead")]
+    pub fn ancestors(&self) -> impl Iterator<Item = Class> + use<> {
+        Dewey::standard().ancestors_of(self).cloned()
+    }
+
+    /// Looks up the breadcrumb of this class in the standard dataset, see [Dewey::breadcrumb]
+    #[deprecated(note = "only looks in `Dewey::standard()`, use `dewey.breadcrumb(&class.code)` on the class's dataset instead")]
+    pub fn breadcrumb(&self) -> Vec<Class> {
+        Dewey::standard().lineage(self)
+    }
+
+    /// Checks whether this class is an ancestor of another in the standard dataset, see [Dewey::is_ancestor_of]
+    #[deprecated(note = "only looks in `Dewey::standard()`, use `dewey.is_ancestor_of(&class.code, &other.code)` on the classes' dataset instead")]
+    pub fn is_ancestor_of(&self, other: &Class) -> bool {
+        Dewey::standard().is_ancestor_of(&self.code, &other.code)
+    }
+
+    /// Checks whether this class is a descendant of another in the standard dataset, see [Dewey::is_descendant_of]
+    #[deprecated(note = "only looks in `Dewey::standard()`, use `dewey.is_descendant_of(&class.code, &other.code)` on the classes' dataset instead")]
+    pub fn is_descendant_of(&self, other: &Class) -> bool {
+        Dewey::standard().is_descendant_of(&self.code, &other.code)
+    }
+
+    /// Looks up the common ancestor of this class & another in the standard dataset, see [Dewey::common_ancestor]
+    #[deprecated(note = "only looks in `Dewey::standard()`, use `dewey.common_ancestor(&class.code, &other.code)` on the classes' dataset instead")]
+    pub fn common_ancestor(&self, other: &Class) -> Option<Class> {
+        Dewey::standard().common_ancestor(&self.code, &other.code)
+    }
+
+    /// Gets the code of this class's parent, as recorded in the source hierarchy
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&str>` - The parent's code, or [None] if this is a main class
+    pub fn parent_code(&self) -> Option<&str> {
+        self.parent_code.as_deref()
+    }
+
+    /// Gets the OpenLibrary search query for works in this class (ie `ddc:00*`), as used by the Library Explorer
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The query, searching the `ddc` field with this class's query string
+    #[cfg(feature = "query")]
+    pub fn openlibrary_query(&self) -> String {
+        format!("ddc:{}", self.query)
+    }
+
+    /// Gets a link to the OpenLibrary search results for works in this class
+    ///
+    /// # Returns
+    ///
+    /// - `String` - URL of the search for [Class::openlibrary_query]
+    #[cfg(feature = "query")]
+    pub fn openlibrary_url(&self) -> String {
+        let query = self.openlibrary_query()
+            .bytes()
+            .map(|byte| match byte {
+                b'0'..=b'9' | b'A'..=b'Z' | b'a'..=b'z' | b'-' | b'_' | b'.' | b'~' | b'*' => char::from(byte).to_string(),
+                _ => format!("%{byte:02X}"),
+            })
+            .collect::<String>();
+        format!("https://openlibrary.org/search?q={query}")
     }
 }
 
@@ -212,25 +1100,257 @@ mod test {
 
     #[test]
     fn test_get() {
-        for (code, name) in vec![
+        for (code, name) in [
             ("247", "Church furnishings & related articles"),
             ("19", "Modern Western philosophy (19th-century, 20th-century)"),
             ("0", "Computer science, information & general works")
         ] {
-            let result = Class::get(code);
+            let result = Dewey::standard().get_class(code);
             assert!(result.is_some(), "Expected Some(...)!");
             assert_eq!(result.unwrap().name, name.to_string(), "Names didn't match!");
         }
 
-        assert!(Class::get("008").is_none(), "This code is unused!");
+        assert!(Dewey::standard().get_class("008").is_none(), "This code is unused!");
+    }
+
+    #[test]
+    fn test_refs() {
+        let dewey = Dewey::standard();
+        let class = dewey.class_ref("516").expect("Expected Some(...)!");
+        assert!(std::ptr::eq(class, dewey.class_ref("516").unwrap()), "Expected the same static class");
+        assert!(std::ptr::eq(dewey.map_ref(), dewey.map_ref()), "Expected the same static trie");
+        assert_eq!(dewey.matches_ref("51").count(), dewey.get_matches("51").len());
+        assert_eq!(dewey.all_ref().count(), dewey.all().len());
+        assert!(dewey.class_ref("5a").is_none(), "Expected invalid codes not to match");
+        assert_eq!(dewey.all_children_ref("51").cloned().collect::<Vec<_>>(), dewey.get_all_children("51"));
+        assert_eq!(dewey.get_all_children(" 51X"), dewey.get_all_children("51"), "Expected loosely written codes to skip the exact match");
+    }
+
+    #[test]
+    fn test_classify() {
+        let dewey = Dewey::standard();
+        for (input, code) in [("781.6555", "781"), ("909.82", "909"), ("621.39 B47", "621"), ("51X", "51"), ("5", "5")] {
+            assert_eq!(dewey.classify(input).map(|class| class.code), Some(code.into()), "Unexpected class for {input}");
+        }
+        assert!(dewey.classify("008.5").is_some_and(|class| class.code == "00"), "Expected the closest known ancestor");
+        assert!(dewey.classify("FIC").is_none() && dewey.classify("").is_none(), "Expected no class");
     }
 
     #[test]
     fn test_matches() {
-        for (code, matches) in vec![("247", 1usize), ("09", 11usize), ("0", 98usize)] {
-            let result = Class::get(code);
+        for (code, matches) in [("247", 1usize), ("09", 11usize), ("0", 98usize)] {
+            let result = Dewey::standard().get_class(code);
             assert!(result.is_some(), "Expected Some(...)!");
-            assert_eq!(result.unwrap().matches().len(), matches, "Unexpected number of matches");
+            assert_eq!(Dewey::standard().get_matches(code).len(), matches, "Unexpected number of matches");
+        }
+    }
+
+    #[test]
+    fn test_parent() {
+        for (code, parent) in [("247", Some("24")), ("24", Some("2")), ("2", None)] {
+            let class = Dewey::standard().get_class(code).unwrap();
+            assert_eq!(class.parent_code(), parent, "Unexpected parent code");
+            assert_eq!(Dewey::standard().get_parent(code).map(|c| c.code), parent.map(Cow::from), "Unexpected parent");
+        }
+    }
+
+    #[test]
+    fn test_ancestors() {
+        let dewey = Dewey::standard();
+        let codes = |classes: Vec<Class>| classes.into_iter().map(|class| class.code).collect::<Vec<_>>();
+        assert_eq!(dewey.ancestors("247").map(|class| class.code.as_ref()).collect::<Vec<_>>(), ["24", "2"], "Expected parent to root");
+        assert_eq!(codes(dewey.breadcrumb("247")), ["2", "24", "247"], "Expected root to self");
+        assert_eq!(codes(dewey.breadcrumb("2")), ["2"]);
+        assert!(dewey.breadcrumb("008").is_empty() && dewey.ancestors("008").next().is_none(), "Expected nothing for unused codes");
+    }
+
+    #[test]
+    fn test_relationships() {
+        let dewey = Dewey::standard();
+        assert!(dewey.is_ancestor_of("00", "005") && dewey.is_ancestor_of("0XX", "005"), "Expected 005 to fall under 00");
+        assert!(dewey.is_descendant_of("005", "0"));
+        assert!(!dewey.is_ancestor_of("005", "005") && !dewey.is_ancestor_of("01", "005") && !dewey.is_ancestor_of("00", "abc"));
+
+        for (a, b, common) in [("005", "004", Some("00")), ("005", "01", Some("0")), ("00", "005", Some("00")), ("005", "516", None)] {
+            assert_eq!(dewey.common_ancestor(a, b).map(|class| class.code).as_deref(), common, "Unexpected common ancestor of {a} & {b}");
+        }
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_class_shortcuts() {
+        let dewey = Dewey::standard();
+        let class = dewey.get_class("5").unwrap();
+        assert_eq!(class.matches_iter().take(3).map(|class| class.code.as_ref()).collect::<Vec<_>>(), ["5", "50", "500"]);
+        assert_eq!(class.all_children_iter().count(), class.all_children().len());
+        assert_eq!(class.children_iter().cloned().collect::<Vec<_>>(), class.children());
+        assert_eq!((class.matches(), class.siblings().len()), (dewey.get_matches("5"), dewey.categories().len() - 1));
+
+        let codes = |classes: Vec<Class>| classes.into_iter().map(|class| class.code).collect::<Vec<_>>();
+        let class = dewey.get_class("516").unwrap();
+        assert_eq!(codes(class.ancestors().collect()), ["51", "5"]);
+        assert_eq!(codes(class.breadcrumb()), ["5", "51", "516"]);
+        assert_eq!(class.parent().unwrap().code, "51");
+
+        let (section, division) = (dewey.get_class("005").unwrap(), dewey.get_class("00").unwrap());
+        assert!(division.is_ancestor_of(&section) && section.is_descendant_of(&division) && !section.is_ancestor_of(&division));
+        assert_eq!(section.common_ancestor(&dewey.get_class("004").unwrap()).unwrap().code, "00");
+    }
+
+    #[test]
+    fn test_distance() {
+        let dewey = Dewey::standard();
+        for (a, b, distance) in [("005", "005", 0), ("005", "00", 1), ("005", "006", 2), ("005", "01", 3), ("005", "516", 6), ("0", "5", 2)] {
+            assert_eq!(dewey.distance(a, b), Some(distance), "Unexpected distance between {a} & {b}");
+            assert_eq!(dewey.distance(b, a), Some(distance), "Expected a symmetric distance");
+        }
+        assert!(dewey.distance("005", "008").is_none() && dewey.similarity("abc", "005").is_none(), "Expected nothing for unused codes");
+
+        assert_eq!(dewey.similarity("005", "005"), Some(1.0));
+        assert_eq!(dewey.similarity("005", "516"), Some(0.0), "Expected unrelated main classes");
+        assert!(dewey.similarity("005", "006").unwrap() > dewey.similarity("0", "1").unwrap().max(dewey.similarity("005", "01").unwrap()));
+    }
+
+    #[test]
+    fn test_ordering() {
+        let dewey = Dewey::standard();
+        let mut classes = dewey.all();
+        classes.reverse();
+        classes.sort();
+        assert_eq!(classes, dewey.all(), "Expected shelf order");
+
+        let decimal = Class::local("516.3", "Analytic geometries", Some("516".to_string()));
+        let (section, next) = (dewey.get_class("516").unwrap(), dewey.get_class("517").unwrap());
+        assert!(section < decimal && decimal < next, "Expected decimal codes between their neighbours");
+
+        let set = std::collections::HashSet::from([section.clone(), dewey.get_class("516").unwrap(), next]);
+        assert_eq!(set.len(), 2, "Expected classes to hash by code");
+        assert_eq!(section, Class::local("516", "Renamed", None), "Expected equality by code");
+    }
+
+    #[test]
+    fn test_shelf_order() {
+        let dewey = Dewey::standard();
+        for code in std::iter::once("").chain(dewey.all_ref().map(|class| class.code.as_ref())) {
+            assert!(dewey.children_of(code).is_sorted(), "Expected the children of {code:?} in shelf order");
+        }
+        assert!(dewey.categories().is_sorted() && dewey.get_matches("5").is_sorted() && dewey.get_all_children("").is_sorted());
+        assert!(dewey.matches_ref("51").is_sorted() && dewey.all_ref().is_sorted());
+
+        let mut entries = dewey.get_matches("5").into_iter().map(|class| dataset::Entry::new(&class.code, &class.name, class.parent_code())).collect::<Vec<_>>();
+        entries.reverse();
+        let loaded = Dewey::builder().source(DeweySource::Entries(entries)).build().unwrap();
+        assert_eq!(loaded.get_direct_children("51"), dewey.get_direct_children("51"), "Expected loaded children in shelf order");
+        assert!(loaded.get_matches("").is_sorted());
+    }
+
+    #[test]
+    fn test_siblings() {
+        let dewey = Dewey::standard();
+        let siblings = dewey.get_siblings("516").into_iter().map(|class| class.code).collect::<Vec<_>>();
+        assert!(siblings.contains(&"515".into()) && !siblings.contains(&"516".into()), "Expected the other sections of 51");
+        assert!(siblings.is_sorted(), "Expected shelf order");
+
+        let main = dewey.get_siblings("5");
+        assert_eq!(main.len(), dewey.categories().len() - 1, "Expected the other main classes");
+        assert!(dewey.get_siblings("008").is_empty(), "This code is unused!");
+    }
+
+    #[test]
+    fn test_raw_tree() {
+        let tree = Dewey::standard().raw_tree();
+        let categories = Dewey::standard().categories();
+        assert_eq!(tree.len(), categories.len(), "Expected one node per main class");
+        assert!(tree.iter().zip(&categories).all(|(node, class)| node.short == format!("{}XX", class.code)), "Expected untrimmed codes");
+        if let Some(node) = tree.iter().find(|node| node.short == "0XX") {
+            assert!(node.children[0].children.iter().any(|node| node.short == "007–009"), "Expected untrimmed ranges");
+        }
+    }
+
+    #[cfg(all(feature = "count", feature = "query"))]
+    #[test]
+    fn test_metadata() {
+        let class = Dewey::standard().get_class("001").unwrap();
+        assert!(class.work_count > 0, "Expected a work count");
+        assert_eq!(class.query, "001*");
+    }
+
+    #[cfg(feature = "notes")]
+    #[test]
+    fn test_notes() {
+        let dewey = Dewey::standard();
+        assert!(dewey.get_class("641").unwrap().notes.is_some_and(|notes| notes.starts_with("Class here cooking")));
+        assert!(dewey.get_class("64").unwrap().notes.is_none(), "Expected no notes for classes without any");
+
+        let loaded = Dewey::builder().include(['6']).build().unwrap();
+        assert_eq!(loaded.get_class("641").unwrap().notes, dewey.get_class("641").unwrap().notes, "Expected notes to be kept at runtime");
+    }
+
+    #[cfg(feature = "query")]
+    #[test]
+    fn test_openlibrary_query() {
+        let class = Dewey::standard().get_class("001").unwrap();
+        assert_eq!(class.openlibrary_query(), "ddc:001*");
+        assert_eq!(class.openlibrary_url(), "https://openlibrary.org/search?q=ddc%3A001*");
+
+        let range = Class { query: "[007 TO 009]".into(), ..class };
+        assert_eq!(range.openlibrary_url(), "https://openlibrary.org/search?q=ddc%3A%5B007%20TO%20009%5D", "Expected the query to be encoded");
+    }
+
+    #[cfg(feature = "rkyv")]
+    #[test]
+    fn test_rkyv() {
+        let class = Dewey::standard().get_class("516").unwrap();
+        let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&class).unwrap();
+        let archived = rkyv::access::<ArchivedClass, rkyv::rancor::Error>(&bytes).unwrap();
+        assert_eq!(archived.code, "516", "Expected to read the code without deserializing");
+        assert_eq!(rkyv::deserialize::<Class, rkyv::rancor::Error>(archived).unwrap(), class);
+    }
+
+    #[cfg(feature = "bincode")]
+    #[test]
+    fn test_bincode() {
+        let class = Dewey::standard().get_class("516").unwrap();
+        let bytes = bincode::encode_to_vec(&class, bincode::config::standard()).unwrap();
+        let (decoded, read): (Class, usize) = bincode::decode_from_slice(&bytes, bincode::config::standard()).unwrap();
+        assert_eq!((decoded, read), (class, bytes.len()));
+    }
+
+    #[cfg(feature = "borsh")]
+    #[test]
+    fn test_borsh() {
+        let class = Dewey::standard().get_class("516").unwrap();
+        let bytes = borsh::to_vec(&class).unwrap();
+        assert_eq!(&bytes[..7], [3, 0, 0, 0, b'5', b'1', b'6'], "Expected the code to be encoded first");
+        assert_eq!(borsh::from_slice::<Class>(&bytes).unwrap(), class);
+    }
+
+    #[cfg(feature = "utoipa")]
+    #[test]
+    fn test_utoipa() {
+        use utoipa::ToSchema;
+
+        let schema = serde_json::to_value(<Class as utoipa::PartialSchema>::schema()).unwrap();
+        assert_eq!(Class::name(), "Class");
+        assert_eq!(schema["properties"]["code"]["type"], "string");
+        assert!(schema["required"].as_array().unwrap().contains(&"name".into()));
+        assert!(schema["properties"]["parent_code"].is_object(), "Expected the private parent code, as it's serialized");
+    }
+
+    #[test]
+    fn test_exact_index() {
+        let dewey = Dewey::standard();
+        assert!(dewey.all_ref().all(|class| dewey.class_ref(&class.code).is_some_and(|found| std::ptr::eq(found, class))));
+        assert_eq!(dewey.class_ref("51X").map(|class| class.code.as_ref()), Some("51"));
+        assert!(dewey.class_ref("008").is_none() && dewey.class_ref("").is_none());
+    }
+
+    #[test]
+    fn test_child_counts() {
+        for code in ["0", "09", "5", "51", "247"] {
+            let class = Dewey::standard().get_class(code).unwrap();
+            assert_eq!(class.child_count, Dewey::standard().get_direct_children(code).len(), "Unexpected child count");
+            assert_eq!(class.descendant_count, Dewey::standard().get_all_children(code).len(), "Unexpected descendant count");
         }
     }
 }
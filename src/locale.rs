@@ -0,0 +1,241 @@
+use crate::{ Class, Dewey };
+
+/// Language class names can be shown in
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum Locale {
+    /// English, the language of the dataset
+    #[default]
+    English,
+
+    /// German
+    German,
+
+    /// French
+    French,
+
+    /// Spanish
+    Spanish,
+}
+
+impl Locale {
+    /// Every locale, in order
+    pub const ALL: [Locale; 4] = [Locale::English, Locale::German, Locale::French, Locale::Spanish];
+
+    /// Gets the language tag of this locale
+    ///
+    /// # Returns
+    ///
+    /// - `&'static str` - The ISO 639-1 code of the language (ie `de`)
+    pub fn tag(&self) -> &'static str {
+        match self {
+            Locale::English => "en",
+            Locale::German => "de",
+            Locale::French => "fr",
+            Locale::Spanish => "es",
+        }
+    }
+
+    /// Gets a locale from a language tag, ignoring case & region (ie `de`, `fr-CA` or `es_MX`)
+    ///
+    /// # Arguments
+    ///
+    /// - `tag` (`impl AsRef<str>`) - BCP 47 or POSIX language tag
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Locale>` - The matching [Locale], or [None] if the language isn't supported
+    pub fn from_tag(tag: impl AsRef<str>) -> Option<Locale> {
+        let language = tag.as_ref().trim().split(['-', '_']).next()?;
+        Locale::ALL.into_iter().find(|locale| locale.tag().eq_ignore_ascii_case(language))
+    }
+
+    /// Gets the translated name of a class
+    ///
+    /// Only main classes & divisions (the first two DDC summaries) are translated.
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code of the class
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&'static str>` - The translated name, or [None] for [Locale::English] and classes without a translation
+    pub fn translate(&self, code: impl AsRef<str>) -> Option<&'static str> {
+        let code = code.as_ref().trim();
+        let (_, de, fr, es) = TRANSLATIONS.iter().find(|(translated, ..)| *translated == code)?;
+        match self {
+            Locale::English => None,
+            Locale::German => Some(de),
+            Locale::French => Some(fr),
+            Locale::Spanish => Some(es),
+        }
+    }
+}
+
+impl Class {
+    /// Gets the name of this class in a language, falling back to the English name if it isn't translated
+    ///
+    /// # Arguments
+    ///
+    /// - `locale` (`Locale`) - Language to get the name in
+    ///
+    /// # Returns
+    ///
+    /// - `&str` - The translated name, or [Class::name]
+    pub fn name_in(&self, locale: Locale) -> &str {
+        locale.translate(&self.code).unwrap_or(&self.name)
+    }
+}
+
+impl Dewey {
+    /// Gets the name of a class in a language, see [Class::name_in]
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code of the class
+    /// - `locale` (`Locale`) - Language to get the name in
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&'static str>` - The (possibly untranslated) name, or [None] if the class doesn't exist
+    pub fn name_in(&self, code: impl AsRef<str>, locale: Locale) -> Option<&'static str> {
+        self.class_ref(code).map(|class| class.name_in(locale))
+    }
+}
+
+/// German, French & Spanish names of the main classes & divisions
+const TRANSLATIONS: &[(&str, &str, &str, &str)] = &[
+    ("0", "Informatik, Informationswissenschaft, allgemeine Werke", "Informatique, information et ouvrages généraux", "Ciencias de la computación, información y obras generales"),
+    ("00", "Informatik, Wissen, Systeme", "Informatique, savoir et systèmes", "Ciencias de la computación, conocimiento y sistemas"),
+    ("01", "Bibliografien", "Bibliographies", "Bibliografías"),
+    ("02", "Bibliotheks- und Informationswissenschaften", "Bibliothéconomie et sciences de l'information", "Bibliotecología y ciencias de la información"),
+    ("03", "Enzyklopädien, Faktenbücher", "Encyclopédies et ouvrages de référence", "Enciclopedias y libros de datos"),
+    ("05", "Zeitschriften, fortlaufende Sammelwerke", "Revues, journaux et publications en série", "Revistas, diarios y publicaciones seriadas"),
+    ("06", "Verbände, Organisationen, Museen", "Associations, organisations et musées", "Asociaciones, organizaciones y museos"),
+    ("07", "Nachrichtenmedien, Journalismus, Verlagswesen", "Médias d'information, journalisme et édition", "Medios informativos, periodismo y edición"),
+    ("08", "Zitate", "Citations", "Citas"),
+    ("09", "Handschriften, seltene Bücher", "Manuscrits et livres rares", "Manuscritos y libros raros"),
+    ("1", "Philosophie und Psychologie", "Philosophie et psychologie", "Filosofía y psicología"),
+    ("10", "Philosophie", "Philosophie", "Filosofía"),
+    ("11", "Metaphysik", "Métaphysique", "Metafísica"),
+    ("12", "Epistemologie", "Épistémologie", "Epistemología"),
+    ("13", "Parapsychologie, Okkultismus", "Parapsychologie et occultisme", "Parapsicología y ocultismo"),
+    ("14", "Philosophische Schulen", "Écoles de pensée philosophiques", "Escuelas filosóficas"),
+    ("15", "Psychologie", "Psychologie", "Psicología"),
+    ("16", "Philosophische Logik", "Logique philosophique", "Lógica filosófica"),
+    ("17", "Ethik", "Éthique", "Ética"),
+    ("18", "Antike, mittelalterliche und östliche Philosophie", "Philosophie antique, médiévale et orientale", "Filosofía antigua, medieval y oriental"),
+    ("19", "Neuzeitliche westliche Philosophie", "Philosophie occidentale moderne", "Filosofía occidental moderna"),
+    ("2", "Religion", "Religion", "Religión"),
+    ("20", "Religion", "Religion", "Religión"),
+    ("21", "Religionsphilosophie, Religionstheorie", "Philosophie et théorie de la religion", "Filosofía y teoría de la religión"),
+    ("22", "Bibel", "Bible", "Biblia"),
+    ("23", "Christentum", "Christianisme", "Cristianismo"),
+    ("24", "Christliche Praxis und Andacht", "Pratique et observance chrétiennes", "Práctica y observancia cristianas"),
+    ("25", "Christliche Orden, Ortsgemeinde", "Ordres chrétiens et église locale", "Órdenes cristianas e iglesia local"),
+    ("26", "Sozialtheologie, Kirchenrecht", "Théologie sociale et ecclésiastique", "Teología social y eclesiástica"),
+    ("27", "Geschichte des Christentums", "Histoire du christianisme", "Historia del cristianismo"),
+    ("28", "Christliche Konfessionen", "Confessions chrétiennes", "Denominaciones cristianas"),
+    ("29", "Andere Religionen", "Autres religions", "Otras religiones"),
+    ("3", "Sozialwissenschaften", "Sciences sociales", "Ciencias sociales"),
+    ("30", "Sozialwissenschaften, Soziologie, Anthropologie", "Sciences sociales, sociologie et anthropologie", "Ciencias sociales, sociología y antropología"),
+    ("31", "Statistik", "Statistique", "Estadística"),
+    ("32", "Politikwissenschaft", "Science politique", "Ciencia política"),
+    ("33", "Wirtschaft", "Économie", "Economía"),
+    ("34", "Recht", "Droit", "Derecho"),
+    ("35", "Öffentliche Verwaltung, Militärwissenschaft", "Administration publique et science militaire", "Administración pública y ciencia militar"),
+    ("36", "Soziale Probleme, Sozialdienste", "Problèmes et services sociaux", "Problemas y servicios sociales"),
+    ("37", "Bildung und Erziehung", "Éducation", "Educación"),
+    ("38", "Handel, Kommunikation, Verkehr", "Commerce, communications et transports", "Comercio, comunicaciones y transporte"),
+    ("39", "Bräuche, Etikette, Folklore", "Coutumes, savoir-vivre et folklore", "Costumbres, etiqueta y folclore"),
+    ("4", "Sprache", "Langues", "Lenguas"),
+    ("40", "Sprache", "Langues", "Lenguas"),
+    ("41", "Linguistik", "Linguistique", "Lingüística"),
+    ("42", "Englisch, Altenglisch", "Anglais et vieil anglais", "Inglés e inglés antiguo"),
+    ("43", "Deutsch und verwandte Sprachen", "Allemand et langues apparentées", "Alemán y lenguas relacionadas"),
+    ("44", "Französisch und verwandte Sprachen", "Français et langues apparentées", "Francés y lenguas relacionadas"),
+    ("45", "Italienisch, Rumänisch und verwandte Sprachen", "Italien, roumain et langues apparentées", "Italiano, rumano y lenguas relacionadas"),
+    ("46", "Spanisch, Portugiesisch, Galicisch", "Espagnol, portugais et galicien", "Español, portugués y gallego"),
+    ("47", "Latein und italische Sprachen", "Latin et langues italiques", "Latín y lenguas itálicas"),
+    ("48", "Klassisches und modernes Griechisch", "Grec classique et moderne", "Griego clásico y moderno"),
+    ("49", "Andere Sprachen", "Autres langues", "Otras lenguas"),
+    ("5", "Naturwissenschaften", "Sciences", "Ciencias"),
+    ("50", "Naturwissenschaften", "Sciences", "Ciencias"),
+    ("51", "Mathematik", "Mathématiques", "Matemáticas"),
+    ("52", "Astronomie", "Astronomie", "Astronomía"),
+    ("53", "Physik", "Physique", "Física"),
+    ("54", "Chemie", "Chimie", "Química"),
+    ("55", "Geowissenschaften, Geologie", "Sciences de la Terre et géologie", "Ciencias de la Tierra y geología"),
+    ("56", "Fossilien, prähistorisches Leben", "Fossiles et vie préhistorique", "Fósiles y vida prehistórica"),
+    ("57", "Biologie", "Biologie", "Biología"),
+    ("58", "Pflanzen (Botanik)", "Plantes (botanique)", "Plantas (botánica)"),
+    ("59", "Tiere (Zoologie)", "Animaux (zoologie)", "Animales (zoología)"),
+    ("6", "Technik", "Technologie", "Tecnología"),
+    ("60", "Technik", "Technologie", "Tecnología"),
+    ("61", "Medizin, Gesundheit", "Médecine et santé", "Medicina y salud"),
+    ("62", "Ingenieurwissenschaften", "Ingénierie", "Ingeniería"),
+    ("63", "Landwirtschaft", "Agriculture", "Agricultura"),
+    ("64", "Hauswirtschaft, Familienleben", "Économie domestique et vie familiale", "Economía doméstica y vida familiar"),
+    ("65", "Management, Öffentlichkeitsarbeit", "Gestion et relations publiques", "Gestión y relaciones públicas"),
+    ("66", "Chemische Verfahrenstechnik", "Génie chimique", "Ingeniería química"),
+    ("67", "Industrielle Fertigung", "Fabrication industrielle", "Manufactura"),
+    ("68", "Fertigung für spezifische Zwecke", "Fabrication à usages spécifiques", "Manufactura para usos específicos"),
+    ("69", "Hausbau, Bauhandwerk", "Construction de bâtiments", "Construcción de edificios"),
+    ("7", "Künste und Unterhaltung", "Arts et loisirs", "Artes y recreación"),
+    ("70", "Künste", "Arts", "Artes"),
+    ("71", "Landschaftsgestaltung, Raumplanung", "Urbanisme et architecture du paysage", "Urbanismo y arquitectura del paisaje"),
+    ("72", "Architektur", "Architecture", "Arquitectura"),
+    ("73", "Plastik, Keramik, Metallkunst", "Sculpture, céramique et travail des métaux", "Escultura, cerámica y metalistería"),
+    ("74", "Grafik, angewandte Kunst", "Arts graphiques et arts décoratifs", "Artes gráficas y artes decorativas"),
+    ("75", "Malerei", "Peinture", "Pintura"),
+    ("76", "Druckgrafik, Drucke", "Gravure et estampes", "Grabado y estampas"),
+    ("77", "Fotografie, Computerkunst, Film, Video", "Photographie, art numérique, cinéma et vidéo", "Fotografía, arte digital, cine y video"),
+    ("78", "Musik", "Musique", "Música"),
+    ("79", "Sport, Spiele, Unterhaltung", "Sports, jeux et divertissements", "Deportes, juegos y entretenimiento"),
+    ("8", "Literatur", "Littérature", "Literatura"),
+    ("80", "Literatur, Rhetorik, Literaturwissenschaft", "Littérature, rhétorique et critique", "Literatura, retórica y crítica"),
+    ("81", "Amerikanische Literatur in Englisch", "Littérature américaine de langue anglaise", "Literatura estadounidense en inglés"),
+    ("82", "Englische, altenglische Literatur", "Littératures anglaise et vieil-anglaise", "Literaturas inglesa e inglesa antigua"),
+    ("83", "Deutsche und verwandte Literaturen", "Littératures allemande et apparentées", "Literaturas alemana y relacionadas"),
+    ("84", "Französische und verwandte Literaturen", "Littératures française et apparentées", "Literaturas francesa y relacionadas"),
+    ("85", "Italienische, rumänische und verwandte Literaturen", "Littératures italienne, roumaine et apparentées", "Literaturas italiana, rumana y relacionadas"),
+    ("86", "Spanische, portugiesische, galicische Literaturen", "Littératures espagnole, portugaise et galicienne", "Literaturas española, portuguesa y gallega"),
+    ("87", "Lateinische und italische Literaturen", "Littératures latine et italiques", "Literaturas latina e itálicas"),
+    ("88", "Klassische und neugriechische Literaturen", "Littératures grecques classique et moderne", "Literaturas griegas clásica y moderna"),
+    ("89", "Andere Literaturen", "Autres littératures", "Otras literaturas"),
+    ("9", "Geschichte und Geografie", "Histoire et géographie", "Historia y geografía"),
+    ("90", "Geschichte", "Histoire", "Historia"),
+    ("91", "Geografie, Reisen", "Géographie et voyages", "Geografía y viajes"),
+    ("92", "Biografie, Genealogie", "Biographie et généalogie", "Biografía y genealogía"),
+    ("93", "Geschichte der Alten Welt (bis ca. 499)", "Histoire du monde antique (jusqu'à 499 environ)", "Historia del mundo antiguo (hasta ca. 499)"),
+    ("94", "Geschichte Europas", "Histoire de l'Europe", "Historia de Europa"),
+    ("95", "Geschichte Asiens", "Histoire de l'Asie", "Historia de Asia"),
+    ("96", "Geschichte Afrikas", "Histoire de l'Afrique", "Historia de África"),
+    ("97", "Geschichte Nordamerikas", "Histoire de l'Amérique du Nord", "Historia de América del Norte"),
+    ("98", "Geschichte Südamerikas", "Histoire de l'Amérique du Sud", "Historia de América del Sur"),
+    ("99", "Geschichte anderer Gebiete", "Histoire des autres régions", "Historia de otras regiones"),
+];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_locales() {
+        let dewey = Dewey::standard();
+        let math = dewey.get_class("51").unwrap();
+        assert_eq!(math.name_in(Locale::German), "Mathematik");
+        assert_eq!(math.name_in(Locale::French), "Mathématiques");
+        assert_eq!(math.name_in(Locale::English), math.name);
+        assert_eq!(dewey.name_in("516", Locale::Spanish), dewey.get_class("516").map(|class| class.name).as_deref(), "Expected untranslated classes to fall back to English");
+        assert!(dewey.name_in("008", Locale::German).is_none(), "This code is unused!");
+
+        for (tag, locale) in [("de", Some(Locale::German)), ("fr-CA", Some(Locale::French)), ("ES_mx", Some(Locale::Spanish)), ("it", None)] {
+            assert_eq!(Locale::from_tag(tag), locale, "Unexpected locale for {tag}");
+        }
+        assert!(TRANSLATIONS.iter().all(|(code, ..)| dewey.get_class(code).is_some()), "Expected every code to exist");
+    }
+}
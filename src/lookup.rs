@@ -0,0 +1,148 @@
+//! Lookups with configurable strictness, for pipelines needing different guarantees
+
+use crate::{ dataset, lenient_code, Class, DdcCode, Dewey };
+
+/// How [LookupOptions] treat the code being looked up
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum LookupMode {
+    /// Only accept codes in the canonical stored form (ie `51` or `813.54`), rejecting whitespace, `X` padding & segmentation marks
+    Strict,
+
+    /// Clean up loosely written codes before looking them up (ie ` 51 `, `51X` or `005/13`), like [Dewey::get_class]
+    #[default]
+    Lenient,
+}
+
+/// Builder for single-class lookups with configurable strictness
+///
+/// ```rust
+/// use dewey_decimal::{ Dewey, LookupMode };
+///
+/// let dewey = Dewey::standard();
+/// assert!(dewey.lookup_options().mode(LookupMode::Strict).get("51X").is_none());
+/// assert_eq!(dewey.lookup_options().get("51X").unwrap().code, "51");
+/// assert_eq!(dewey.lookup_options().fallback_to_prefix(true).get("813.54").unwrap().code, "813");
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct LookupOptions {
+    dewey: Dewey,
+    mode: LookupMode,
+    fallback: bool,
+}
+
+impl LookupOptions {
+    /// Sets how codes are treated
+    ///
+    /// # Arguments
+    ///
+    /// - `mode` (`LookupMode`) - Strict or lenient treatment of codes (lenient by default)
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The updated [LookupOptions]
+    pub fn mode(mut self, mode: LookupMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Sets whether codes without a class fall back to their most specific ancestor with one
+    ///
+    /// # Arguments
+    ///
+    /// - `fallback` (`bool`) - Whether to fall back to the longest prefix with a class (disabled by default)
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The updated [LookupOptions]
+    pub fn fallback_to_prefix(mut self, fallback: bool) -> Self {
+        self.fallback = fallback;
+        self
+    }
+
+    /// Looks up a class without copying it
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code to look up
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&'static Class>` - The matching [Class] (or its closest ancestor, when falling back), or [None] if the code is rejected or has no class
+    pub fn get_ref(&self, code: impl AsRef<str>) -> Option<&'static Class> {
+        let code = code.as_ref();
+        let code = match self.mode {
+            LookupMode::Strict if DdcCode::new(code).ok()?.as_str() != code => return None,
+            LookupMode::Strict => code.to_string(),
+            LookupMode::Lenient => lenient_code(code),
+        };
+
+        let class = self.dewey.class_ref(&code);
+        if class.is_some() || !self.fallback {
+            return class;
+        }
+        dataset::prefixes(&code).rev().find_map(|prefix| self.dewey.class_ref(prefix))
+    }
+
+    /// Looks up a class
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code to look up
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Class>` - The matching [Class] (or its closest ancestor, when falling back), or [None] if the code is rejected or has no class
+    pub fn get(&self, code: impl AsRef<str>) -> Option<Class> {
+        self.get_ref(code).cloned()
+    }
+}
+
+impl Dewey {
+    /// Starts building a lookup with configurable strictness, see [LookupOptions]
+    ///
+    /// # Returns
+    ///
+    /// - `LookupOptions` - A new [LookupOptions], which is lenient and doesn't fall back by default
+    pub fn lookup_options(&self) -> LookupOptions {
+        LookupOptions { dewey: *self, mode: LookupMode::default(), fallback: false }
+    }
+
+    /// Gets a class by exact code, only accepting codes in canonical form (see [LookupMode::Strict])
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code to search for (ie `51`, but not `51X` or ` 51 `)
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Class>` - The matching [Class], or [None] if the code isn't canonical or has no class
+    pub fn get_class_strict(&self, code: impl AsRef<str>) -> Option<Class> {
+        self.lookup_options().mode(LookupMode::Strict).get(code)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_lookup_modes() {
+        let dewey = Dewey::standard();
+        assert_eq!(dewey.get_class_strict("51").unwrap().code, "51");
+        for loose in ["51X", " 51", "5 1", "005/13", "51a", ""] {
+            assert!(dewey.get_class_strict(loose).is_none(), "Expected {loose:?} to be rejected");
+        }
+
+        let lenient = dewey.lookup_options().mode(LookupMode::Lenient);
+        assert_eq!(lenient.get_ref(" 5 1 ").map(|class| class.code.as_ref()), Some("51"));
+        assert!(lenient.get("813.54").is_none(), "Expected no fallback by default");
+
+        let strict_fallback = dewey.lookup_options().mode(LookupMode::Strict).fallback_to_prefix(true);
+        assert_eq!(strict_fallback.get("813.54").unwrap().code, "813");
+        assert!(strict_fallback.get("813.54 ").is_none(), "Expected strictness to apply before falling back");
+        assert_eq!(lenient.fallback_to_prefix(true).get("813/54").unwrap().code, "813");
+    }
+}
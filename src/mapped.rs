@@ -0,0 +1,340 @@
+use std::{ borrow::Cow, fmt, fs::File, io::{ self, BufWriter, Write }, path::Path, sync::OnceLock };
+
+use memmap2::Mmap;
+
+use crate::{ blob::{ write_str, Reader }, lenient_code, Class, Dataset, Dewey };
+
+const MAGIC: &[u8; 8] = b"DDCMAP\0\0";
+
+/// Current version of the mapped data file format
+pub const MAPPED_FORMAT_VERSION: u32 = 1;
+
+const HEADER_LEN: usize = MAGIC.len() + 4 + 4;
+
+/// Dataset backed by a memory-mapped data file, rather than embedded in the binary
+///
+/// Data files are produced by [Dewey::write_data_file] (or [MappedDataset::write]). Opening a file only checks its header & record offsets, and classes are decoded straight from the mapping on lookup, so opening even very large files is cheap. Corrupted records are skipped by lookups rather than failing the whole file.
+///
+/// The lookups of [MappedDataset] only cover exact codes, prefixes & direct children. For the rest of the [Dewey] API, turn the file into a handle with [MappedDataset::into_dewey].
+///
+/// The file layout is a header (magic, format version, class count), followed by a table of record offsets sorted by code, followed by the records themselves.
+pub struct MappedDataset {
+    map: Mmap,
+    len: usize,
+    /// Classes & indexes of the file, built the first time it's used through a [Dewey] handle
+    dataset: OnceLock<Dataset>,
+}
+
+impl fmt::Debug for MappedDataset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MappedDataset").field("len", &self.len).finish_non_exhaustive()
+    }
+}
+
+fn invalid(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
+
+struct Record<'a> {
+    code: &'a str,
+    name: &'a str,
+    parent_code: &'a str,
+    child_count: u32,
+    descendant_count: u32,
+}
+
+impl MappedDataset {
+    /// Writes classes to a data file
+    ///
+    /// # Arguments
+    ///
+    /// - `classes` (`impl IntoIterator<Item = Class>`) - Classes to write (in any order)
+    /// - `writer` (`impl Write`) - Destination of the data file
+    ///
+    /// # Returns
+    ///
+    /// - `io::Result<()>` - An error if writing fails
+    pub fn write(classes: impl IntoIterator<Item = Class>, mut writer: impl Write) -> io::Result<()> {
+        let mut classes = classes.into_iter().collect::<Vec<_>>();
+        classes.sort_by(|a, b| a.code.cmp(&b.code));
+        classes.dedup_by(|a, b| a.code == b.code);
+
+        let mut records = Vec::new();
+        let mut offsets = Vec::with_capacity(classes.len());
+        let records_start = HEADER_LEN + classes.len() * 4;
+        for class in &classes {
+            let offset = u32::try_from(records_start + records.len()).map_err(|_| invalid("data file too large"))?;
+            offsets.push(offset);
+            write_str(&mut records, &class.code);
+            write_str(&mut records, &class.name);
+            write_str(&mut records, class.parent_code().unwrap_or(""));
+            records.extend_from_slice(&(class.child_count as u32).to_le_bytes());
+            records.extend_from_slice(&(class.descendant_count as u32).to_le_bytes());
+        }
+
+        writer.write_all(MAGIC)?;
+        writer.write_all(&MAPPED_FORMAT_VERSION.to_le_bytes())?;
+        writer.write_all(&(classes.len() as u32).to_le_bytes())?;
+        for offset in offsets {
+            writer.write_all(&offset.to_le_bytes())?;
+        }
+        writer.write_all(&records)
+    }
+
+    /// Opens and memory-maps a data file
+    ///
+    /// # Arguments
+    ///
+    /// - `path` (`impl AsRef<Path>`) - Path of the data file
+    ///
+    /// # Returns
+    ///
+    /// - `io::Result<Self>` - The mapped dataset, or an error if the file can't be mapped, isn't a data file, or has offsets outside of it
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        let file = File::open(path)?;
+        // SAFETY: the mapping is read-only. As with any memory-mapped file, it must not be truncated or modified by other processes while mapped.
+        let map = unsafe { Mmap::map(&file)? };
+
+        if map.len() < HEADER_LEN || &map[..MAGIC.len()] != MAGIC {
+            return Err(invalid("not a dewey-decimal data file"));
+        }
+        let version = u32::from_le_bytes(map[8..12].try_into().unwrap());
+        if version != MAPPED_FORMAT_VERSION {
+            return Err(invalid("unsupported data file version"));
+        }
+        let len = u32::from_le_bytes(map[12..16].try_into().unwrap()) as usize;
+        if map.len() < HEADER_LEN + len * 4 {
+            return Err(invalid("truncated data file"));
+        }
+
+        let dataset = Self { map, len, dataset: OnceLock::new() };
+        let records = HEADER_LEN + len * 4..dataset.map.len();
+        if !(0..len).all(|index| dataset.read_u32(HEADER_LEN + index * 4).is_some_and(|offset| records.contains(&(offset as usize)))) {
+            return Err(invalid("corrupt data file offsets"));
+        }
+        Ok(dataset)
+    }
+
+    /// Turns the data file into a [Dewey] handle, supporting the whole lookup & search API
+    ///
+    /// The file stays mapped for the rest of the program, like datasets loaded with a [DeweyBuilder](crate::DeweyBuilder). Its classes are decoded & indexed the first time the handle is used, borrowing their strings from the mapping, and corrupted records are left out.
+    ///
+    /// ```rust,no_run
+    /// use dewey_decimal::MappedDataset;
+    ///
+    /// let dewey = MappedDataset::open("classes.ddc").unwrap().into_dewey();
+    /// let results = dewey.search("geometry");
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// - `Dewey` - A handle to the classes of the data file
+    pub fn into_dewey(self) -> Dewey {
+        Dewey::mapped(Box::leak(Box::new(self)))
+    }
+
+    /// Gets the classes & indexes of a leaked data file, decoding them on first use
+    pub(crate) fn dataset(&'static self) -> &'static Dataset {
+        self.dataset.get_or_init(|| {
+            let mut classes = (0..self.len)
+                .filter_map(|index| self.record(index))
+                .map(|record| {
+                    let mut class = Class::local("", "", None);
+                    class.code = Cow::Borrowed(record.code);
+                    class.name = Cow::Borrowed(record.name);
+                    class.parent_code = Some(record.parent_code).filter(|code| !code.is_empty()).map(Cow::Borrowed);
+                    class
+                })
+                .collect::<Vec<_>>();
+            classes.sort();
+            Dataset::from_classes(classes, &[])
+        })
+    }
+
+    /// Gets the number of classes in the data file
+    ///
+    /// # Returns
+    ///
+    /// - `usize` - Number of classes
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks whether the data file contains no classes
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether the data file is empty
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn read_u32(&self, offset: usize) -> Option<u32> {
+        Reader(self.map.get(offset..)?).u32()
+    }
+
+    /// Decodes a record, or [None] if it's corrupted
+    fn record(&self, index: usize) -> Option<Record<'_>> {
+        let offset = self.read_u32(HEADER_LEN + index * 4)? as usize;
+        let mut reader = Reader(self.map.get(offset..)?);
+        Some(Record {
+            code: reader.str()?,
+            name: reader.str()?,
+            parent_code: reader.str()?,
+            child_count: reader.u32()?,
+            descendant_count: reader.u32()?,
+        })
+    }
+
+    fn code_at(&self, index: usize) -> &str {
+        self.record(index).map_or("", |record| record.code)
+    }
+
+    fn class_at(&self, index: usize) -> Option<Class> {
+        let record = self.record(index)?;
+        let mut class = Class::local(
+            record.code,
+            record.name,
+            (!record.parent_code.is_empty()).then(|| record.parent_code.to_string())
+        );
+        class.child_count = record.child_count as usize;
+        class.descendant_count = record.descendant_count as usize;
+        class.has_children = record.child_count > 0;
+        Some(class)
+    }
+
+    fn lower_bound(&self, code: &str) -> usize {
+        let (mut low, mut high) = (0, self.len);
+        while low < high {
+            let mid = (low + high) / 2;
+            if self.code_at(mid) < code { low = mid + 1 } else { high = mid }
+        }
+        low
+    }
+
+    /// Gets a class by exact code match, see [Dewey::get_class]
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code to search for
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Class>` - The matching [Class], or [None] if not found
+    pub fn get_class(&self, code: impl AsRef<str>) -> Option<Class> {
+        let code = lenient_code(code.as_ref());
+        let index = self.lower_bound(&code);
+        (index < self.len && self.code_at(index) == code).then(|| self.class_at(index)).flatten()
+    }
+
+    /// Returns all classes matching the provided prefix, see [Dewey::get_matches]
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code to search for
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<Class>` - [Vec] of [Class] instances matching the prefix, in shelf order
+    pub fn get_matches(&self, code: impl AsRef<str>) -> Vec<Class> {
+        let code = lenient_code(code.as_ref());
+        (self.lower_bound(&code)..self.len)
+            .take_while(|index| self.code_at(*index).starts_with(code.as_str()))
+            .filter_map(|index| self.class_at(index))
+            .collect()
+    }
+
+    /// Gets all the direct children of the class with the provided code, see [Dewey::get_direct_children]
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code to search for (an empty string gets the main classes)
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<Class>` - [Vec] of [Class] instances that are direct children of the specified class, in shelf order
+    pub fn get_direct_children(&self, code: impl AsRef<str>) -> Vec<Class> {
+        let code = lenient_code(code.as_ref());
+        self.get_matches(&code)
+            .into_iter()
+            .filter(|class| class.parent_code().unwrap_or("") == code && class.code != code)
+            .collect()
+    }
+
+    /// Gets the parent of the selected class, if any, see [Dewey::get_parent]
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code to search for
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Class>` - Parent of the selected [Class], if any
+    pub fn get_parent(&self, code: impl AsRef<str>) -> Option<Class> {
+        self.get_class(code)?
+            .parent_code()
+            .and_then(|parent| self.get_class(parent))
+    }
+}
+
+impl Dewey {
+    /// Exports this dataset to a data file that can be opened with [MappedDataset::open]
+    ///
+    /// # Arguments
+    ///
+    /// - `path` (`impl AsRef<Path>`) - Path of the file to write
+    ///
+    /// # Returns
+    ///
+    /// - `io::Result<()>` - An error if writing fails
+    pub fn write_data_file(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        MappedDataset::write(self.all(), &mut writer)?;
+        writer.flush()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_mapped_dataset() {
+        let path = std::env::temp_dir().join(format!("dewey-decimal-test-{}.ddc", std::process::id()));
+        let dewey = Dewey::standard();
+        dewey.write_data_file(&path).unwrap();
+
+        let mapped = MappedDataset::open(&path).unwrap();
+        assert_eq!(mapped.len(), dewey.all().len());
+        for code in ["0", "24", "247", "51"] {
+            let (expected, actual) = (dewey.get_class(code).unwrap(), mapped.get_class(code).unwrap());
+            assert_eq!((actual.code, actual.name, actual.child_count), (expected.code, expected.name, expected.child_count));
+            assert_eq!(mapped.get_matches(code).len(), dewey.get_matches(code).len());
+            assert_eq!(mapped.get_direct_children(code).len(), dewey.get_direct_children(code).len());
+        }
+        assert_eq!(mapped.get_direct_children("").len(), 10);
+        assert_eq!(mapped.get_parent("247").unwrap().code, "24");
+        assert!(mapped.get_class("008").is_none(), "This code is unused!");
+        assert_eq!(mapped.get_class(" 51X").unwrap().code, "51", "Expected codes to be cleaned up");
+        assert_eq!(mapped.get_direct_children("5 1").len(), dewey.get_direct_children("51").len());
+
+        let handle = mapped.into_dewey();
+        assert_eq!(handle.all(), dewey.all());
+        assert_eq!(handle.search("geometry")[0].code, dewey.search("geometry")[0].code);
+        assert_eq!(handle.get_class("516").unwrap().descendant_count, dewey.get_class("516").unwrap().descendant_count);
+
+        let mut bytes = std::fs::read(&path).unwrap();
+        let first = u32::from_le_bytes(bytes[HEADER_LEN..HEADER_LEN + 4].try_into().unwrap()) as usize;
+        bytes[first..first + 4].copy_from_slice(&u32::MAX.to_le_bytes());
+        std::fs::write(&path, &bytes).unwrap();
+        let corrupted = MappedDataset::open(&path).expect("Expected records to be checked lazily");
+        assert!(corrupted.get_class("0").is_none() && corrupted.get_class("1").is_some(), "Expected only the corrupted record to be skipped");
+
+        bytes[HEADER_LEN..HEADER_LEN + 4].copy_from_slice(&u32::MAX.to_le_bytes());
+        std::fs::write(&path, &bytes).unwrap();
+        assert!(MappedDataset::open(&path).is_err(), "Expected offsets to be checked");
+        std::fs::write(&path, b"garbage").unwrap();
+        assert!(MappedDataset::open(&path).is_err(), "Expected invalid data file");
+        std::fs::remove_file(&path).unwrap();
+    }
+}
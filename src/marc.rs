@@ -0,0 +1,139 @@
+use std::fmt;
+
+use crate::{ Class, Dewey };
+
+/// Delimiters introducing a subfield: `$` (MarcEdit & most displays), `‡` (OCLC displays), and the ISO 2709 unit separator
+const SUBFIELD_DELIMITERS: [char; 3] = ['$', '‡', '\x1f'];
+
+/// Errors produced when reading a MARC 082 field
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MarcError {
+    /// The field has no classification number (`$a`)
+    MissingNumber,
+
+    /// The classification number doesn't fall under any known class
+    Unclassified(String),
+}
+
+impl fmt::Display for MarcError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MarcError::MissingNumber => write!(f, "MARC 082 field has no classification number ($a)"),
+            MarcError::Unclassified(number) => write!(f, "classification number doesn't match any class: {number}"),
+        }
+    }
+}
+
+impl std::error::Error for MarcError {}
+
+/// Type of DDC edition a MARC 082 number was taken from, as given by the field's first indicator
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum DdcEdition {
+    /// Full edition (indicator `0`)
+    Full,
+
+    /// Abridged edition (indicator `1`)
+    Abridged,
+
+    /// Another edition, identified in `$2` (indicator `7`)
+    Other,
+}
+
+impl DdcEdition {
+    fn from_indicator(indicator: char) -> Option<Self> {
+        match indicator {
+            '0' => Some(DdcEdition::Full),
+            '1' => Some(DdcEdition::Abridged),
+            '7' => Some(DdcEdition::Other),
+            _ => None,
+        }
+    }
+}
+
+/// DDC number read from a MARC 082 field by [Dewey::from_marc_082]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MarcClassification {
+    /// The most specific [Class] the number falls under (see [Dewey::classify])
+    pub class: Class,
+
+    /// The classification number (`$a`), without segmentation marks (ie `813.54` for `813/.54`)
+    pub number: String,
+
+    /// Type of edition (first indicator), or [None] if blank or unknown
+    pub edition: Option<DdcEdition>,
+
+    /// Edition number (`$2`, ie `23`)
+    pub edition_number: Option<String>,
+
+    /// Item number (`$b`, ie a Cutter number)
+    pub item_number: Option<String>,
+}
+
+impl Dewey {
+    /// Reads the DDC number of a MARC 082 (Dewey Decimal Classification Number) field
+    ///
+    /// Accepts the field as shown by most cataloging tools, with or without the tag (ie `082 04 $a 813.54 $2 23`, `=082  04$a813/.54$223`, or `04 ‡a 813.54 ‡2 23`). Segmentation marks (`/` and `'`) are removed from the number, and only the first `$a` is read.
+    ///
+    /// # Arguments
+    ///
+    /// - `field` (`impl AsRef<str>`) - Value of the 082 field
+    ///
+    /// # Returns
+    ///
+    /// - `Result<MarcClassification, MarcError>` - The matched class & edition, or why the field couldn't be read
+    pub fn from_marc_082(&self, field: impl AsRef<str>) -> Result<MarcClassification, MarcError> {
+        let field = field.as_ref().trim().trim_start_matches('=');
+        let field = field.strip_prefix("082").unwrap_or(field);
+        let (indicators, subfields) = field.split_once(SUBFIELD_DELIMITERS).unwrap_or((field, ""));
+
+        let indicators = indicators.trim().chars().collect::<Vec<_>>();
+        let edition = match indicators.as_slice() {
+            [first, _] => DdcEdition::from_indicator(*first),
+            _ => None,
+        };
+
+        let subfields = subfields
+            .split(SUBFIELD_DELIMITERS)
+            .filter_map(|subfield| {
+                let mut chars = subfield.chars();
+                let code = chars.next()?;
+                let value = chars.as_str().trim();
+                (!value.is_empty()).then_some((code, value))
+            })
+            .collect::<Vec<_>>();
+        let subfield = |code: char| subfields.iter().find(|(found, _)| *found == code).map(|(_, value)| value.to_string());
+
+        let number = subfield('a').ok_or(MarcError::MissingNumber)?.replace(['/', '\''], "");
+        let class = self.classify(&number).ok_or_else(|| MarcError::Unclassified(number.clone()))?;
+        Ok(MarcClassification { class, number, edition, edition_number: subfield('2'), item_number: subfield('b') })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_from_marc_082() {
+        let dewey = Dewey::standard();
+        let parsed = dewey.from_marc_082("082 04 $a 813.54 $2 23").unwrap();
+        assert_eq!(parsed.class.code, "813");
+        assert_eq!(parsed.number, "813.54");
+        assert_eq!(parsed.edition, Some(DdcEdition::Full));
+        assert_eq!(parsed.edition_number.as_deref(), Some("23"));
+
+        let parsed = dewey.from_marc_082("=082  14$a516/.35$bS65$214").unwrap();
+        assert_eq!((parsed.class.code.as_ref(), parsed.number.as_str()), ("516", "516.35"), "Expected segmentation marks to be removed");
+        assert_eq!((parsed.edition, parsed.item_number.as_deref()), (Some(DdcEdition::Abridged), Some("S65")));
+
+        let parsed = dewey.from_marc_082("\\4 ‡a 621.39 ‡q OCoLC").unwrap();
+        assert_eq!((parsed.class.code.as_ref(), parsed.edition, parsed.edition_number), ("621", None, None));
+
+        assert_eq!(dewey.from_marc_082("082 04 $2 23").unwrap_err(), MarcError::MissingNumber);
+        assert_eq!(dewey.from_marc_082("082 04 $a [Fic]").unwrap_err(), MarcError::Unclassified("[Fic]".to_string()));
+    }
+}
@@ -0,0 +1,109 @@
+use std::{ fs::File, io::{ self, BufWriter, Write }, path::Path };
+
+use crate::{ Class, CodeStyle, DatasetEdition, Dewey };
+
+/// Namespace of MARCXML documents
+const MARCXML: &str = "http://www.loc.gov/MARC21/slim";
+
+/// Leader of classification records: new (`n`) classification data (`w`) in UCS/Unicode (`a`), with lengths left for the receiving system to compute
+const LEADER: &str = "00000nw  a2200000n  4500";
+
+/// Fixed-length data elements (008) of classification records: a valid, standard, fully established single number from the schedules, not synthesized
+const FIXED_DATA: &str = "000000aaaaaana";
+
+fn escape_xml(value: &str) -> String {
+    value.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+/// Writes a data field, skipping empty subfields
+fn write_datafield(writer: &mut impl Write, tag: &str, subfields: &[(char, &str)]) -> io::Result<()> {
+    writeln!(writer, "    <datafield tag=\"{tag}\" ind1=\" \" ind2=\" \">")?;
+    for (code, value) in subfields.iter().filter(|(_, value)| !value.is_empty()) {
+        writeln!(writer, "      <subfield code=\"{code}\">{}</subfield>", escape_xml(value))?;
+    }
+    writeln!(writer, "    </datafield>")
+}
+
+impl Dewey {
+    fn write_marcxml_record(&self, writer: &mut impl Write, class: &Class, edition: &str) -> io::Result<()> {
+        let number = CodeStyle::Padded.format(&class.code);
+        writeln!(writer, "  <record>")?;
+        writeln!(writer, "    <leader>{LEADER}</leader>")?;
+        writeln!(writer, "    <controlfield tag=\"001\">{}</controlfield>", escape_xml(&number))?;
+        writeln!(writer, "    <controlfield tag=\"008\">{FIXED_DATA}</controlfield>")?;
+        write_datafield(writer, "084", &[('a', "ddc"), ('c', edition)])?;
+
+        let ancestors = self.breadcrumb(&class.code);
+        let mut classification = vec![('a', number.as_str())];
+        classification.extend(ancestors.iter().filter(|ancestor| ancestor.code != class.code).map(|ancestor| ('h', ancestor.name.as_ref())));
+        classification.push(('j', &class.name));
+        write_datafield(writer, "153", &classification)?;
+
+        if let Some(parent) = class.parent_code().and_then(|code| self.class_ref(code)) {
+            write_datafield(writer, "553", &[('a', &CodeStyle::Padded.format(&parent.code)), ('t', &parent.name)])?;
+        }
+        writeln!(writer, "  </record>")
+    }
+
+    /// Writes every class as a MARC 21 classification record in MARCXML, in shelf order, ie for ingesting into a union catalog
+    ///
+    /// Each record has the number (in canonical DDC notation, ie `510`) as its control number (001), default fixed-length data (008), the scheme in 084 (`$a ddc`, with `$c 23` for the DDC 23 summaries), and the number & caption in 153 (`$a` & `$j`), preceded by the captions of its ancestors (`$h`). The hierarchy is approximated with a 553 field linking each class to its parent (`$a` & `$t`).
+    ///
+    /// # Arguments
+    ///
+    /// - `writer` (`impl Write`) - Destination of the collection
+    ///
+    /// # Returns
+    ///
+    /// - `io::Result<()>` - An error if writing fails
+    pub fn write_marcxml(&self, mut writer: impl Write) -> io::Result<()> {
+        let edition = match self.edition() {
+            Some(DatasetEdition::Ddc23Summaries) => "23",
+            _ => "",
+        };
+
+        writeln!(writer, "<?xml version=\"1.0\" encoding=\"UTF-8\"?>")?;
+        writeln!(writer, "<collection xmlns=\"{MARCXML}\">")?;
+        for class in self.all_ref() {
+            self.write_marcxml_record(&mut writer, class, edition)?;
+        }
+        writeln!(writer, "</collection>")
+    }
+
+    /// Exports every class as a MARC 21 classification record to a MARCXML file, see [Dewey::write_marcxml]
+    ///
+    /// # Arguments
+    ///
+    /// - `path` (`impl AsRef<Path>`) - Path of the file to write
+    ///
+    /// # Returns
+    ///
+    /// - `io::Result<()>` - An error if writing fails
+    pub fn export_marcxml(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_marcxml(&mut writer)?;
+        writer.flush()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_write_marcxml() {
+        let mut output = Vec::new();
+        Dewey::standard().write_marcxml(&mut output).unwrap();
+        let xml = String::from_utf8(output).unwrap();
+
+        assert_eq!(xml.matches("<record>").count(), Dewey::standard().all().len(), "Expected one record per class");
+        assert_eq!(xml.matches("<record>").count(), xml.matches("</record>").count(), "Expected balanced elements");
+        assert_eq!(xml.matches("<datafield tag=\"553\"").count(), Dewey::standard().all().len() - Dewey::standard().categories().len());
+
+        let record = xml.split("<record>").find(|record| record.contains("<controlfield tag=\"001\">516</controlfield>")).expect("Expected a record for 516");
+        assert!(record.contains("<leader>00000nw  a2200000n  4500</leader>"));
+        assert!(record.contains("<subfield code=\"a\">516</subfield>\n      <subfield code=\"h\">Science</subfield>\n      <subfield code=\"h\">Mathematics</subfield>\n      <subfield code=\"j\">Geometry</subfield>"));
+        assert!(record.contains("<datafield tag=\"553\" ind1=\" \" ind2=\" \">\n      <subfield code=\"a\">510</subfield>\n      <subfield code=\"t\">Mathematics</subfield>"));
+        assert!(!xml.contains(" & ") && xml.trim_end().ends_with("</collection>"));
+    }
+}
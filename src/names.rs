@@ -0,0 +1,141 @@
+use crate::{ dataset::is_valid_code, search::fold, Class, Dewey };
+
+/// Result of [Dewey::lookup_any], tagged with how the input was interpreted
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[cfg_attr(feature = "serde", serde(tag = "kind", content = "result", rename_all = "snake_case"))]
+pub enum LookupResult {
+    /// The input was a DDC code, resolving to the matching [Class] if any
+    Code(Option<Class>),
+
+    /// The input was text, resolving to the classes whose names contain it
+    Text(Vec<Class>),
+}
+
+fn is_code(input: &str) -> bool {
+    is_valid_code(input.trim_end_matches('X'))
+}
+
+impl Dewey {
+    /// Gets all classes whose names start with the provided prefix (ignoring case & diacritics)
+    ///
+    /// Uses a sorted name index generated at build time, so lookups are a binary search rather than a scan.
+    ///
+    /// # Arguments
+    ///
+    /// - `prefix` (`impl AsRef<str>`) - Start of the class name to search for
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<Class>` - [Vec] of matching [Class] instances, sorted by name
+    pub fn classes_named_like(&self, prefix: impl AsRef<str>) -> Vec<Class> {
+        let prefix = fold(prefix.as_ref());
+        let names = self.dataset().names;
+        let start = names.partition_point(|(name, _)| *name < prefix.as_str());
+
+        names[start..]
+            .iter()
+            .take_while(|(name, _)| name.starts_with(&prefix))
+            .filter_map(|(_, code)| self.get_class(code))
+            .collect()
+    }
+
+    /// Gets all classes whose names contain the provided text (ignoring case & diacritics), consulting [Dewey::synonyms]
+    ///
+    /// # Arguments
+    ///
+    /// - `text` (`impl AsRef<str>`) - Text to search for
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<Class>` - [Vec] of matching [Class] instances, exact name matches first, then names starting with the text, then the rest
+    pub fn classes_named_containing(&self, text: impl AsRef<str>) -> Vec<Class> {
+        let variants = self.synonyms().expand(text);
+        self.cached(
+            || format!("names:{}", variants.join("\u{1f}")),
+            || self.rank_names_containing(&variants)
+        )
+    }
+
+    pub(crate) fn rank_names_containing(&self, variants: &[String]) -> Vec<Class> {
+        let mut matches = self.dataset().names.iter()
+            .filter_map(|(name, code)| {
+                variants
+                    .iter()
+                    .filter_map(|variant| {
+                        if *name == variant {
+                            Some(0)
+                        } else if name.starts_with(variant.as_str()) {
+                            Some(1)
+                        } else if name.contains(variant.as_str()) {
+                            Some(2)
+                        } else {
+                            None
+                        }
+                    })
+                    .min()
+                    .map(|rank| (rank, *code))
+            })
+            .collect::<Vec<_>>();
+        matches.sort();
+
+        matches
+            .into_iter()
+            .filter_map(|(_, code)| self.get_class(code))
+            .collect()
+    }
+
+    /// Looks up either a code or a class name, depending on what the input looks like
+    ///
+    /// Inputs in DDC notation (optionally padded with `X`, ie `51X` or `813.54`) are treated as codes, anything else as text.
+    ///
+    /// # Arguments
+    ///
+    /// - `input` (`impl AsRef<str>`) - Code (ie `81`) or text (ie `American fiction`) to look up
+    ///
+    /// # Returns
+    ///
+    /// - `LookupResult` - [LookupResult::Code] from [Dewey::get_class], or [LookupResult::Text] from [Dewey::classes_named_containing]
+    pub fn lookup_any(&self, input: impl AsRef<str>) -> LookupResult {
+        let input = input.as_ref().trim();
+        if is_code(input) {
+            LookupResult::Code(self.get_class(input))
+        } else {
+            LookupResult::Text(self.classes_named_containing(input))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_classes_named_like() {
+        let results = Dewey::standard().classes_named_like("modern ");
+        assert!(!results.is_empty(), "Expected matches");
+        assert!(
+            results.iter().all(|class| class.name.to_lowercase().starts_with("modern ")),
+            "Unexpected match"
+        );
+        assert!(results.iter().any(|class| class.code == "19"), "Expected Modern Western philosophy");
+
+        assert!(Dewey::standard().classes_named_like("zzzz").is_empty(), "Expected no matches");
+    }
+
+    #[test]
+    fn test_lookup_any() {
+        match Dewey::standard().lookup_any("81") {
+            LookupResult::Code(Some(class)) => assert_eq!(class.name, "American literature in English"),
+            other => panic!("Expected a code match, got {other:?}"),
+        }
+        assert!(matches!(Dewey::standard().lookup_any("008"), LookupResult::Code(None)), "This code is unused!");
+
+        match Dewey::standard().lookup_any("American fiction") {
+            LookupResult::Text(classes) => assert_eq!(classes[0].code, "813"),
+            other => panic!("Expected a text match, got {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,223 @@
+use std::{ fmt, fs, path::Path, sync::Mutex };
+
+use ort::{ session::Session, value::Tensor };
+
+use crate::SuggestBackend;
+
+/// Number of features produced by [OnnxBackend]'s default featurizer
+pub const DEFAULT_FEATURE_DIMENSIONS: usize = 4096;
+
+/// Error produced by an [OnnxBackend]
+#[derive(Debug)]
+pub enum OnnxError {
+    /// The ONNX runtime failed to load or run the model
+    Runtime(ort::Error),
+
+    /// The labels file couldn't be read
+    Io(String),
+
+    /// The model produced a different number of scores than there are labels
+    LabelMismatch {
+        /// Number of labels
+        expected: usize,
+
+        /// Number of scores produced by the model
+        found: usize,
+    },
+}
+
+impl fmt::Display for OnnxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OnnxError::Runtime(error) => write!(f, "ONNX runtime error: {error}"),
+            OnnxError::Io(error) => write!(f, "failed to read labels: {error}"),
+            OnnxError::LabelMismatch { expected, found } =>
+                write!(f, "model produced {found} scores for {expected} labels"),
+        }
+    }
+}
+
+impl std::error::Error for OnnxError {}
+
+impl From<ort::Error> for OnnxError {
+    fn from(error: ort::Error) -> Self {
+        OnnxError::Runtime(error)
+    }
+}
+
+/// Reference [SuggestBackend] running a small ONNX text-classification model locally
+///
+/// The model takes a single `f32` input of shape `[1, dimensions]`: text is lowercased, split into alphanumeric tokens, and every token is hashed (FNV-1a) into one of `dimensions` buckets, with the resulting counts L2-normalized. It produces a single `f32` output of shape `[1, labels]`, whose logits are turned into probabilities with a softmax. Any model trained on the same featurization (ie a logistic regression exported from scikit-learn) can be used as-is.
+///
+/// The ONNX runtime library is loaded dynamically (see the `ort` crate's `load-dynamic` feature), so it must be installed separately, ie by pointing `ORT_DYLIB_PATH` at `libonnxruntime`.
+pub struct OnnxBackend {
+    session: Mutex<Session>,
+    labels: Vec<String>,
+    dimensions: usize,
+}
+
+impl OnnxBackend {
+    /// Loads a model & its labels
+    ///
+    /// # Arguments
+    ///
+    /// - `model` (`impl AsRef<Path>`) - Path to the `.onnx` model
+    /// - `labels` (`impl AsRef<Path>`) - Path to a text file with the code of each output, one per line, in output order
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Self, OnnxError>` - The loaded [OnnxBackend], using [DEFAULT_FEATURE_DIMENSIONS] features
+    pub fn load(model: impl AsRef<Path>, labels: impl AsRef<Path>) -> Result<Self, OnnxError> {
+        let labels = fs::read_to_string(labels).map_err(|error| OnnxError::Io(error.to_string()))?;
+        let labels = labels
+            .lines()
+            .map(str::trim)
+            .filter(|label| !label.is_empty())
+            .map(str::to_string)
+            .collect();
+        let session = Session::builder()?.commit_from_file(model)?;
+        Ok(Self { session: Mutex::new(session), labels, dimensions: DEFAULT_FEATURE_DIMENSIONS })
+    }
+
+    /// Sets the number of features the model expects
+    ///
+    /// # Arguments
+    ///
+    /// - `dimensions` (`usize`) - Length of the model's input (must match the dimensions used for training)
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The backend, using `dimensions` features
+    pub fn with_dimensions(mut self, dimensions: usize) -> Self {
+        self.dimensions = dimensions.max(1);
+        self
+    }
+
+    /// Gets the codes of the model's outputs
+    ///
+    /// # Returns
+    ///
+    /// - `&[String]` - Codes, in output order
+    pub fn labels(&self) -> &[String] {
+        &self.labels
+    }
+
+    /// Converts text into the model's input features
+    ///
+    /// # Arguments
+    ///
+    /// - `text` (`&str`) - Text to featurize
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<f32>` - L2-normalized hashed token counts
+    pub fn featurize(&self, text: &str) -> Vec<f32> {
+        featurize(text, self.dimensions)
+    }
+}
+
+/// Hashes the tokens of text into `dimensions` buckets (see [OnnxBackend])
+///
+/// # Arguments
+///
+/// - `text` (`&str`) - Text to featurize
+/// - `dimensions` (`usize`) - Number of buckets
+///
+/// # Returns
+///
+/// - `Vec<f32>` - L2-normalized hashed token counts (all zeros if the text has no tokens)
+fn featurize(text: &str, dimensions: usize) -> Vec<f32> {
+    let mut features = vec![0.0f32; dimensions];
+    for token in text.split(|c: char| !c.is_alphanumeric()).filter(|token| !token.is_empty()) {
+        let hash = token
+            .to_lowercase()
+            .bytes()
+            .fold(0xcbf29ce484222325u64, |hash, byte| (hash ^ (byte as u64)).wrapping_mul(0x100000001b3));
+        features[(hash % (dimensions as u64)) as usize] += 1.0;
+    }
+
+    let norm = features.iter().map(|value| value * value).sum::<f32>().sqrt();
+    if norm > 0.0 {
+        features.iter_mut().for_each(|value| *value /= norm);
+    }
+    features
+}
+
+/// Turns a model's logits into the probabilities of its labels
+///
+/// # Arguments
+///
+/// - `labels` (`&[String]`) - Codes of the model's outputs, in output order
+/// - `logits` (`&[f32]`) - Scores produced by the model
+/// - `limit` (`usize`) - Maximum number of labels to keep
+///
+/// # Returns
+///
+/// - `Result<Vec<(String, f64)>, OnnxError>` - Labels & their softmax probabilities, most likely first, or [OnnxError::LabelMismatch] if there isn't one logit per label
+fn probabilities(labels: &[String], logits: &[f32], limit: usize) -> Result<Vec<(String, f64)>, OnnxError> {
+    if logits.len() != labels.len() {
+        return Err(OnnxError::LabelMismatch { expected: labels.len(), found: logits.len() });
+    }
+
+    let max = logits.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+    let exps = logits.iter().map(|logit| ((logit - max) as f64).exp()).collect::<Vec<_>>();
+    let total = exps.iter().sum::<f64>();
+
+    let mut scores = labels
+        .iter()
+        .cloned()
+        .zip(exps.into_iter().map(|exp| exp / total))
+        .collect::<Vec<_>>();
+    scores.sort_by(|a, b| b.1.total_cmp(&a.1));
+    scores.truncate(limit);
+    Ok(scores)
+}
+
+impl SuggestBackend for OnnxBackend {
+    type Error = OnnxError;
+
+    fn suggest(&self, text: &str, limit: usize) -> Result<Vec<(String, f64)>, Self::Error> {
+        let input = Tensor::from_array(([1usize, self.dimensions], self.featurize(text)))?;
+        let mut session = self.session.lock().unwrap();
+        let outputs = session.run(ort::inputs![input])?;
+        let (_, logits) = outputs[0].try_extract_tensor::<f32>()?;
+        probabilities(&self.labels, logits, limit)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_featurize() {
+        let features = featurize("Rust, rust & RUST", 64);
+        assert_eq!(features.len(), 64);
+        assert_eq!(features.iter().filter(|value| **value > 0.0).count(), 1, "Expected tokens hashed case-insensitively into one bucket");
+        assert_eq!(features.iter().copied().fold(0.0f32, f32::max), 1.0);
+
+        let features = featurize("algebra geometry topology", 4096);
+        let norm = features.iter().map(|value| value * value).sum::<f32>().sqrt();
+        assert!((norm - 1.0).abs() < 1e-6, "Expected L2-normalized features");
+        assert_eq!(features, featurize("TOPOLOGY  geometry, algebra!", 4096), "Expected order & punctuation to be ignored");
+
+        assert!(featurize("", 16).iter().chain(featurize(" - ", 16).iter()).all(|value| *value == 0.0), "Expected no features without tokens");
+        assert_eq!(featurize("anything", 1), vec![1.0]);
+    }
+
+    #[test]
+    fn test_probabilities() {
+        let labels = ["510", "516", "520"].map(str::to_string);
+        let scores = probabilities(&labels, &[1.0, 3.0, 2.0], 10).unwrap();
+        assert_eq!(scores.iter().map(|(code, _)| code.as_str()).collect::<Vec<_>>(), ["516", "520", "510"]);
+        assert!((scores.iter().map(|(_, score)| score).sum::<f64>() - 1.0).abs() < 1e-9, "Expected probabilities to sum to 1");
+        assert!((scores[0].1 / scores[1].1 - 1.0f64.exp()).abs() < 1e-9);
+
+        let scores = probabilities(&labels, &[1000.0, 1000.0, -1000.0], 2).unwrap();
+        assert_eq!(scores.len(), 2);
+        assert!(scores.iter().all(|(_, score)| (score - 0.5).abs() < 1e-9), "Expected large logits not to overflow");
+
+        assert!(matches!(probabilities(&labels, &[1.0, 2.0], 10), Err(OnnxError::LabelMismatch { expected: 3, found: 2 })));
+        assert!(probabilities(&[], &[], 10).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,738 @@
+use std::{ borrow::Cow, collections::{ BTreeMap, BTreeSet }, fmt, sync::{ Arc, RwLock } };
+#[cfg(feature = "serde")]
+use std::{ fs::File, io::{ BufReader, BufWriter, Read, Write }, path::Path };
+
+use crate::{ dataset::{ self, Diagnostic, Severity }, lenient_code, search, Class, DdcCode, Dewey };
+
+/// Errors produced when modifying an [Overlay]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum OverlayError {
+    /// The code isn't a valid DDC code (ie `516` or `516.35`)
+    InvalidCode(String),
+
+    /// A class with this code already exists
+    AlreadyExists(String),
+
+    /// No class with this code exists
+    NotFound(String),
+
+    /// The code falls under a retired or redirected class, so a class added there wouldn't be visible
+    Hidden(String),
+
+    /// A class can't be redirected to itself or one of its descendants
+    InvalidRedirect(String),
+
+    /// Reading or writing a saved overlay failed
+    Io(String),
+
+    /// A saved overlay couldn't be parsed
+    Format(String),
+
+    /// A saved overlay uses a schema version this crate doesn't support
+    UnsupportedVersion(u32),
+}
+
+impl fmt::Display for OverlayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OverlayError::InvalidCode(code) => write!(f, "invalid DDC code: {code:?}"),
+            OverlayError::AlreadyExists(code) => write!(f, "class already exists: {code}"),
+            OverlayError::NotFound(code) => write!(f, "class not found: {code}"),
+            OverlayError::Hidden(code) => write!(f, "class falls under a retired or redirected class: {code}"),
+            OverlayError::InvalidRedirect(code) => write!(f, "class can't be redirected into itself: {code}"),
+            OverlayError::Io(error) => write!(f, "failed to access saved overlay: {error}"),
+            OverlayError::Format(error) => write!(f, "failed to parse saved overlay: {error}"),
+            OverlayError::UnsupportedVersion(version) =>
+                write!(f, "unsupported overlay schema version: {version}"),
+        }
+    }
+}
+
+impl std::error::Error for OverlayError {}
+
+/// Immutable view of the standard classes combined with an [Overlay]'s local changes
+///
+/// Obtained from [Overlay::snapshot]. Later changes to the overlay are never visible through an existing snapshot.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OverlaySnapshot {
+    added: BTreeMap<String, String>,
+    renamed: BTreeMap<String, String>,
+    retired: BTreeSet<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    redirected: BTreeMap<String, String>,
+}
+
+/// Canonicalizes a code before it's stored in an overlay, so `51X`, ` 51 ` & `51` (or `0081` & `008.1`) are the same class
+fn canonical(code: &str) -> Result<String, OverlayError> {
+    DdcCode::parse_lenient(code)
+        .map(|code| code.as_str().to_string())
+        .map_err(|_| OverlayError::InvalidCode(code.to_string()))
+}
+
+impl OverlaySnapshot {
+    /// Gets the target of the redirect covering a code, if any
+    fn redirect_of(&self, code: &str) -> Option<&str> {
+        self.redirected
+            .iter()
+            .find(|(redirected, _)| code.starts_with(redirected.as_str()))
+            .map(|(_, target)| target.as_str())
+    }
+
+    /// Follows the redirects covering a code to their final target (ie `516` to `514` after redirecting `516` to `515` & `515` to `514`), or [None] if they loop (ie in hand-edited overlay files)
+    fn redirect_target<'a>(&'a self, code: &'a str) -> Option<&'a str> {
+        let mut seen = BTreeSet::new();
+        let mut code = code;
+        while let Some(target) = self.redirect_of(code) {
+            if !seen.insert(code) {
+                return None;
+            }
+            code = target;
+        }
+        Some(code)
+    }
+
+    /// Checks whether a code was retired or redirected (along with its descendants)
+    fn is_hidden(&self, code: &str) -> bool {
+        self.retired.iter().any(|retired| code.starts_with(retired.as_str())) || self.redirect_of(code).is_some()
+    }
+
+    fn exists(&self, code: &str) -> bool {
+        !self.is_hidden(code) && (self.added.contains_key(code) || Dewey::standard().class_ref(code).is_some())
+    }
+
+    /// Iterates over the local classes under a prefix (including the prefix itself), hidden or not
+    fn added_under<'a>(&'a self, prefix: &'a str) -> impl Iterator<Item = &'a String> {
+        self.added
+            .range(prefix.to_string()..)
+            .take_while(move |(code, _)| code.starts_with(prefix))
+            .map(|(code, _)| code)
+    }
+
+    fn codes_matching(&self, prefix: &str) -> Vec<String> {
+        let mut codes = Dewey::standard().matches_ref(prefix)
+            .map(|class| class.code.to_string())
+            .chain(self.added_under(prefix).cloned())
+            .filter(|code| !self.is_hidden(code))
+            .collect::<Vec<_>>();
+        codes.sort();
+        codes
+    }
+
+    /// Counts the children & descendants of a visible class, from the standard counts adjusted for the local changes under it
+    fn counts(&self, code: &str) -> (usize, usize) {
+        let standard = Dewey::standard();
+        let under = |hidden: &String| hidden.starts_with(code) && hidden != code;
+        let hidden = self.retired.iter().chain(self.redirected.keys()).filter(|hidden| under(hidden)).collect::<BTreeSet<_>>();
+        let hidden_descendants = hidden
+            .iter()
+            .filter(|root| !hidden.iter().any(|other| other != *root && root.starts_with(other.as_str())))
+            .filter_map(|root| standard.class_ref(root))
+            .map(|class| class.descendant_count + 1)
+            .sum::<usize>();
+
+        let local = self.added_under(code).filter(|local| *local != code && !self.is_hidden(local)).collect::<Vec<_>>();
+        let descendants = standard.class_ref(code).map_or(0, |class| class.descendant_count) - hidden_descendants + local.len();
+        let children = standard.children_of(code).iter().filter(|child| !self.is_hidden(&child.code)).count() +
+            local.iter().filter(|local| self.parent_of(local).as_deref() == Some(code)).count();
+        (children, descendants)
+    }
+
+    fn parent_of(&self, code: &str) -> Option<String> {
+        dataset::prefixes(code)
+            .rev()
+            .find(|prefix| self.exists(prefix))
+            .map(String::from)
+    }
+
+    fn resolve(&self, code: &str) -> Option<Class> {
+        if self.is_hidden(code) {
+            return None;
+        }
+
+        let mut class = match self.added.get(code) {
+            Some(name) => Class::local(code, name, self.parent_of(code)),
+            None => Dewey::standard().get_class(code)?,
+        };
+
+        if let Some(name) = self.renamed.get(code) {
+            class.name = Cow::Owned(name.clone());
+        }
+
+        (class.child_count, class.descendant_count) = self.counts(code);
+        class.has_children = class.child_count > 0;
+
+        Some(class)
+    }
+
+    /// Gets a class by exact code match, see [Dewey::get_class]
+    ///
+    /// Codes that were redirected (or fall under a redirected class) resolve to the target of the redirect, following chains of redirects to the final target.
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code to search for
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Class>` - The matching [Class], or [None] if it doesn't exist, was retired, or its redirects loop
+    pub fn get_class(&self, code: impl AsRef<str>) -> Option<Class> {
+        let code = &lenient_code(code.as_ref());
+        self.redirect_target(code).and_then(|target| self.resolve(target))
+    }
+
+    /// Returns all classes matching the provided prefix, see [Dewey::get_matches]
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code to search for
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<Class>` - [Vec] of [Class] instances matching the prefix, in shelf order
+    pub fn get_matches(&self, code: impl AsRef<str>) -> Vec<Class> {
+        self.codes_matching(&lenient_code(code.as_ref()))
+            .into_iter()
+            .filter_map(|code| self.resolve(&code))
+            .collect()
+    }
+
+    /// Gets all the direct children of the class with the provided code, see [Dewey::get_direct_children]
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code to search for
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<Class>` - [Vec] of [Class] instances that are direct children of the specified class, in shelf order
+    pub fn get_direct_children(&self, code: impl AsRef<str>) -> Vec<Class> {
+        let code = &lenient_code(code.as_ref());
+        self.codes_matching(code)
+            .into_iter()
+            .filter(|child| self.parent_of(child).as_deref() == Some(code))
+            .filter_map(|child| self.resolve(&child))
+            .collect()
+    }
+
+    /// Gets all children (not including the exact match itself), see [Dewey::get_all_children]
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code to search for
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<Class>` - [Vec] of all children of this prefix, local classes included, in shelf order
+    pub fn get_all_children(&self, code: impl AsRef<str>) -> Vec<Class> {
+        let code = &lenient_code(code.as_ref());
+        self.codes_matching(code)
+            .into_iter()
+            .filter(|descendant| descendant != code)
+            .filter_map(|descendant| self.resolve(&descendant))
+            .collect()
+    }
+
+    /// Gets the path from a main class down to the selected class, see [Dewey::breadcrumb]
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code of the class
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<Class>` - The class's main class first, ending with the class itself (empty if it doesn't exist or was retired)
+    pub fn breadcrumb(&self, code: impl AsRef<str>) -> Vec<Class> {
+        let mut lineage = Vec::new();
+        let mut next = self.get_class(code);
+        while let Some(class) = next {
+            next = class.parent_code().and_then(|parent| self.get_class(parent));
+            lineage.push(class);
+        }
+        lineage.reverse();
+        lineage
+    }
+
+    /// Gets the parent of the selected class, if any, see [Dewey::get_parent]
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code to search for
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Class>` - Parent of the selected [Class], if any
+    pub fn get_parent(&self, code: impl AsRef<str>) -> Option<Class> {
+        self.get_class(code)?
+            .parent_code()
+            .and_then(|parent| self.get_class(parent))
+    }
+
+    /// Searches class names by keyword, see [Dewey::search]
+    ///
+    /// Renamed classes are matched by their new names, local classes are included, and retired or redirected classes are left out.
+    ///
+    /// # Arguments
+    ///
+    /// - `query` (`impl AsRef<str>`) - Keywords to search for
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<Class>` - [Vec] of matching [Class] instances, best first
+    pub fn search(&self, query: impl AsRef<str>) -> Vec<Class> {
+        let phrasings = Dewey::standard().search_phrasings(query.as_ref());
+        let mut codes = Dewey::standard().search(query.as_ref())
+            .into_iter()
+            .map(|class| class.code.into_owned())
+            .collect::<BTreeSet<_>>();
+        codes.extend(self.added.keys().chain(self.renamed.keys()).cloned());
+
+        let mut ranked = codes
+            .into_iter()
+            .filter_map(|code| self.resolve(&code))
+            .map(|class| (search::best_keyword_rank(&phrasings, &class.name), class))
+            .filter(|((matched, ..), _)| matched.0 > 0)
+            .collect::<Vec<_>>();
+        ranked.sort_by(|(a, first), (b, second)| a.cmp(b).then_with(|| first.code.cmp(&second.code)));
+        ranked.into_iter().map(|(_, class)| class).collect()
+    }
+
+    /// Lints the merged view of this snapshot, see [dataset::lint]
+    ///
+    /// Additionally reports renamed, retired or redirected codes that don't match any class as `W003` warnings (ie in hand-edited overlay files).
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<Diagnostic>` - Every [Diagnostic] found, errors first
+    pub fn lint(&self) -> Vec<Diagnostic> {
+        let mut diagnostics = dataset::lint(self.get_matches(""));
+        let redirects = self.redirected.iter().flat_map(|(code, target)| [code, target]);
+        for code in self.renamed.keys().chain(self.retired.iter()).chain(redirects) {
+            if !self.added.contains_key(code) && Dewey::standard().class_ref(code).is_none() {
+                diagnostics.push(Diagnostic {
+                    severity: Severity::Warning,
+                    rule: "W003",
+                    code: Some(code.clone()),
+                    message: format!("overlay refers to unknown class {code}"),
+                    suggestion: Some("remove the change from the overlay".to_string()),
+                });
+            }
+        }
+        diagnostics
+    }
+}
+
+/// Current schema version of saved overlays
+#[cfg(feature = "serde")]
+pub const OVERLAY_SCHEMA_VERSION: u32 = 1;
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct OverlayFile {
+    version: u32,
+    #[serde(flatten)]
+    overlay: OverlaySnapshot,
+}
+
+/// Thread-safe set of local changes (additions, renames, retirements & redirects) layered over the standard classes
+///
+/// Local classes (ie invented subsections like `813.6`) are seen by lookups & traversal through an [OverlaySnapshot], without forking the dataset.
+///
+/// Writers never block readers for longer than it takes to swap a pointer: each change produces a new [OverlaySnapshot], and readers holding an older snapshot continue to see a consistent view.
+///
+/// ```rust
+/// use dewey_decimal::Overlay;
+///
+/// let overlay = Overlay::new();
+/// overlay.add("6199", "Local medical history").unwrap();
+/// overlay.rename("61", "Medicine").unwrap();
+///
+/// let snapshot = overlay.snapshot();
+/// assert_eq!(snapshot.get_class("61").unwrap().name, "Medicine");
+/// assert_eq!(snapshot.get_parent("6199").unwrap().code, "619");
+/// ```
+#[derive(Debug, Default)]
+pub struct Overlay {
+    state: RwLock<Arc<OverlaySnapshot>>,
+}
+
+impl Overlay {
+    /// Creates an empty overlay
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - A new [Overlay] without any changes
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Gets a consistent view of the current state of the overlay
+    ///
+    /// # Returns
+    ///
+    /// - `Arc<OverlaySnapshot>` - The current [OverlaySnapshot]
+    pub fn snapshot(&self) -> Arc<OverlaySnapshot> {
+        self.state.read().unwrap().clone()
+    }
+
+    fn update<T>(&self, f: impl FnOnce(&mut OverlaySnapshot) -> Result<T, OverlayError>) -> Result<T, OverlayError> {
+        let mut state = self.state.write().unwrap();
+        let mut next = OverlaySnapshot::clone(&state);
+        let result = f(&mut next)?;
+        *state = Arc::new(next);
+        Ok(result)
+    }
+
+    /// Adds a local class
+    ///
+    /// Re-adding a retired class only brings the class itself back: its standard children stay retired & its local descendants are dropped.
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code of the new class, in DDC notation (ie `516` or `516.35`), cleaned up like in [DdcCode::parse_lenient]
+    /// - `name` (`impl AsRef<str>`) - Name of the new class
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), OverlayError>` - An error if the code is invalid, already in use, or falls under a retired or redirected class
+    pub fn add(&self, code: impl AsRef<str>, name: impl AsRef<str>) -> Result<(), OverlayError> {
+        let code = canonical(code.as_ref())?;
+        self.update(|state| {
+            if state.exists(&code) {
+                return Err(OverlayError::AlreadyExists(code));
+            }
+            let covers = |hidden: &String| *hidden != code && code.starts_with(hidden.as_str());
+            if state.retired.iter().any(covers) || state.redirected.keys().any(covers) {
+                return Err(OverlayError::Hidden(code));
+            }
+
+            if state.retired.remove(&code) {
+                state.retired.extend(Dewey::standard().children_of(&code).iter().map(|child| child.code.to_string()));
+                state.added.retain(|local, _| !local.starts_with(code.as_str()));
+                state.renamed.remove(&code);
+            }
+            state.redirected.remove(&code);
+            state.added.insert(code, name.as_ref().to_string());
+            Ok(())
+        })
+    }
+
+    /// Renames an existing class (standard or local)
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code of the class to rename
+    /// - `name` (`impl AsRef<str>`) - New name of the class
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), OverlayError>` - An error if the class doesn't exist
+    pub fn rename(&self, code: impl AsRef<str>, name: impl AsRef<str>) -> Result<(), OverlayError> {
+        let code = canonical(code.as_ref())?;
+        self.update(|state| {
+            if !state.exists(&code) {
+                return Err(OverlayError::NotFound(code));
+            }
+
+            match state.added.get_mut(&code) {
+                Some(local) => {
+                    *local = name.as_ref().to_string();
+                }
+                None => {
+                    state.renamed.insert(code, name.as_ref().to_string());
+                }
+            }
+            Ok(())
+        })
+    }
+
+    /// Retires an existing class (standard or local), hiding it and all of its descendants
+    ///
+    /// Local classes are removed along with their local descendants.
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code of the class to retire
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), OverlayError>` - An error if the class doesn't exist
+    pub fn retire(&self, code: impl AsRef<str>) -> Result<(), OverlayError> {
+        let code = canonical(code.as_ref())?;
+        self.update(|state| {
+            if !state.exists(&code) {
+                return Err(OverlayError::NotFound(code));
+            }
+
+            if state.added.contains_key(&code) {
+                state.added.retain(|local, _| !local.starts_with(code.as_str()));
+            } else {
+                state.retired.insert(code);
+            }
+            Ok(())
+        })
+    }
+
+    /// Redirects an existing class (standard or local) to another, ie to remap a number an institution doesn't use
+    ///
+    /// The class and its descendants are hidden from listings & searches like retired classes, but looking any of them up with [OverlaySnapshot::get_class] gets the target instead. Redirecting the target later on moves the earlier redirects along (ie `516` to `515`, then `515` to `514` gets `514` for `516`).
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code of the class to redirect
+    /// - `target` (`impl AsRef<str>`) - Code of the class to redirect to
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), OverlayError>` - An error if either class doesn't exist, or the target falls under the redirected class
+    pub fn redirect(&self, code: impl AsRef<str>, target: impl AsRef<str>) -> Result<(), OverlayError> {
+        let (code, target) = (canonical(code.as_ref())?, canonical(target.as_ref())?);
+        self.update(|state| {
+            if let Some(missing) = [&code, &target].into_iter().find(|code| !state.exists(code)) {
+                return Err(OverlayError::NotFound(missing.clone()));
+            }
+            if target.starts_with(code.as_str()) {
+                return Err(OverlayError::InvalidRedirect(code));
+            }
+
+            state.redirected.insert(code, target);
+            Ok(())
+        })
+    }
+
+    /// Writes the current state of the overlay as JSON
+    ///
+    /// # Arguments
+    ///
+    /// - `writer` (`impl Write`) - Destination of the JSON document
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), OverlayError>` - An error if writing fails
+    #[cfg(feature = "serde")]
+    pub fn save_to(&self, writer: impl Write) -> Result<(), OverlayError> {
+        let file = OverlayFile {
+            version: OVERLAY_SCHEMA_VERSION,
+            overlay: OverlaySnapshot::clone(&self.snapshot()),
+        };
+        serde_json::to_writer_pretty(writer, &file).map_err(|e| OverlayError::Io(e.to_string()))
+    }
+
+    /// Saves the current state of the overlay to a JSON file
+    ///
+    /// # Arguments
+    ///
+    /// - `path` (`impl AsRef<Path>`) - Path of the file to write
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), OverlayError>` - An error if writing fails
+    #[cfg(feature = "serde")]
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), OverlayError> {
+        let file = File::create(path).map_err(|e| OverlayError::Io(e.to_string()))?;
+        let mut writer = BufWriter::new(file);
+        self.save_to(&mut writer)?;
+        writer.flush().map_err(|e| OverlayError::Io(e.to_string()))
+    }
+
+    /// Reads an overlay previously written by [Overlay::save_to]
+    ///
+    /// # Arguments
+    ///
+    /// - `reader` (`impl Read`) - Source of the JSON document
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Self, OverlayError>` - The loaded [Overlay], or an error if the document is invalid or uses an unsupported schema version
+    #[cfg(feature = "serde")]
+    pub fn load_from(reader: impl Read) -> Result<Self, OverlayError> {
+        let document: serde_json::Value = serde_json::from_reader(reader)
+            .map_err(|e| OverlayError::Format(e.to_string()))?;
+        let version = document
+            .get("version")
+            .and_then(|version| version.as_u64())
+            .ok_or_else(|| OverlayError::Format("missing schema version".to_string()))?;
+        if version != u64::from(OVERLAY_SCHEMA_VERSION) {
+            return Err(OverlayError::UnsupportedVersion(u32::try_from(version).unwrap_or(u32::MAX)));
+        }
+
+        let file: OverlayFile = serde_json::from_value(document)
+            .map_err(|e| OverlayError::Format(e.to_string()))?;
+        let overlay = file.overlay;
+        let added = overlay.added.into_iter().map(|(code, name)| Ok((canonical(&code)?, name))).collect::<Result<_, _>>()?;
+        let overlay = OverlaySnapshot {
+            added,
+            renamed: overlay.renamed.into_iter().map(|(code, name)| (lenient_code(&code), name)).collect(),
+            retired: overlay.retired.iter().map(|code| lenient_code(code)).collect(),
+            redirected: overlay.redirected.iter().map(|(code, target)| (lenient_code(code), lenient_code(target))).collect(),
+        };
+
+        Ok(Self { state: RwLock::new(Arc::new(overlay)) })
+    }
+
+    /// Loads an overlay from a JSON file previously written by [Overlay::save]
+    ///
+    /// # Arguments
+    ///
+    /// - `path` (`impl AsRef<Path>`) - Path of the file to read
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Self, OverlayError>` - The loaded [Overlay], or an error if reading or parsing fails
+    #[cfg(feature = "serde")]
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, OverlayError> {
+        let file = File::open(path).map_err(|e| OverlayError::Io(e.to_string()))?;
+        Self::load_from(BufReader::new(file))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_overlay() {
+        let overlay = Overlay::new();
+        let before = overlay.snapshot();
+
+        overlay.add("0081", "Local collections").unwrap();
+        overlay.rename("00", "Computing").unwrap();
+        overlay.retire("09").unwrap();
+        assert_eq!(overlay.add("001", "Duplicate"), Err(OverlayError::AlreadyExists("001".to_string())));
+        assert_eq!(overlay.add("1a", "Invalid"), Err(OverlayError::InvalidCode("1a".to_string())));
+        assert_eq!(overlay.retire("09"), Err(OverlayError::NotFound("09".to_string())));
+
+        let after = overlay.snapshot();
+        let local = after.get_class("0081").unwrap();
+        assert_eq!((local.code.as_ref(), local.parent_code()), ("008.1", Some("00")));
+        assert_eq!(after.get_class("00").unwrap().name, "Computing");
+        assert!(after.get_direct_children("00").iter().any(|c| c.code == "008.1"), "Expected the local class");
+        assert!(after.get_class("09").is_none() && after.get_class("090").is_none(), "Expected retired classes");
+        assert_eq!(after.get_class("0").unwrap().child_count, 9);
+
+        overlay.add("813.54", "American fiction, 1945-1999").unwrap();
+        assert_eq!(overlay.snapshot().get_parent("813.54").unwrap().code, "813", "Expected the section as the parent");
+        overlay.add("813.541", "Local authors").unwrap();
+        let snapshot = overlay.snapshot();
+        let codes = |classes: Vec<Class>| classes.into_iter().map(|class| class.code).collect::<Vec<_>>();
+        assert_eq!(codes(snapshot.breadcrumb("813.541")), ["8", "81", "813", "813.54", "813.541"]);
+        assert_eq!(codes(snapshot.get_all_children("813")), ["813.54", "813.541"], "Expected local descendants");
+        assert!(snapshot.breadcrumb("09").is_empty() && snapshot.get_all_children("09").is_empty(), "Expected retired classes");
+
+        assert!(before.get_class("0081").is_none(), "Old snapshots shouldn't change");
+        assert_eq!(before.get_class("00").unwrap().name, Dewey::standard().get_class("00").unwrap().name);
+    }
+
+    #[test]
+    fn test_add_retired() {
+        let overlay = Overlay::new();
+        overlay.retire("5").unwrap();
+        assert_eq!(overlay.add("51", "Maths"), Err(OverlayError::Hidden("51".to_string())), "Expected the retired ancestor to be reported");
+        overlay.redirect("61", "62").unwrap();
+        assert_eq!(overlay.add("619.9", "Local"), Err(OverlayError::Hidden("619.9".to_string())));
+
+        let overlay = Overlay::new();
+        overlay.add("516.9", "Local geometries").unwrap();
+        overlay.rename("51", "Maths").unwrap();
+        overlay.retire("51").unwrap();
+        overlay.add("51", "Local mathematics").unwrap();
+        let snapshot = overlay.snapshot();
+        assert_eq!(snapshot.get_class("51").unwrap().name, "Local mathematics");
+        assert!(snapshot.get_class("516").is_none() && snapshot.get_class("510").is_none(), "Expected the standard children to stay retired");
+        assert!(snapshot.get_class("516.9").is_none(), "Expected the local descendants to stay retired");
+        assert!(snapshot.get_direct_children("51").is_empty());
+        overlay.add("516", "Local geometry").unwrap();
+        assert_eq!(overlay.snapshot().get_class("516").unwrap().name, "Local geometry");
+    }
+
+    #[test]
+    fn test_lenient_codes() {
+        let overlay = Overlay::new();
+        overlay.retire("51X").unwrap();
+        assert!(overlay.snapshot().get_class("51").is_none() && overlay.snapshot().get_class("516").is_none());
+        assert_eq!(overlay.rename("51X", "Maths"), Err(OverlayError::NotFound("51".to_string())));
+
+        overlay.add("0081", "Local collections").unwrap();
+        assert_eq!(overlay.add("008.1", "Duplicate"), Err(OverlayError::AlreadyExists("008.1".to_string())));
+        overlay.rename(" 008/1 ", "Special collections").unwrap();
+        assert_eq!(overlay.snapshot().get_class("0081").unwrap().name, "Special collections");
+
+        overlay.add("813.6", "Local fiction").unwrap();
+        overlay.add("813.61", "Local authors").unwrap();
+        assert_eq!(overlay.snapshot().get_class("813").unwrap().descendant_count, 2);
+        overlay.retire("813.6").unwrap();
+        let snapshot = overlay.snapshot();
+        assert!(snapshot.get_class("813.61").is_none(), "Expected local descendants to be retired too");
+        assert_eq!(snapshot.get_class("813").unwrap().descendant_count, 0);
+
+        let standard = Dewey::standard();
+        let expected = standard.get_class("5").unwrap().descendant_count - standard.get_class("51").unwrap().descendant_count - 1;
+        let science = snapshot.get_class("5").unwrap();
+        assert_eq!((science.child_count, science.descendant_count), (standard.children_of("5").len() - 1, expected));
+        assert_eq!(snapshot.get_class("0").unwrap().descendant_count, standard.get_class("0").unwrap().descendant_count + 1);
+    }
+
+    #[test]
+    fn test_overrides() {
+        let overlay = Overlay::new();
+        overlay.rename("77", "Lens-based media").unwrap();
+        overlay.redirect("004", "005").unwrap();
+        overlay.add("0081", "Local photography collections").unwrap();
+        assert_eq!(overlay.redirect("004", "005"), Err(OverlayError::NotFound("004".to_string())));
+        assert_eq!(overlay.redirect("00", "005"), Err(OverlayError::InvalidRedirect("00".to_string())));
+
+        let snapshot = overlay.snapshot();
+        assert_eq!(snapshot.get_class("004").unwrap().code, "005", "Expected the target of the redirect");
+        assert!(!snapshot.get_direct_children("00").iter().any(|class| class.code == "004"), "Expected redirected classes to be hidden");
+
+        let results = snapshot.search("photography");
+        assert!(results.iter().any(|class| class.code == "008.1"), "Expected local classes");
+        assert!(!results.iter().any(|class| class.code == "77"), "Expected renamed classes to be matched by their new names");
+        assert_eq!(snapshot.search("lens")[0].name, "Lens-based media");
+        assert!(snapshot.search("computer").iter().all(|class| class.code != "004"));
+        assert!(snapshot.search("maths").iter().any(|class| class.code == "51"), "Expected synonyms to be consulted");
+    }
+
+    #[test]
+    fn test_redirect_chains() {
+        let overlay = Overlay::new();
+        overlay.redirect("516", "515").unwrap();
+        overlay.redirect("515", "514").unwrap();
+        let snapshot = overlay.snapshot();
+        assert_eq!(snapshot.get_class("516").unwrap().code, "514", "Expected the final target of the redirects");
+        assert_eq!(snapshot.get_class("516.3").unwrap().code, "514");
+        assert_eq!(overlay.redirect("514", "516"), Err(OverlayError::NotFound("516".to_string())), "Expected no redirects to hidden classes");
+
+        overlay.retire("514").unwrap();
+        assert!(overlay.snapshot().get_class("516").is_none(), "Expected retired targets to end the chain");
+
+        let looping = OverlaySnapshot {
+            redirected: [("515", "516"), ("516", "515")].map(|(code, target)| (code.to_string(), target.to_string())).into(),
+            ..Default::default()
+        };
+        assert!(looping.get_class("516").is_none() && looping.get_class("515").is_none(), "Expected looping redirects not to resolve");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_save_load() {
+        let overlay = Overlay::new();
+        overlay.add("0081", "Local collections").unwrap();
+        overlay.rename("00", "Computing").unwrap();
+        overlay.retire("09").unwrap();
+
+        let mut saved = Vec::new();
+        overlay.save_to(&mut saved).unwrap();
+        let loaded = Overlay::load_from(saved.as_slice()).unwrap().snapshot();
+        assert_eq!(loaded.get_class("0081").unwrap().name, "Local collections");
+        assert_eq!(loaded.get_class("00").unwrap().name, "Computing");
+        assert!(loaded.get_class("09").is_none(), "Expected retired class");
+
+        let edited = br#"{"version": 1, "added": {"0081": " Local"}, "renamed": {"0009": "Nothing"}, "retired": []}"#;
+        let rules = Overlay::load_from(&edited[..]).unwrap().snapshot().lint().into_iter().map(|d| d.rule).collect::<Vec<_>>();
+        assert_eq!(rules, vec!["W002", "W003"]);
+
+        let future = br#"{"version": 99, "added": {}, "renamed": {}, "retired": []}"#;
+        assert_eq!(Overlay::load_from(&future[..]).unwrap_err(), OverlayError::UnsupportedVersion(99));
+    }
+}
@@ -0,0 +1,47 @@
+use rayon::prelude::*;
+
+use crate::{ Class, Dewey };
+
+impl Dewey {
+    /// Iterates over all classes in parallel, without copying them (see [Dewey::all_ref])
+    ///
+    /// The iterator is indexed, so collecting it keeps shelf order (ie `dewey.par_iter().map(..).collect::<Vec<_>>()`).
+    ///
+    /// # Returns
+    ///
+    /// - `impl IndexedParallelIterator<Item = &'static Class>` - Parallel iterator over every [Class]
+    pub fn par_iter(&self) -> impl IndexedParallelIterator<Item = &'static Class> {
+        self.all_ref().collect::<Vec<_>>().into_par_iter()
+    }
+
+    /// Iterates over the direct children of a class in parallel, without copying them (see [Dewey::children_of])
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code of the parent class (an empty string gets the main classes)
+    ///
+    /// # Returns
+    ///
+    /// - `impl IndexedParallelIterator<Item = &'static Class>` - Parallel iterator over the children, which is empty if the class doesn't exist
+    pub fn par_children(&self, code: impl AsRef<str>) -> impl IndexedParallelIterator<Item = &'static Class> {
+        self.children_of(code).par_iter()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parallel() {
+        let dewey = Dewey::standard();
+        let codes: Vec<&str> = dewey.par_iter().map(|class| class.code.as_ref()).collect();
+        assert_eq!(codes, dewey.all_ref().map(|class| class.code.as_ref()).collect::<Vec<_>>(), "Expected shelf order");
+
+        let subtrees: Vec<usize> = dewey.par_children("").map(|class| dewey.get_all_children(&class.code).len()).collect();
+        assert_eq!(subtrees.len(), 10);
+        assert_eq!(subtrees.iter().sum::<usize>() + 10, dewey.all_ref().count());
+        assert_eq!(dewey.par_children("51").count(), dewey.children_of("51").len());
+        assert_eq!(dewey.par_children("abc").count(), 0);
+    }
+}
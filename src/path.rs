@@ -0,0 +1,185 @@
+use std::fmt;
+
+use crate::{ export::PATH_SEPARATOR, lenient_code, Class, CodeStyle, Dewey };
+
+/// Separator between codes in the paths of [Dewey::path_string]
+pub const CODE_PATH_SEPARATOR: char = '/';
+
+/// Errors produced when parsing a path of codes with [Dewey::from_path]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ParsePathError {
+    /// The path doesn't contain any code
+    Empty,
+
+    /// A segment of the path doesn't match any class
+    NotFound(String),
+
+    /// The first segment of the path isn't a main class
+    NotMainClass(String),
+
+    /// A segment of the path isn't a direct child of the segment before it
+    NotChild {
+        /// Code of the preceding segment
+        parent: String,
+
+        /// The offending segment
+        code: String,
+    },
+}
+
+impl fmt::Display for ParsePathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParsePathError::Empty => write!(f, "path is empty"),
+            ParsePathError::NotFound(code) => write!(f, "no class with code {code}"),
+            ParsePathError::NotMainClass(code) => write!(f, "path starts with {code}, which isn't a main class"),
+            ParsePathError::NotChild { parent, code } => write!(f, "{code} isn't a direct child of {parent}"),
+        }
+    }
+}
+
+impl std::error::Error for ParsePathError {}
+
+impl Class {
+    /// Gets the code path of this class in the standard dataset, see [Dewey::path_string]
+    #[deprecated(note = "only looks in `Dewey::standard()`, use `dewey.path_string(&class.code)` on the class's dataset instead")]
+    pub fn path_string(&self) -> String {
+        Dewey::standard().path_string(&self.code)
+    }
+
+    /// Gets the display path of this class in the standard dataset, see [Dewey::display_path]
+    #[deprecated(note = "only looks in `Dewey::standard()`, use `dewey.display_path(&class.code)` on the class's dataset instead")]
+    pub fn display_path(&self) -> String {
+        Dewey::standard().display_path(&self.code)
+    }
+
+    /// Gets the path of this class in the standard dataset, see [Dewey::path_with]
+    #[deprecated(note = "only looks in `Dewey::standard()`, use `dewey.path_with(&class.code, style, separator)` on the class's dataset instead")]
+    pub fn path_with(&self, style: CodeStyle, separator: &str) -> String {
+        Dewey::standard().path_with(&self.code, style, separator)
+    }
+}
+
+impl Dewey {
+    /// Gets the codes of a class's ancestors & the class itself, joined with [CODE_PATH_SEPARATOR] (ie `5/51/516`), ie for materialized paths in a search index
+    ///
+    /// Codes are written as stored, regardless of the crate-wide [CodeStyle], so paths stay stable. Parse them back with [Dewey::from_path].
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code of the class
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The path, main class first (empty if the class doesn't exist)
+    pub fn path_string(&self, code: impl AsRef<str>) -> String {
+        self.path_with(code, CodeStyle::Trimmed, &CODE_PATH_SEPARATOR.to_string())
+    }
+
+    /// Gets the codes of a class's ancestors & the class itself in canonical DDC notation, joined with [PATH_SEPARATOR] (ie `500 > 510 > 516`), for displaying
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code of the class
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The path, main class first (empty if the class doesn't exist)
+    pub fn display_path(&self, code: impl AsRef<str>) -> String {
+        self.path_with(code, CodeStyle::Padded, PATH_SEPARATOR)
+    }
+
+    /// Gets the codes of a class's ancestors & the class itself, formatted & joined as provided
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code of the class
+    /// - `style` (`CodeStyle`) - Style of each code
+    /// - `separator` (`&str`) - Separator between codes
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The path, main class first (empty if the class doesn't exist)
+    pub fn path_with(&self, code: impl AsRef<str>, style: CodeStyle, separator: &str) -> String {
+        self.breadcrumb(code)
+            .iter()
+            .map(|class| style.format(&class.code))
+            .collect::<Vec<_>>()
+            .join(separator)
+    }
+
+    /// Parses a path of codes back into its class, checking that every segment is a direct child of the one before it
+    ///
+    /// Segments can be separated with `/` or `>` (so both [Dewey::path_string] & [Dewey::display_path] parse), and are matched leniently in either [CodeStyle] (ie `5/51/516` & `500 > 510 > 516` are the same path).
+    ///
+    /// # Arguments
+    ///
+    /// - `path` (`impl AsRef<str>`) - Path to parse, starting with a main class
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Class, ParsePathError>` - The class of the last segment, or the first invalid segment
+    pub fn from_path(&self, path: impl AsRef<str>) -> Result<Class, ParsePathError> {
+        if path.as_ref().trim().is_empty() {
+            return Err(ParsePathError::Empty);
+        }
+
+        let mut current: Option<&'static Class> = None;
+        for segment in path.as_ref().split([CODE_PATH_SEPARATOR, '>']).map(str::trim) {
+            let code = lenient_code(segment);
+            let parent = current.map_or("", |class| &class.code);
+            let child = self.children_of(parent)
+                .iter()
+                .find(|child| child.code == code || CodeStyle::Padded.format(&child.code) == code);
+
+            current = match child {
+                Some(child) => Some(child),
+                None if self.class_ref(&code).is_none() => return Err(ParsePathError::NotFound(segment.to_string())),
+                None if current.is_none() => return Err(ParsePathError::NotMainClass(segment.to_string())),
+                None => return Err(ParsePathError::NotChild { parent: parent.to_string(), code: segment.to_string() }),
+            };
+        }
+        current.cloned().ok_or(ParsePathError::Empty)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_paths() {
+        let dewey = Dewey::standard();
+        let geometry = dewey.get_class("516").unwrap();
+        assert_eq!(dewey.path_string("516"), "5/51/516");
+        assert_eq!(dewey.display_path("516"), "500 > 510 > 516");
+        assert_eq!(dewey.path_string("0"), "0");
+        assert_eq!(dewey.path_string("abc"), "");
+
+        for path in ["5/51/516", "500 > 510 > 516", " 5 / 51 /516 "] {
+            assert_eq!(dewey.from_path(path).map(|class| class.code), Ok(geometry.code.clone()), "Unexpected class for {path}");
+        }
+        assert_eq!(dewey.from_path("000 > 000").unwrap().code, "00", "Expected padded segments to match by depth");
+        assert_eq!(dewey.from_path(" "), Err(ParsePathError::Empty));
+        assert_eq!(dewey.from_path("5//51"), Err(ParsePathError::NotFound(String::new())));
+        assert_eq!(dewey.from_path("51/516"), Err(ParsePathError::NotMainClass("51".to_string())));
+        assert_eq!(dewey.from_path("5/52/516"), Err(ParsePathError::NotChild { parent: "52".to_string(), code: "516".to_string() }));
+        assert_eq!(dewey.from_path("5/51/008"), Err(ParsePathError::NotFound("008".to_string())));
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_class_paths() {
+        let geometry = Dewey::standard().get_class("516").unwrap();
+        assert_eq!((geometry.path_string(), geometry.display_path()), ("5/51/516".to_string(), "500 > 510 > 516".to_string()));
+        assert_eq!(geometry.path_with(CodeStyle::Trimmed, "."), "5.51.516");
+    }
+
+    #[test]
+    fn test_paths_handle() {
+        let science = Dewey::builder().include(['5']).build().unwrap();
+        assert_eq!(science.path_string("516"), "5/51/516");
+        assert_eq!(science.path_string("813"), "", "Expected classes outside the dataset to have no path");
+        assert_eq!(science.from_path(science.display_path("516")).unwrap().code, "516");
+    }
+}
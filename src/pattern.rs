@@ -0,0 +1,55 @@
+use regex::Regex;
+
+use crate::{ Class, Dewey };
+
+impl Dewey {
+    /// Searches class names & codes with a regular expression (ie `(?i)history of .* america`), for queries keyword search can't express
+    ///
+    /// A class matches if the pattern matches anywhere in its name, its code as stored (ie `516.3`), or its code in the crate-wide [CodeStyle](crate::CodeStyle). Anchor the pattern (ie `^51`) to match whole codes or names.
+    ///
+    /// # Arguments
+    ///
+    /// - `pattern` (`impl AsRef<str>`) - Pattern to compile, in the syntax of the `regex` crate
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Vec<Class>, regex::Error>` - Every matching [Class] in shelf order, or an error if the pattern is invalid
+    pub fn search_regex(&self, pattern: impl AsRef<str>) -> Result<Vec<Class>, regex::Error> {
+        Ok(self.search_regex_with(&Regex::new(pattern.as_ref())?))
+    }
+
+    /// Searches class names & codes with a compiled regular expression, ie to reuse a pattern across datasets (see [Dewey::search_regex])
+    ///
+    /// # Arguments
+    ///
+    /// - `regex` (`&Regex`) - Compiled pattern
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<Class>` - Every matching [Class] in shelf order
+    pub fn search_regex_with(&self, regex: &Regex) -> Vec<Class> {
+        self.all_ref()
+            .filter(|class| regex.is_match(&class.name) || regex.is_match(&class.code) || regex.is_match(&class.display_code()))
+            .cloned()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_search_regex() {
+        let dewey = Dewey::standard();
+        let codes = |pattern: &str| dewey.search_regex(pattern).unwrap().into_iter().map(|class| class.code.into_owned()).collect::<Vec<_>>();
+
+        let histories = dewey.search_regex("(?i)history of .* america").unwrap();
+        assert!(!histories.is_empty() && histories.iter().all(|class| class.name.to_lowercase().contains("america")));
+        assert_eq!(codes("^Geometry$"), ["516"]);
+        assert!(codes("^51").iter().all(|code| code.starts_with("51")), "Expected codes to match");
+        assert_eq!(codes("^5$"), ["5"]);
+        assert!(codes("geometry").is_empty(), "Expected matching to be case-sensitive by default");
+        assert!(dewey.search_regex("(unclosed").is_err());
+    }
+}
@@ -0,0 +1,204 @@
+//! Precompiled caches of datasets, so services loading a dataset at runtime don't parse & index it on every start
+//!
+//! A cache file starts with a magic number, the format version ([CACHE_FORMAT_VERSION], `u32`) & the metadata features of the records (a `u8`, see `blob.rs`), followed by a DEFLATE-compressed payload: the class records in shelf order (in the layout of the embedded blob, see `blob.rs`), the name index (name & code pairs), the keyword index (each keyword followed by its codes), and the source tree (each node followed by its children, with its query & work count only with the `query` & `count` features). Every sequence is prefixed with its length (`u32`).
+
+use std::{ fs, io::{ self, Read, Write }, path::Path };
+
+use crate::{ blob::{ self, Reader }, Dataset, Dewey, LoadError, RawNode };
+
+const MAGIC: &[u8; 8] = b"DDCCACHE";
+
+/// Current version of the cache file format, bumped whenever the layout changes
+pub const CACHE_FORMAT_VERSION: u32 = 2;
+
+fn write_len(output: &mut Vec<u8>, len: usize) {
+    output.extend_from_slice(&(len as u32).to_le_bytes());
+}
+
+fn write_nodes(output: &mut Vec<u8>, nodes: &[RawNode]) {
+    write_len(output, nodes.len());
+    for node in nodes {
+        blob::write_str(output, node.name);
+        blob::write_str(output, node.short);
+        #[cfg(feature = "query")]
+        blob::write_str(output, node.query);
+        #[cfg(feature = "count")]
+        output.extend_from_slice(&node.count.to_le_bytes());
+        write_nodes(output, node.children);
+    }
+}
+
+fn read_nodes(reader: &mut Reader<'static>) -> Option<&'static [RawNode]> {
+    let len = reader.u32()? as usize;
+    let mut nodes = Vec::with_capacity(len.min(reader.0.len()));
+    for _ in 0..len {
+        nodes.push(RawNode {
+            name: reader.str()?,
+            short: reader.str()?,
+            #[cfg(feature = "query")]
+            query: reader.str()?,
+            #[cfg(feature = "count")]
+            count: u64::from_le_bytes(reader.bytes()?),
+            children: read_nodes(reader)?,
+        });
+    }
+    Some(Box::leak(nodes.into_boxed_slice()))
+}
+
+/// Reads a sequence prefixed with its length
+fn read_seq<T>(reader: &mut Reader<'static>, mut item: impl FnMut(&mut Reader<'static>) -> Option<T>) -> Option<Vec<T>> {
+    let len = reader.u32()? as usize;
+    (0..len).map(|_| item(reader)).collect()
+}
+
+fn decode(data: &'static [u8]) -> Option<Dataset> {
+    let mut reader = Reader(data);
+    let classes = read_seq(&mut reader, Reader::class)?;
+    let names = read_seq(&mut reader, |reader| Some((reader.str()?, reader.str()?)))?;
+    let keywords = read_seq(&mut reader, |reader| Some((reader.str()?, &*Vec::leak(read_seq(reader, Reader::str)?))))?;
+    let raw = read_nodes(&mut reader)?;
+    reader.0.is_empty().then(|| Dataset::indexed(classes, raw, Vec::leak(names), Vec::leak(keywords)))
+}
+
+impl Dewey {
+    /// Writes a precompiled cache of this dataset, with its classes & indexes, see [Dewey::load_cache]
+    ///
+    /// # Arguments
+    ///
+    /// - `writer` (`impl Write`) - Destination of the cache
+    ///
+    /// # Returns
+    ///
+    /// - `io::Result<()>` - An error if writing fails
+    pub fn write_cache(&self, mut writer: impl Write) -> io::Result<()> {
+        let dataset = self.dataset();
+        let mut payload = Vec::new();
+        write_len(&mut payload, self.all_ref().count());
+        for class in self.all_ref() {
+            blob::write_class(&mut payload, class);
+        }
+
+        write_len(&mut payload, dataset.names.len());
+        for (name, code) in dataset.names {
+            blob::write_str(&mut payload, name);
+            blob::write_str(&mut payload, code);
+        }
+
+        write_len(&mut payload, dataset.keywords.len());
+        for (keyword, codes) in dataset.keywords {
+            blob::write_str(&mut payload, keyword);
+            write_len(&mut payload, codes.len());
+            codes.iter().for_each(|code| blob::write_str(&mut payload, code));
+        }
+        write_nodes(&mut payload, dataset.raw);
+
+        writer.write_all(MAGIC)?;
+        writer.write_all(&CACHE_FORMAT_VERSION.to_le_bytes())?;
+        writer.write_all(&[blob::LAYOUT])?;
+        writer.write_all(&miniz_oxide::deflate::compress_to_vec(&payload, 6))
+    }
+
+    /// Saves a precompiled cache of this dataset to a file, ie after loading a custom dataset with a [DeweyBuilder](crate::DeweyBuilder), see [Dewey::write_cache]
+    ///
+    /// # Arguments
+    ///
+    /// - `path` (`impl AsRef<Path>`) - Path of the file to write
+    ///
+    /// # Returns
+    ///
+    /// - `io::Result<()>` - An error if writing fails
+    pub fn save_cache(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let mut output = Vec::new();
+        self.write_cache(&mut output)?;
+        fs::write(path, output)
+    }
+
+    /// Loads a dataset from a precompiled cache written by [Dewey::write_cache], without parsing, validating or recomputing its hierarchy & name indexes
+    ///
+    /// Like datasets loaded with a [DeweyBuilder](crate::DeweyBuilder), the dataset lives for the rest of the program, and its classes borrow their strings from the decompressed cache.
+    ///
+    /// # Arguments
+    ///
+    /// - `reader` (`impl Read`) - Source of the cache
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Dewey, LoadError>` - A handle to the dataset, or an error if the cache can't be read, was written by another format version or with other `count` & `query` features, or is corrupted
+    pub fn load_cache_from(mut reader: impl Read) -> Result<Dewey, LoadError> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).map_err(|error| LoadError::Io(error.to_string()))?;
+
+        let Some((header, payload)) = bytes.split_at_checked(MAGIC.len() + 5) else {
+            return Err(LoadError::Format("cache is truncated".to_string()));
+        };
+        if &header[..MAGIC.len()] != MAGIC {
+            return Err(LoadError::Format("not a dataset cache".to_string()));
+        }
+        let version = u32::from_le_bytes(header[MAGIC.len()..MAGIC.len() + 4].try_into().unwrap());
+        if version != CACHE_FORMAT_VERSION {
+            return Err(LoadError::Format(format!("unsupported cache format version {version}, expected {CACHE_FORMAT_VERSION}")));
+        }
+        if header[MAGIC.len() + 4] != blob::LAYOUT {
+            return Err(LoadError::Format("cache was written with other `count` & `query` features".to_string()));
+        }
+
+        let data = miniz_oxide::inflate::decompress_to_vec(payload).map_err(|error| LoadError::Format(format!("cache is corrupted: {error}")))?;
+        let dataset = decode(Vec::leak(data)).ok_or_else(|| LoadError::Format("cache is corrupted".to_string()))?;
+        Ok(Dewey::loaded(Box::leak(Box::new(dataset))))
+    }
+
+    /// Loads a dataset from a precompiled cache file, see [Dewey::load_cache_from]
+    ///
+    /// # Arguments
+    ///
+    /// - `path` (`impl AsRef<Path>`) - Path of the cache
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Dewey, LoadError>` - A handle to the dataset, or an error if the cache can't be read or is invalid
+    pub fn load_cache(path: impl AsRef<Path>) -> Result<Dewey, LoadError> {
+        let file = fs::File::open(path).map_err(|error| LoadError::Io(error.to_string()))?;
+        Dewey::load_cache_from(io::BufReader::new(file))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{ dataset::Entry, DatasetSource, DeweySource };
+
+    #[test]
+    fn test_cache() {
+        let entries = [("5", "Science", None), ("51", "Mathematics", Some("5")), ("516", "Géométrie & topologie", Some("51"))];
+        let dewey = Dewey::builder()
+            .source(DeweySource::Entries(entries.iter().map(|(code, name, parent)| Entry::new(code, name, *parent)).collect()))
+            .build()
+            .unwrap();
+
+        let path = std::env::temp_dir().join(format!("dewey-cache-{}.bin", std::process::id()));
+        dewey.save_cache(&path).unwrap();
+        let cached = Dewey::load_cache(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(cached.all(), dewey.all());
+        assert_eq!(cached.get_class("5").unwrap().descendant_count, 2);
+        assert_eq!(cached.children_of("51"), dewey.children_of("51"));
+        assert_eq!(cached.search("geometrie")[0].code, "516");
+        assert_eq!(cached.classes_named_like("GEOMETRIE").len(), 1);
+        assert_eq!(cached.dataset_source(), DatasetSource::Runtime);
+
+        let mut standard = Vec::new();
+        Dewey::standard().write_cache(&mut standard).unwrap();
+        let cached = Dewey::load_cache_from(standard.as_slice()).unwrap();
+        assert_eq!(cached.all().len(), Dewey::standard().all().len());
+        assert_eq!(cached.raw_tree().len(), Dewey::standard().raw_tree().len());
+        assert_eq!(cached.get_class("516").unwrap().name, "Geometry");
+
+        assert!(matches!(Dewey::load_cache_from(&b"DDCCACHE"[..]), Err(LoadError::Format(_))));
+        assert!(matches!(Dewey::load_cache_from(&standard[..standard.len() / 2]), Err(LoadError::Format(_))));
+        standard[MAGIC.len() + 4] ^= 0b11;
+        assert!(matches!(Dewey::load_cache_from(standard.as_slice()), Err(LoadError::Format(message)) if message.contains("features")));
+        standard[MAGIC.len()] = 1;
+        assert!(matches!(Dewey::load_cache_from(standard.as_slice()), Err(LoadError::Format(message)) if message.contains("version 1")));
+    }
+}
@@ -0,0 +1,280 @@
+use std::cmp::Ordering;
+
+use crate::{ dataset::is_valid_code, Class, Dewey };
+
+/// Where a [PseudoClass] is shelved relative to numeric classes
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+pub enum ShelfPosition {
+    /// Shelved before `000`
+    BeforeNumbers,
+
+    /// Shelved after `999`
+    AfterNumbers,
+}
+
+/// Non-DDC shelving mark used alongside DDC numbers (ie `FIC` for fiction, `E` for easy books)
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+pub struct PseudoClass {
+    /// Shelving mark, stored uppercased (ie `FIC`)
+    pub mark: String,
+
+    /// Friendly name of the pseudo-class
+    pub name: String,
+
+    /// Where the pseudo-class is shelved relative to numeric classes
+    pub position: ShelfPosition,
+}
+
+impl PseudoClass {
+    /// Creates a new pseudo-class
+    ///
+    /// # Arguments
+    ///
+    /// - `mark` (`impl AsRef<str>`) - Shelving mark (case-insensitive, must not contain digits)
+    /// - `name` (`impl AsRef<str>`) - Friendly name of the pseudo-class
+    /// - `position` (`ShelfPosition`) - Where the pseudo-class is shelved relative to numeric classes
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - A new [PseudoClass]
+    pub fn new(mark: impl AsRef<str>, name: impl AsRef<str>, position: ShelfPosition) -> Self {
+        Self { mark: mark.as_ref().trim().to_uppercase(), name: name.as_ref().to_string(), position }
+    }
+}
+
+/// Pseudo-classes built into the crate, following common public library practice
+const BUILTIN_PSEUDO_CLASSES: &[(&str, &str, ShelfPosition)] = &[
+    ("E", "Easy books", ShelfPosition::BeforeNumbers),
+    ("B", "Biography", ShelfPosition::AfterNumbers),
+    ("FIC", "Fiction", ShelfPosition::AfterNumbers),
+];
+
+/// Set of pseudo-classes recognized by [Dewey::parse_shelf_mark]
+#[derive(Clone, Debug, Default)]
+pub struct PseudoClasses {
+    classes: Vec<PseudoClass>,
+}
+
+impl PseudoClasses {
+    /// Creates an empty set
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - A new [PseudoClasses] without any pseudo-classes
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a set containing the built-in pseudo-classes (`E`, `B`, and `FIC`)
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - A new [PseudoClasses] with the built-in pseudo-classes
+    pub fn builtin() -> Self {
+        let mut classes = Self::new();
+        for (mark, name, position) in BUILTIN_PSEUDO_CLASSES {
+            classes.add(PseudoClass::new(mark, name, *position));
+        }
+        classes
+    }
+
+    /// Adds a pseudo-class, replacing any existing pseudo-class with the same mark
+    ///
+    /// Marks that are empty or contain digits are ignored, so pseudo-classes never collide with DDC numbers.
+    ///
+    /// # Arguments
+    ///
+    /// - `class` (`PseudoClass`) - The pseudo-class to add
+    pub fn add(&mut self, class: PseudoClass) {
+        if class.mark.is_empty() || class.mark.chars().any(|c| c.is_ascii_digit()) {
+            return;
+        }
+
+        self.classes.retain(|existing| existing.mark != class.mark);
+        self.classes.push(class);
+    }
+
+    /// Gets a pseudo-class by its mark (case-insensitive)
+    ///
+    /// # Arguments
+    ///
+    /// - `mark` (`impl AsRef<str>`) - Mark to search for
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&PseudoClass>` - The matching [PseudoClass], if any
+    pub fn get(&self, mark: impl AsRef<str>) -> Option<&PseudoClass> {
+        let mark = mark.as_ref().trim().to_uppercase();
+        self.classes.iter().find(|class| class.mark == mark)
+    }
+
+    /// Gets all the registered pseudo-classes
+    ///
+    /// # Returns
+    ///
+    /// - `&[PseudoClass]` - Registered pseudo-classes, in registration order
+    pub fn classes(&self) -> &[PseudoClass] {
+        &self.classes
+    }
+}
+
+/// Shelving mark of an item: either a DDC class or a pseudo-class
+///
+/// Shelving marks are ordered as they're shelved: pseudo-classes shelved before numbers (by mark), then DDC classes (in shelf order), then pseudo-classes shelved after numbers (by mark).
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "kind", content = "value", rename_all = "snake_case"))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+pub enum ShelfMark {
+    /// A DDC class
+    Class(Class),
+
+    /// A pseudo-class
+    Pseudo(PseudoClass),
+}
+
+impl ShelfMark {
+    fn tier(&self) -> u8 {
+        match self {
+            ShelfMark::Pseudo(PseudoClass { position: ShelfPosition::BeforeNumbers, .. }) => 0,
+            ShelfMark::Class(_) => 1,
+            ShelfMark::Pseudo(PseudoClass { position: ShelfPosition::AfterNumbers, .. }) => 2,
+        }
+    }
+}
+
+impl std::hash::Hash for ShelfMark {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        match self {
+            ShelfMark::Class(class) => class.code.hash(state),
+            ShelfMark::Pseudo(class) => class.mark.hash(state),
+        }
+    }
+}
+
+impl PartialEq for ShelfMark {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for ShelfMark {}
+
+impl PartialOrd for ShelfMark {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ShelfMark {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.tier().cmp(&other.tier()).then_with(|| match (self, other) {
+            (ShelfMark::Class(a), ShelfMark::Class(b)) => a.cmp(b),
+            (ShelfMark::Pseudo(a), ShelfMark::Pseudo(b)) => a.mark.cmp(&b.mark),
+            _ => Ordering::Equal,
+        })
+    }
+}
+
+impl Dewey {
+    /// Gets a snapshot of the pseudo-classes of this dataset
+    ///
+    /// # Returns
+    ///
+    /// - `PseudoClasses` - The currently registered [PseudoClasses] (the built-in set unless replaced)
+    pub fn pseudo_classes(&self) -> PseudoClasses {
+        self.dataset().pseudo_classes.read().unwrap().clone()
+    }
+
+    /// Gets a registered pseudo-class by its mark (case-insensitive), without cloning the whole set
+    pub(crate) fn pseudo_class(&self, mark: &str) -> Option<PseudoClass> {
+        self.dataset().pseudo_classes.read().unwrap().get(mark).cloned()
+    }
+
+    /// Registers an additional pseudo-class with this dataset, see [PseudoClasses::add]
+    ///
+    /// Like arrangement policies, pseudo-classes belong to the dataset, so they're shared by every handle to it (ie by all [Dewey::standard] handles).
+    ///
+    /// # Arguments
+    ///
+    /// - `class` (`PseudoClass`) - The pseudo-class to register (ie `PseudoClass::new("REF", "Reference", ShelfPosition::BeforeNumbers)`)
+    pub fn register_pseudo_class(&self, class: PseudoClass) {
+        self.dataset().pseudo_classes.write().unwrap().add(class);
+    }
+
+    /// Replaces the pseudo-classes of this dataset
+    ///
+    /// # Arguments
+    ///
+    /// - `classes` (`PseudoClasses`) - The new set (use [PseudoClasses::new] to disable pseudo-classes entirely)
+    pub fn set_pseudo_classes(&self, classes: PseudoClasses) {
+        *self.dataset().pseudo_classes.write().unwrap() = classes;
+    }
+
+    /// Parses a shelving mark, which is either a registered pseudo-class mark (case-insensitive) or a DDC code
+    ///
+    /// # Arguments
+    ///
+    /// - `input` (`impl AsRef<str>`) - Mark (ie `FIC`) or code (ie `813`) to parse
+    ///
+    /// # Returns
+    ///
+    /// - `Option<ShelfMark>` - The parsed [ShelfMark], or [None] if the input is neither a registered mark nor an existing class
+    pub fn parse_shelf_mark(&self, input: impl AsRef<str>) -> Option<ShelfMark> {
+        let input = input.as_ref().trim();
+        match self.pseudo_class(input) {
+            Some(class) => Some(ShelfMark::Pseudo(class)),
+            None if is_valid_code(input.trim_end_matches('X')) => self.get_class(input).map(ShelfMark::Class),
+            None => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_shelf_marks() {
+        let dewey = Dewey::standard();
+        let mut marks = ["fic", "813", "E", "B", "0"]
+            .iter()
+            .map(|input| dewey.parse_shelf_mark(input).unwrap())
+            .collect::<Vec<_>>();
+        marks.sort();
+
+        let sorted = marks
+            .iter()
+            .map(|mark| match mark {
+                ShelfMark::Class(class) => class.code.as_ref(),
+                ShelfMark::Pseudo(class) => class.mark.as_str(),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(sorted, vec!["E", "0", "813", "B", "FIC"]);
+
+        let mut classes = ["516", "51", "5", "510"].map(|code| dewey.parse_shelf_mark(code).unwrap());
+        classes.sort();
+        assert_eq!(classes.iter().map(|mark| match mark {
+            ShelfMark::Class(class) => class.code.as_ref(),
+            ShelfMark::Pseudo(class) => class.mark.as_str(),
+        }).collect::<Vec<_>>(), ["5", "51", "510", "516"], "Expected classes in shelf order");
+        assert!(dewey.parse_shelf_mark("XYZ").is_none(), "Expected an unknown mark");
+
+        let mut classes = PseudoClasses::new();
+        classes.add(PseudoClass::new("ref", "Reference", ShelfPosition::BeforeNumbers));
+        classes.add(PseudoClass::new("R2", "Invalid", ShelfPosition::BeforeNumbers));
+        assert_eq!(classes.classes().len(), 1, "Marks containing digits should be ignored");
+        assert_eq!(classes.get("REF").unwrap().name, "Reference");
+
+        let local = Dewey::builder().include('0'..='9').build().unwrap();
+        local.register_pseudo_class(PseudoClass::new("ref", "Reference", ShelfPosition::BeforeNumbers));
+        assert!(matches!(local.parse_shelf_mark("REF"), Some(ShelfMark::Pseudo(_))));
+        assert!(dewey.parse_shelf_mark("REF").is_none(), "Expected pseudo-classes to stay with their dataset");
+    }
+}
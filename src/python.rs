@@ -0,0 +1,185 @@
+//! Python bindings for the standard dataset, generated with `pyo3`
+//!
+//! Build & install the module with `maturin develop --release` (or a wheel with `maturin build --release`), which uses the `pyproject.toml` at the root of the crate to enable this feature. Classes are returned as immutable `Class` objects:
+//!
+//! ```python
+//! import dewey_decimal
+//!
+//! geometry = dewey_decimal.get_class("516")
+//! print(geometry.name, geometry.parent_code)  # Geometry 51
+//! print([child.code for child in dewey_decimal.children("51")])
+//! print(dewey_decimal.search("geometry", limit=5))
+//! ```
+
+use pyo3::{ basic::CompareOp, prelude::* };
+
+use crate::{ Class, Dewey };
+
+/// Class exposed to Python as `dewey_decimal.Class`
+#[pyclass(name = "Class", module = "dewey_decimal", frozen)]
+#[derive(Clone)]
+pub struct PythonClass(pub Class);
+
+#[pymethods]
+impl PythonClass {
+    /// Code of the class, trimmed (ie `51`)
+    #[getter]
+    fn code(&self) -> &str {
+        &self.0.code
+    }
+
+    /// Code of the class, in the crate-wide [CodeStyle](crate::CodeStyle), see [Class::display_code]
+    #[getter]
+    fn display_code(&self) -> String {
+        self.0.display_code()
+    }
+
+    /// Name of the class
+    #[getter]
+    fn name(&self) -> &str {
+        &self.0.name
+    }
+
+    /// Whether the class has children
+    #[getter]
+    fn has_children(&self) -> bool {
+        self.0.has_children
+    }
+
+    /// Code of the parent class, or `None` for main classes
+    #[getter]
+    fn parent_code(&self) -> Option<&str> {
+        self.0.parent_code()
+    }
+
+    /// Gets the parent class, see [Dewey::get_parent]
+    fn parent(&self) -> Option<PythonClass> {
+        Dewey::standard().get_parent(&self.0.code).map(PythonClass)
+    }
+
+    /// Gets the direct children of the class, see [Dewey::children_of]
+    fn children(&self) -> Vec<PythonClass> {
+        children(&self.0.code)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Class(code={:?}, name={:?})", self.0.code, self.0.name)
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __richcmp__(&self, other: PyRef<'_, PythonClass>, op: CompareOp) -> bool {
+        op.matches(self.0.cmp(&other.0))
+    }
+
+    fn __hash__(&self) -> u64 {
+        use std::hash::{ DefaultHasher, Hash, Hasher };
+
+        let mut hasher = DefaultHasher::new();
+        self.0.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+/// Gets a class by code, see [Dewey::get_class]
+///
+/// # Arguments
+///
+/// - `code` (`&str`) - Code of the class (ie `516`)
+///
+/// # Returns
+///
+/// - `Option<PythonClass>` - The class, or `None` if it doesn't exist
+#[pyfunction]
+pub fn get_class(code: &str) -> Option<PythonClass> {
+    Dewey::standard().get_class(code).map(PythonClass)
+}
+
+/// Gets the direct children of a class, see [Dewey::children_of]
+///
+/// # Arguments
+///
+/// - `code` (`&str`) - Code of the parent class, or an empty string for the main classes
+///
+/// # Returns
+///
+/// - `Vec<PythonClass>` - Child classes, which is empty if the class doesn't exist
+#[pyfunction]
+pub fn children(code: &str) -> Vec<PythonClass> {
+    Dewey::standard().children_of(code).iter().cloned().map(PythonClass).collect()
+}
+
+/// Gets the parent of a class, see [Dewey::get_parent]
+///
+/// # Arguments
+///
+/// - `code` (`&str`) - Code of the class
+///
+/// # Returns
+///
+/// - `Option<PythonClass>` - The parent class, or `None` for main classes & unknown codes
+#[pyfunction]
+pub fn parent(code: &str) -> Option<PythonClass> {
+    Dewey::standard().get_parent(code).map(PythonClass)
+}
+
+/// Searches class names by keyword, see [Dewey::search]
+///
+/// # Arguments
+///
+/// - `query` (`&str`) - Keywords to search for
+/// - `limit` (`Option<usize>`) - Maximum number of results
+///
+/// # Returns
+///
+/// - `Vec<PythonClass>` - Matching classes, best first
+#[pyfunction]
+#[pyo3(signature = (query, limit = None))]
+pub fn search(query: &str, limit: Option<usize>) -> Vec<PythonClass> {
+    let mut results = Dewey::standard().search(query);
+    if let Some(limit) = limit {
+        results.truncate(limit);
+    }
+    results.into_iter().map(PythonClass).collect()
+}
+
+/// The `dewey_decimal` Python module
+#[pymodule]
+fn dewey_decimal(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PythonClass>()?;
+    module.add_function(wrap_pyfunction!(get_class, module)?)?;
+    module.add_function(wrap_pyfunction!(children, module)?)?;
+    module.add_function(wrap_pyfunction!(parent, module)?)?;
+    module.add_function(wrap_pyfunction!(search, module)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use pyo3::types::PyDict;
+
+    use super::*;
+
+    #[test]
+    fn test_python() {
+        Python::initialize();
+        Python::attach(|py| {
+            let module = PyModule::new(py, "dewey_decimal").unwrap();
+            dewey_decimal(&module).unwrap();
+            let globals = PyDict::new(py);
+            globals.set_item("dewey_decimal", module).unwrap();
+
+            let run = |code: &str| py.eval(&std::ffi::CString::new(code).unwrap(), Some(&globals), None).unwrap().to_string();
+            assert_eq!(run("dewey_decimal.get_class('516').name"), "Geometry");
+            assert_eq!(run("dewey_decimal.get_class('516').parent().code"), "51");
+            assert_eq!(run("dewey_decimal.parent('516') == dewey_decimal.get_class('51')"), "True");
+            assert_eq!(run("dewey_decimal.get_class('008')"), "None");
+            assert_eq!(run("len(dewey_decimal.children(''))"), "10");
+            assert_eq!(run("[child.code for child in dewey_decimal.children('51')] == [child.code for child in dewey_decimal.get_class('51').children()]"), "True");
+            assert_eq!(run("repr(dewey_decimal.search('geometry', limit=1)[0])"), "Class(code=\"516\", name=\"Geometry\")");
+            assert_eq!(run("len({dewey_decimal.get_class('5'), dewey_decimal.children('')[5]})"), "1");
+        });
+    }
+}
@@ -0,0 +1,173 @@
+use crate::{ search, Class, Dewey, VisitControl };
+
+/// Builder for filtered queries over the class hierarchy
+///
+/// Queries are executed in a single pass over the trie, pruning subtrees beyond [DeweyQuery::max_depth] and stopping as soon as [DeweyQuery::limit] is reached. They run against the dataset of the handle they were started from (see [Dewey::query]), or the standard dataset for [DeweyQuery::new].
+///
+/// ```rust
+/// use dewey_decimal::{ Dewey, DeweyQuery };
+///
+/// let results = DeweyQuery::new().within("6").name_contains("medicine").max_depth(2).limit(5).run();
+/// assert!(results.iter().all(|class| class.code.starts_with('6')));
+///
+/// let science = Dewey::builder().include(['5']).build().unwrap();
+/// assert!(science.query().max_depth(0).run().iter().all(|class| class.code == "5"));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct DeweyQuery {
+    dewey: Dewey,
+    within: String,
+    name_contains: Option<String>,
+    max_depth: Option<usize>,
+    limit: Option<usize>,
+}
+
+impl DeweyQuery {
+    /// Creates a query matching every class of the standard dataset, like `Dewey::standard().query()`
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - A new, unfiltered [DeweyQuery]
+    pub fn new() -> Self {
+        Dewey::standard().query()
+    }
+
+    /// Restricts the query to a subtree (including its root)
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code of the subtree's root
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The updated [DeweyQuery]
+    pub fn within(mut self, code: impl AsRef<str>) -> Self {
+        self.within = code.as_ref().to_string();
+        self
+    }
+
+    /// Restricts the query to classes whose names contain the provided text (case-insensitive), consulting [Dewey::synonyms]
+    ///
+    /// # Arguments
+    ///
+    /// - `text` (`impl AsRef<str>`) - Text to search for
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The updated [DeweyQuery]
+    pub fn name_contains(mut self, text: impl AsRef<str>) -> Self {
+        self.name_contains = Some(text.as_ref().to_string());
+        self
+    }
+
+    /// Restricts the query to classes at most `depth` levels below the subtree's root (see [Dewey::walk])
+    ///
+    /// # Arguments
+    ///
+    /// - `depth` (`usize`) - Maximum depth, where the root of [DeweyQuery::within] is `0`
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The updated [DeweyQuery]
+    pub fn max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = Some(depth);
+        self
+    }
+
+    /// Limits the number of results
+    ///
+    /// # Arguments
+    ///
+    /// - `limit` (`usize`) - Maximum number of classes to return
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The updated [DeweyQuery]
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Executes the query
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<Class>` - [Vec] of matching [Class] instances, in shelf order
+    pub fn run(&self) -> Vec<Class> {
+        let dewey = self.dewey;
+        let variants = self.name_contains.as_ref().map(|text| dewey.synonyms().expand(text));
+        let mut results = Vec::new();
+
+        if self.limit == Some(0) {
+            return results;
+        }
+
+        dewey.walk(&self.within, |class, depth| {
+            let name_matches = variants.as_ref().is_none_or(|variants| {
+                let name = search::fold(&class.name);
+                variants.iter().any(|variant| name.contains(variant.as_str()))
+            });
+
+            if name_matches {
+                results.push(class.clone());
+                if self.limit.is_some_and(|limit| results.len() >= limit) {
+                    return VisitControl::Stop;
+                }
+            }
+
+            if self.max_depth.is_some_and(|max_depth| depth >= max_depth) {
+                VisitControl::SkipChildren
+            } else {
+                VisitControl::Continue
+            }
+        });
+
+        results
+    }
+}
+
+impl Dewey {
+    /// Starts building a filtered query over this dataset, see [DeweyQuery]
+    ///
+    /// # Returns
+    ///
+    /// - `DeweyQuery` - A new, unfiltered [DeweyQuery]
+    pub fn query(&self) -> DeweyQuery {
+        DeweyQuery { dewey: *self, within: String::new(), name_contains: None, max_depth: None, limit: None }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_query() {
+        let divisions = DeweyQuery::new().within("6").max_depth(1).run();
+        assert_eq!(divisions.len(), 11, "Expected the root and its divisions");
+
+        let limited = DeweyQuery::new().within("6").limit(3).run();
+        assert_eq!(limited.iter().map(|c| c.code.as_ref()).collect::<Vec<_>>(), vec!["6", "60", "600"]);
+
+        let named = DeweyQuery::new().within("8").name_contains("poetry").run();
+        assert!(!named.is_empty(), "Expected matches");
+        assert!(named.iter().all(|c| c.code.starts_with('8') && c.name.to_lowercase().contains("poetry")));
+
+        for text in ["information & general", "Information and General"] {
+            let named = DeweyQuery::new().name_contains(text).run();
+            assert!(named.iter().any(|c| c.code == "0"), "Expected {text} to match captions with an ampersand");
+        }
+    }
+
+    #[test]
+    fn test_query_handle() {
+        let science = Dewey::builder().source(crate::DeweySource::Entries(vec![
+            crate::dataset::Entry::new("5", "Science", None),
+            crate::dataset::Entry::new("51", "Local maths", Some("5")),
+        ])).build().unwrap();
+        let codes = science.query().run().into_iter().map(|class| class.code).collect::<Vec<_>>();
+        assert_eq!(codes, ["5", "51"], "Expected the handle's dataset");
+        assert_eq!(science.query().name_contains("local").run()[0].name, "Local maths");
+        assert!(DeweyQuery::new().name_contains("local maths").run().is_empty(), "Expected the standard dataset");
+    }
+}
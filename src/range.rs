@@ -0,0 +1,58 @@
+use std::ops::{ Bound, RangeBounds };
+
+use crate::{ Class, Dewey };
+
+/// Gets the key a code sorts by numerically, padding the integer part to three digits (ie `51` to `510`, `516.3` stays `516.3`)
+pub(crate) fn numeric_key(code: &str) -> String {
+    let code = code.trim().trim_end_matches('X');
+    let (integer, fraction) = code.split_once('.').map_or((code, None), |(integer, fraction)| (integer, Some(fraction)));
+    match fraction {
+        Some(fraction) => format!("{integer:0<3}.{fraction}"),
+        None => format!("{integer:0<3}"),
+    }
+}
+
+impl Dewey {
+    /// Gets all classes whose codes fall in a numeric span, ie `"510".."520"`
+    ///
+    /// Codes are compared as numbers, so shorter codes stand for their padded number (`51` is `510`, and falls in `"510".."520"`), and decimal codes fall between their integer neighbours (`516.3` is in `"516"..="517"`, but not in `"510"..="516"`).
+    ///
+    /// # Arguments
+    ///
+    /// - `range` (`impl RangeBounds<T>`) - Range of codes, ie `"510".."520"` or `"500"..="599"`
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<Class>` - [Vec] of [Class] instances in the range, in shelf order
+    pub fn range<T: AsRef<str>>(&self, range: impl RangeBounds<T>) -> Vec<Class> {
+        let bound = |bound: Bound<&T>| bound.map(|code| numeric_key(code.as_ref()));
+        let range = (bound(range.start_bound()), bound(range.end_bound()));
+        self.all_ref()
+            .filter(|class| range.contains(&numeric_key(&class.code)))
+            .cloned()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_range() {
+        let dewey = Dewey::standard();
+        let codes = |classes: Vec<Class>| classes.into_iter().map(|class| class.code).collect::<Vec<_>>();
+
+        let mathematics = codes(dewey.range("510".."520"));
+        assert_eq!(mathematics, codes(dewey.get_matches("51")), "Expected the whole division");
+        assert_eq!(codes(dewey.range("510"..="519")), mathematics);
+        assert_eq!(codes(dewey.range("51X"..="519")), mathematics, "Expected padded bounds");
+        assert_eq!(codes(dewey.range("515"..="516")), ["515", "516"]);
+        assert!(codes(dewey.range("990"..)).iter().all(|code| numeric_key(code).as_str() >= "990"));
+        assert!(dewey.range("520".."510").is_empty(), "Expected empty ranges to match nothing");
+        assert_eq!(dewey.range::<&str>(..).len(), dewey.all().len());
+
+        assert_eq!(numeric_key("5"), "500");
+        assert_eq!(numeric_key("516.3"), "516.3");
+    }
+}
@@ -0,0 +1,224 @@
+use std::{ collections::BTreeMap, sync::{ Arc, RwLock } };
+
+use crate::{ Dewey, Overlay, OverlaySnapshot };
+
+/// Registry of named [Overlay]s & [Dewey] handles (ie one per tenant or branch library)
+///
+/// Overlays layer local changes over the standard classes, while handles point to whole datasets (ie loaded with a [DeweyBuilder](crate::DeweyBuilder)). Both are registered under separate names.
+///
+/// Cloning a [Registry] is cheap, and every clone refers to the same overlays & handles.
+///
+/// ```rust
+/// use dewey_decimal::{ Dewey, Registry };
+///
+/// let registry = Registry::new();
+/// registry.get_or_create("north-branch").add("0081", "Local history room").unwrap();
+///
+/// let handle = registry.clone();
+/// assert!(handle.snapshot("north-branch").unwrap().get_class("0081").is_some());
+/// assert!(handle.snapshot("south-branch").is_none());
+///
+/// let science = Dewey::builder().include(['5']).build().unwrap();
+/// registry.insert_dataset("science-library", science);
+/// assert!(handle.dataset("science-library").unwrap().get_class("6").is_none());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Registry {
+    overlays: Arc<RwLock<BTreeMap<String, Arc<Overlay>>>>,
+    datasets: Arc<RwLock<BTreeMap<String, Dewey>>>,
+}
+
+impl Registry {
+    /// Creates an empty registry
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - A new [Registry]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers an overlay under the provided name, replacing any existing overlay with that name
+    ///
+    /// # Arguments
+    ///
+    /// - `name` (`impl AsRef<str>`) - Name of the overlay
+    /// - `overlay` (`Overlay`) - The [Overlay] to register
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Arc<Overlay>>` - The previously registered [Overlay], if any
+    pub fn insert(&self, name: impl AsRef<str>, overlay: Overlay) -> Option<Arc<Overlay>> {
+        self.overlays.write().unwrap().insert(name.as_ref().to_string(), Arc::new(overlay))
+    }
+
+    /// Gets the overlay registered under the provided name
+    ///
+    /// # Arguments
+    ///
+    /// - `name` (`impl AsRef<str>`) - Name of the overlay
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Arc<Overlay>>` - The registered [Overlay], or [None] if not found
+    pub fn get(&self, name: impl AsRef<str>) -> Option<Arc<Overlay>> {
+        self.overlays.read().unwrap().get(name.as_ref()).cloned()
+    }
+
+    /// Gets the overlay registered under the provided name, registering an empty one if not found
+    ///
+    /// # Arguments
+    ///
+    /// - `name` (`impl AsRef<str>`) - Name of the overlay
+    ///
+    /// # Returns
+    ///
+    /// - `Arc<Overlay>` - The registered [Overlay]
+    pub fn get_or_create(&self, name: impl AsRef<str>) -> Arc<Overlay> {
+        if let Some(overlay) = self.get(&name) {
+            return overlay;
+        }
+
+        self.overlays
+            .write()
+            .unwrap()
+            .entry(name.as_ref().to_string())
+            .or_default()
+            .clone()
+    }
+
+    /// Gets a consistent view of the overlay registered under the provided name
+    ///
+    /// # Arguments
+    ///
+    /// - `name` (`impl AsRef<str>`) - Name of the overlay
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Arc<OverlaySnapshot>>` - The current [OverlaySnapshot], or [None] if not found
+    pub fn snapshot(&self, name: impl AsRef<str>) -> Option<Arc<OverlaySnapshot>> {
+        self.get(name).map(|overlay| overlay.snapshot())
+    }
+
+    /// Removes the overlay registered under the provided name
+    ///
+    /// # Arguments
+    ///
+    /// - `name` (`impl AsRef<str>`) - Name of the overlay
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Arc<Overlay>>` - The removed [Overlay], if any
+    pub fn remove(&self, name: impl AsRef<str>) -> Option<Arc<Overlay>> {
+        self.overlays.write().unwrap().remove(name.as_ref())
+    }
+
+    /// Gets the names of all registered overlays
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<String>` - Sorted names of all registered overlays
+    pub fn names(&self) -> Vec<String> {
+        self.overlays.read().unwrap().keys().cloned().collect()
+    }
+
+    /// Registers a dataset handle under the provided name, replacing any existing handle with that name
+    ///
+    /// # Arguments
+    ///
+    /// - `name` (`impl AsRef<str>`) - Name of the dataset
+    /// - `dewey` (`Dewey`) - Handle to the dataset (ie from a [DeweyBuilder](crate::DeweyBuilder))
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Dewey>` - The previously registered handle, if any
+    pub fn insert_dataset(&self, name: impl AsRef<str>, dewey: Dewey) -> Option<Dewey> {
+        self.datasets.write().unwrap().insert(name.as_ref().to_string(), dewey)
+    }
+
+    /// Gets the dataset handle registered under the provided name
+    ///
+    /// # Arguments
+    ///
+    /// - `name` (`impl AsRef<str>`) - Name of the dataset
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Dewey>` - The registered handle, or [None] if not found
+    pub fn dataset(&self, name: impl AsRef<str>) -> Option<Dewey> {
+        self.datasets.read().unwrap().get(name.as_ref()).copied()
+    }
+
+    /// Removes the dataset handle registered under the provided name
+    ///
+    /// The dataset itself lives on, so handles obtained before stay valid.
+    ///
+    /// # Arguments
+    ///
+    /// - `name` (`impl AsRef<str>`) - Name of the dataset
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Dewey>` - The removed handle, if any
+    pub fn remove_dataset(&self, name: impl AsRef<str>) -> Option<Dewey> {
+        self.datasets.write().unwrap().remove(name.as_ref())
+    }
+
+    /// Gets the names of all registered dataset handles
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<String>` - Sorted names of all registered dataset handles
+    pub fn dataset_names(&self) -> Vec<String> {
+        self.datasets.read().unwrap().keys().cloned().collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::thread;
+
+    use super::*;
+
+    #[test]
+    fn test_replace_remove() {
+        let registry = Registry::new();
+        assert!(registry.insert("main", Overlay::new()).is_none());
+        registry.get("main").unwrap().add("0081", "Local history").unwrap();
+
+        let previous = registry.insert("main", Overlay::new()).expect("Expected the replaced overlay");
+        assert!(previous.snapshot().get_class("0081").is_some());
+        assert!(registry.snapshot("main").unwrap().get_class("0081").is_none(), "Expected the new overlay");
+
+        assert_eq!(registry.names(), ["main"]);
+        assert!(registry.remove("main").is_some() && registry.remove("main").is_none());
+        assert!(registry.get("main").is_none() && registry.names().is_empty());
+    }
+
+    #[test]
+    fn test_datasets() {
+        let registry = Registry::new();
+        let science = Dewey::builder().include(['5']).build().unwrap();
+        assert!(registry.insert_dataset("science", Dewey::standard()).is_none());
+        assert!(registry.insert_dataset("science", science).is_some(), "Expected the replaced handle");
+
+        let handle = registry.clone().dataset("science").unwrap();
+        assert!(handle.get_class("516").is_some() && handle.get_class("6").is_none());
+        assert_eq!((registry.dataset_names(), registry.names()), (vec!["science".to_string()], Vec::new()), "Expected handles & overlays to be named separately");
+
+        assert!(registry.remove_dataset("science").is_some() && registry.dataset("science").is_none());
+        assert!(handle.get_class("516").is_some(), "Expected removed handles to stay valid");
+    }
+
+    #[test]
+    fn test_concurrent_get_or_create() {
+        let registry = Registry::new();
+        let overlays = thread::scope(|scope| {
+            let handles = (0..8).map(|_| scope.spawn(|| registry.get_or_create("shared"))).collect::<Vec<_>>();
+            handles.into_iter().map(|handle| handle.join().unwrap()).collect::<Vec<_>>()
+        });
+
+        assert!(overlays.iter().all(|overlay| Arc::ptr_eq(overlay, &overlays[0])), "Expected a single overlay to be created");
+        assert_eq!(registry.names(), ["shared"]);
+    }
+}
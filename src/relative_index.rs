@@ -0,0 +1,301 @@
+//! A curated Relative Index, mapping subject terms to DDC numbers like the index volume of the printed schedules
+
+use crate::{ search::{ fold, tokenize }, Class, Dewey };
+
+/// Subject terms & the DDC numbers they're classed in
+///
+/// Numbers are given in full, even where the dataset stops at the section (ie `595.799` for bees, found under `595`).
+const RELATIVE_INDEX: &[(&str, &str)] = &[
+    ("Accounting", "657"),
+    ("Aeronautics", "629.13"),
+    ("Africa — history", "960"),
+    ("Agriculture", "630"),
+    ("Algebra", "512"),
+    ("American Civil War", "973.7"),
+    ("American fiction", "813"),
+    ("American football", "796.332"),
+    ("Ancient history", "930"),
+    ("Archaeology", "930.1"),
+    ("Architecture", "720"),
+    ("Arithmetic", "513"),
+    ("Artificial intelligence", "006.3"),
+    ("Asia — history", "950"),
+    ("Astronautics", "629.4"),
+    ("Astronomy", "520"),
+    ("Atlases", "912"),
+    ("Automobiles — engineering", "629.222"),
+    ("Baking", "641.815"),
+    ("Banking", "332.1"),
+    ("Baseball", "796.357"),
+    ("Basketball", "796.323"),
+    ("Beekeeping", "638.1"),
+    ("Bees", "595.799"),
+    ("Bible", "220"),
+    ("Biography", "920"),
+    ("Birds — zoology", "598"),
+    ("Botany", "580"),
+    ("Buddhism", "294.3"),
+    ("Building construction", "690"),
+    ("Calculus", "515"),
+    ("Cancer — medicine", "616.994"),
+    ("Card games", "795.4"),
+    ("Cats — animal husbandry", "636.8"),
+    ("Chemical engineering", "660"),
+    ("Chemistry", "540"),
+    ("Chess", "794.1"),
+    ("Child psychology", "155.4"),
+    ("Chinese language", "495.1"),
+    ("Christianity", "230"),
+    ("Civil engineering", "624"),
+    ("Climate change", "363.73874"),
+    ("Comics", "741.5"),
+    ("Computer graphics", "006.6"),
+    ("Computer networks", "004.6"),
+    ("Computer programming", "005.1"),
+    ("Computer security", "005.8"),
+    ("Cooking", "641.5"),
+    ("Costume", "391"),
+    ("Crime", "364"),
+    ("Customs", "390"),
+    ("Dance", "792.8"),
+    ("Databases", "005.74"),
+    ("Dentistry", "617.6"),
+    ("Diabetes", "616.462"),
+    ("Dictionaries — English language", "423"),
+    ("Dinosaurs", "567.9"),
+    ("Diseases", "616"),
+    ("Dogs — animal husbandry", "636.7"),
+    ("Drawing", "741"),
+    ("Dreams", "154.63"),
+    ("Earthquakes", "551.22"),
+    ("Ecology", "577"),
+    ("Economics", "330"),
+    ("Education", "370"),
+    ("Electrical engineering", "621.3"),
+    ("Electronics", "621.381"),
+    ("Encyclopedias", "030"),
+    ("Engineering", "620"),
+    ("English fiction", "823"),
+    ("English language", "420"),
+    ("Epistemology", "121"),
+    ("Ethics", "170"),
+    ("Etiquette", "395"),
+    ("Europe — history", "940"),
+    ("Evolution", "576.8"),
+    ("Fishes — zoology", "597"),
+    ("Fishing", "799.1"),
+    ("Folklore", "398"),
+    ("French language", "440"),
+    ("Gardening", "635"),
+    ("Genealogy", "929.1"),
+    ("Genetics", "576.5"),
+    ("Geography", "910"),
+    ("Geometry", "516"),
+    ("German language", "430"),
+    ("Golf", "796.352"),
+    ("Grammar — English language", "425"),
+    ("Graphic novels", "741.5"),
+    ("Greek language", "480"),
+    ("Guitars", "787.87"),
+    ("Handicrafts", "745.5"),
+    ("Hinduism", "294.5"),
+    ("Home economics", "640"),
+    ("Horses — animal husbandry", "636.1"),
+    ("Hunting", "799.2"),
+    ("Insects", "595.7"),
+    ("Interior decoration", "747"),
+    ("Internet", "004.678"),
+    ("Investments", "332.6"),
+    ("Islam", "297"),
+    ("Japanese language", "495.6"),
+    ("Journalism", "070"),
+    ("Judaism", "296"),
+    ("Knitting", "746.432"),
+    ("Landscape architecture", "712"),
+    ("Latin language", "470"),
+    ("Law", "340"),
+    ("Library science", "020"),
+    ("Linguistics", "410"),
+    ("Logic", "160"),
+    ("Machine learning", "006.31"),
+    ("Management", "658"),
+    ("Manufacturing", "670"),
+    ("Maps", "912"),
+    ("Marketing", "658.8"),
+    ("Medicine", "610"),
+    ("Metaphysics", "110"),
+    ("Meteorology", "551.5"),
+    ("Microbiology", "579"),
+    ("Military science", "355"),
+    ("Mining engineering", "622"),
+    ("Money", "332.4"),
+    ("Moon", "523.3"),
+    ("Motion pictures", "791.43"),
+    ("Museums", "069"),
+    ("Music", "780"),
+    ("Mythology", "201.3"),
+    ("North America — history", "970"),
+    ("Number theory", "512.7"),
+    ("Nursing", "610.73"),
+    ("Nutrition", "613.2"),
+    ("Operating systems", "005.43"),
+    ("Painting", "750"),
+    ("Pharmacology", "615"),
+    ("Philosophy", "100"),
+    ("Photography", "770"),
+    ("Physics", "530"),
+    ("Pianos", "786.2"),
+    ("Planets", "523.4"),
+    ("Poetry", "808.1"),
+    ("Political science", "320"),
+    ("Probabilities", "519.2"),
+    ("Programming languages", "005.13"),
+    ("Psychiatry", "616.89"),
+    ("Psychology", "150"),
+    ("Public administration", "351"),
+    ("Quantum mechanics", "530.12"),
+    ("Quilting", "746.46"),
+    ("Rare books", "090"),
+    ("Relativity — physics", "530.11"),
+    ("Rhetoric", "808"),
+    ("Robotics", "629.892"),
+    ("Sculpture", "730"),
+    ("Shakespeare, William", "822.33"),
+    ("Soccer", "796.334"),
+    ("Social welfare", "361"),
+    ("Sociology", "301"),
+    ("Solar system", "523.2"),
+    ("South America — history", "980"),
+    ("Spanish language", "460"),
+    ("Sports", "796"),
+    ("Stars — astronomy", "523.8"),
+    ("Statistics — mathematics", "519.5"),
+    ("Surgery", "617"),
+    ("Television", "791.45"),
+    ("Tennis", "796.342"),
+    ("Theater", "792"),
+    ("Thermodynamics", "536.7"),
+    ("Topology", "514"),
+    ("Travel", "910"),
+    ("Volcanoes", "551.21"),
+    ("Weather", "551.6"),
+    ("Whales", "599.5"),
+    ("Wine — home economics", "641.22"),
+    ("Woodworking", "684.08"),
+    ("World War, 1914–1918", "940.3"),
+    ("World War, 1939–1945", "940.53"),
+    ("Zoology", "590"),
+];
+
+/// Whether two words are the same, or the singular & plural of each other (ie `bee` & `bees`)
+fn same_word(a: &str, b: &str) -> bool {
+    a == b || a.strip_suffix('s') == Some(b) || b.strip_suffix('s') == Some(a)
+}
+
+/// Gets the entries of the Relative Index whose term occurs in tokenized text
+///
+/// Qualifiers (ie ` — zoology`) are ignored, and every other word of the term must be among the tokens.
+///
+/// # Arguments
+///
+/// - `tokens` (`&[String]`) - Tokens of the text (see [tokenize])
+///
+/// # Returns
+///
+/// - `impl Iterator<Item = (usize, &'static str)>` - Number of words of each matching term, and its DDC number
+pub(crate) fn terms_within(tokens: &[String]) -> impl Iterator<Item = (usize, &'static str)> + use<'_> {
+    RELATIVE_INDEX.iter().filter_map(|(term, number)| {
+        let words = tokenize(term.split(" — ").next().unwrap_or(term));
+        words
+            .iter()
+            .all(|word| tokens.iter().any(|token| same_word(token, word)))
+            .then_some((words.len(), *number))
+    })
+}
+
+/// Entry of the Relative Index matched by [Dewey::index_lookup]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct IndexEntry {
+    /// Subject term, as printed in the index (ie `Bees`, or `Dogs — animal husbandry` for qualified terms)
+    pub term: String,
+
+    /// Full DDC number of the subject (ie `595.799`)
+    pub number: String,
+
+    /// Most specific class of the dataset the number falls under (ie `595`)
+    pub class: Class,
+}
+
+impl Dewey {
+    /// Looks up a subject term in the built-in Relative Index (ignoring case & diacritics, see [Dewey::search])
+    ///
+    /// Terms equal to the query rank first, then terms starting with it, then terms containing all of its words (ie `history` matches `Europe — history`). Words match on prefixes, so `program` finds `Computer programming`.
+    ///
+    /// # Arguments
+    ///
+    /// - `term` (`impl AsRef<str>`) - Subject term to look up (ie `beekeeping`)
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<IndexEntry>` - Matching [IndexEntry] instances, best first (entries outside this dataset are skipped)
+    pub fn index_lookup(&self, term: impl AsRef<str>) -> Vec<IndexEntry> {
+        let query = fold(term.as_ref());
+        let tokens = tokenize(&query);
+        if tokens.is_empty() {
+            return Vec::new();
+        }
+
+        let mut matches = RELATIVE_INDEX.iter()
+            .filter_map(|(term, number)| {
+                let lowered = fold(term);
+                let words = tokenize(&lowered);
+                let rank = if lowered == query {
+                    0
+                } else if lowered.starts_with(&query) {
+                    1
+                } else if tokens.iter().all(|token| words.iter().any(|word| word.starts_with(token.as_str()))) {
+                    2
+                } else {
+                    return None;
+                };
+                Some((rank, *term, *number))
+            })
+            .collect::<Vec<_>>();
+        matches.sort_by_key(|(rank, term, _)| (*rank, *term));
+
+        matches
+            .into_iter()
+            .filter_map(|(_, term, number)| {
+                self.classify(number).map(|class| IndexEntry { term: term.to_string(), number: number.to_string(), class })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::DdcCode;
+
+    #[test]
+    fn test_relative_index() {
+        let dewey = Dewey::standard();
+        for (term, number) in RELATIVE_INDEX {
+            assert!(DdcCode::new(number).is_ok(), "Invalid number for {term}");
+            assert!(dewey.classify(number).is_some(), "Expected {term} ({number}) to fall under a class");
+        }
+        assert!(RELATIVE_INDEX.windows(2).all(|pair| pair[0].0 < pair[1].0), "Expected the index to be sorted by term");
+
+        let bees = dewey.index_lookup("BEES");
+        assert_eq!((bees[0].term.as_str(), bees[0].number.as_str(), bees[0].class.code.as_ref()), ("Bees", "595.799", "595"));
+        assert_eq!(dewey.index_lookup("beekeeping")[0].number, "638.1");
+
+        let programming = dewey.index_lookup("program");
+        assert_eq!(programming.iter().map(|entry| entry.term.as_str()).collect::<Vec<_>>(), ["Programming languages", "Computer programming"]);
+        assert!(dewey.index_lookup("history").len() >= 5);
+        assert!(dewey.index_lookup("").is_empty() && dewey.index_lookup("zeppelins").is_empty());
+    }
+}
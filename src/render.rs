@@ -0,0 +1,275 @@
+//! Text rendering helpers for terminals and logs
+
+use std::{ borrow::Borrow, io::{ self, Write } };
+
+use crate::{ Class, CodeStyle, Dewey, VisitControl };
+
+/// Column of a table rendered by [table]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Column {
+    /// Code of the class, in the crate-wide [CodeStyle] (see [Dewey::set_code_style])
+    Code,
+
+    /// Code of the class, padded to three digits (ie `500`, `510`, `516`)
+    PaddedCode,
+
+    /// Friendly name of the class
+    Name,
+
+    /// Number of direct children of the class
+    Children,
+}
+
+impl Column {
+    fn title(&self) -> &'static str {
+        match self {
+            Column::Code => "Code",
+            Column::PaddedCode => "DDC",
+            Column::Name => "Name",
+            Column::Children => "Children",
+        }
+    }
+
+    fn is_numeric(&self) -> bool {
+        matches!(self, Column::Children)
+    }
+
+    fn value(&self, class: &Class) -> String {
+        match self {
+            Column::Code => class.display_code(),
+            Column::PaddedCode => CodeStyle::Padded.format(&class.code),
+            Column::Name => class.name.to_string(),
+            Column::Children => class.child_count.to_string(),
+        }
+    }
+}
+
+/// Renders classes as an aligned text table, with a header row
+///
+/// Text columns are left-aligned and numeric columns are right-aligned. Lines have no trailing whitespace.
+///
+/// ```rust
+/// use dewey_decimal::{ render::{ self, Column }, Dewey };
+///
+/// let table = render::table(Dewey::standard().categories(), &[Column::PaddedCode, Column::Name]);
+/// assert!(table.lines().nth(2).unwrap().starts_with("000  "));
+/// ```
+///
+/// # Arguments
+///
+/// - `classes` (`impl IntoIterator<Item = impl Borrow<Class>>`) - Classes to render, one per row
+/// - `columns` (`&[Column]`) - Columns to render, in order
+///
+/// # Returns
+///
+/// - `String` - The rendered table, each line terminated by a newline
+pub fn table(classes: impl IntoIterator<Item = impl Borrow<Class>>, columns: &[Column]) -> String {
+    let rows = classes
+        .into_iter()
+        .map(|class| columns.iter().map(|column| column.value(class.borrow())).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let widths = columns
+        .iter()
+        .enumerate()
+        .map(|(index, column)| {
+            rows.iter()
+                .map(|row| row[index].chars().count())
+                .chain([column.title().len()])
+                .max()
+                .unwrap_or(0)
+        })
+        .collect::<Vec<_>>();
+
+    let render_line = |cells: Vec<String>| {
+        let line = cells
+            .iter()
+            .zip(columns.iter().zip(&widths))
+            .map(|(cell, (column, width))| {
+                if column.is_numeric() { format!("{cell:>width$}") } else { format!("{cell:<width$}") }
+            })
+            .collect::<Vec<_>>()
+            .join("  ");
+        format!("{}\n", line.trim_end())
+    };
+
+    let mut output = render_line(columns.iter().map(|column| column.title().to_string()).collect());
+    output.push_str(&render_line(widths.iter().map(|width| "-".repeat(*width)).collect()));
+    for row in rows {
+        output.push_str(&render_line(row));
+    }
+    output
+}
+
+/// ANSI colors of each main class (`0` through `9`) in listings
+const MAIN_CLASS_COLORS: [&str; 10] = ["31", "32", "33", "34", "35", "36", "91", "92", "93", "94"];
+
+/// Truncates a line to a number of characters, marking the cut with an ellipsis
+fn truncate(line: String, width: Option<usize>) -> String {
+    match width {
+        Some(width) if line.chars().count() > width => {
+            let mut line = line.chars().take(width.saturating_sub(1)).collect::<String>();
+            line.push('…');
+            line
+        }
+        _ => line,
+    }
+}
+
+impl Dewey {
+    /// Writes a subtree as an indented long listing, one class per line, designed for piping into a pager
+    ///
+    /// Each line starts with the class's code padded to three digits, so names always start at the same offset before being indented by two spaces per level. When `color` is set, each line is wrapped in an ANSI color chosen by its main class.
+    ///
+    /// # Arguments
+    ///
+    /// - `writer` (`impl Write`) - Destination of the listing
+    /// - `root` (`impl AsRef<str>`) - Code of the subtree's root (an empty string lists the entire hierarchy)
+    /// - `max_depth` (`Option<usize>`) - Number of levels to list below the root (`Some(0)` lists the root alone, [None] lists the whole subtree)
+    /// - `color` (`bool`) - Whether to color lines with ANSI escape codes
+    ///
+    /// # Returns
+    ///
+    /// - `io::Result<()>` - An error if writing fails (ie the pager was closed)
+    pub fn write_listing(&self, mut writer: impl Write, root: impl AsRef<str>, max_depth: Option<usize>, color: bool) -> io::Result<()> {
+        let root = root.as_ref().trim_matches('X');
+        let mut result = Ok(());
+
+        self.walk(root, |class, depth| {
+            let line = format!("{}  {}{}", CodeStyle::Padded.format(&class.code), "  ".repeat(depth), class.name);
+            let line = match (color, class.code.chars().next().and_then(|digit| digit.to_digit(10))) {
+                (true, Some(digit)) => format!("\x1b[{}m{line}\x1b[0m", MAIN_CLASS_COLORS[digit as usize]),
+                _ => line,
+            };
+
+            result = writeln!(writer, "{line}");
+            match result {
+                Err(_) => VisitControl::Stop,
+                Ok(()) if max_depth.is_some_and(|max_depth| depth >= max_depth) => VisitControl::SkipChildren,
+                Ok(()) => VisitControl::Continue,
+            }
+        });
+        result
+    }
+
+    /// Renders a subtree as an indented long listing, see [Dewey::write_listing]
+    ///
+    /// # Arguments
+    ///
+    /// - `root` (`impl AsRef<str>`) - Code of the subtree's root (an empty string lists the entire hierarchy)
+    /// - `max_depth` (`Option<usize>`) - Number of levels to list below the root ([None] lists the whole subtree)
+    /// - `color` (`bool`) - Whether to color lines with ANSI escape codes
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The rendered listing, each line terminated by a newline
+    pub fn listing(&self, root: impl AsRef<str>, max_depth: Option<usize>, color: bool) -> String {
+        let mut output = Vec::new();
+        self.write_listing(&mut output, root, max_depth, color).expect("Writing to a Vec can't fail");
+        String::from_utf8(output).expect("Listings are valid UTF-8")
+    }
+
+    fn render_branch(&self, output: &mut String, class: &Class, indent: &str, depth: usize, width: Option<usize>) {
+        if depth == 0 {
+            return;
+        }
+
+        let children = self.children_of(&class.code);
+        for (index, child) in children.iter().enumerate() {
+            let (branch, continuation) = if index + 1 == children.len() { ("└── ", "    ") } else { ("├── ", "│   ") };
+            let line = format!("{indent}{branch}{} {}", child.display_code(), child.name);
+            output.push_str(&truncate(line, width));
+            output.push('\n');
+            self.render_branch(output, child, &format!("{indent}{continuation}"), depth - 1, width);
+        }
+    }
+
+    /// Renders a subtree as a text tree drawn with box-drawing characters, ie for CLI output, logs & documentation
+    ///
+    /// Codes are shown in the crate-wide [CodeStyle] (see [Dewey::set_code_style]).
+    ///
+    /// ```rust
+    /// use dewey_decimal::Dewey;
+    ///
+    /// let tree = Dewey::standard().render_tree("5", 1, None).unwrap();
+    /// let mut lines = tree.lines();
+    /// assert_eq!(lines.next(), Some("5 Science"));
+    /// assert!(lines.next().unwrap().starts_with("├── 50 "));
+    /// assert!(lines.last().unwrap().starts_with("└── 59 "));
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code of the subtree's root (an empty string renders every main class as a root)
+    /// - `depth` (`usize`) - Number of levels to render below the root (`0` renders the root alone)
+    /// - `width` (`Option<usize>`) - Maximum number of characters per line, longer lines being cut with an ellipsis
+    ///
+    /// # Returns
+    ///
+    /// - `Option<String>` - The rendered tree, each line terminated by a newline, or [None] if the class doesn't exist
+    pub fn render_tree(&self, code: impl AsRef<str>, depth: usize, width: Option<usize>) -> Option<String> {
+        let code = code.as_ref();
+        let roots = if code.trim().is_empty() { self.children_of("") } else { std::slice::from_ref(self.class_ref(code)?) };
+
+        let mut output = String::new();
+        for root in roots {
+            output.push_str(&truncate(format!("{} {}", root.display_code(), root.name), width));
+            output.push('\n');
+            self.render_branch(&mut output, root, "", depth, width);
+        }
+        Some(output)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_table() {
+        let classes = ["5", "51", "516"].map(|code| Dewey::standard().get_class(code).unwrap());
+        let table = table(&classes, &[Column::Code, Column::PaddedCode, Column::Children]);
+        assert_eq!(table, format!(
+            "Code  DDC  Children\n----  ---  --------\n5     500  {:>8}\n51    510  {:>8}\n516   516  {:>8}\n",
+            classes[0].child_count,
+            classes[1].child_count,
+            classes[2].child_count
+        ));
+        assert_eq!(super::table(Vec::<Class>::new(), &[Column::Name]), "Name\n----\n");
+    }
+
+    #[test]
+    fn test_listing() {
+        let dewey = Dewey::standard();
+        let listing = dewey.listing("51", None, false);
+        let mathematics = dewey.get_class("51").unwrap();
+        assert!(listing.starts_with(&format!("510  {}\n", mathematics.name)), "Expected the root without indentation");
+        assert!(listing.lines().skip(1).all(|line| line[5..].starts_with("  ")), "Expected indented children");
+        assert_eq!(listing.lines().count(), Dewey::standard().get_matches("51").len());
+
+        let colored = dewey.listing("", Some(0), true);
+        assert!(colored.starts_with("\x1b[31m000  ") && colored.contains("\x1b[94m900  "), "Expected colors by main class");
+        assert_eq!(colored.lines().count(), Dewey::standard().categories().len(), "Expected the main classes alone");
+        assert_eq!(dewey.listing("51", Some(1), false).lines().count(), 1 + dewey.children_of("51").len());
+
+        let science = Dewey::builder().include(['5']).build().unwrap();
+        assert_eq!(science.listing("", None, false).lines().count(), science.all().len(), "Expected the handle's dataset");
+    }
+
+    #[test]
+    fn test_render_tree() {
+        let dewey = Dewey::standard();
+        let tree = dewey.render_tree("51", 1, None).unwrap();
+        assert_eq!(tree.lines().count(), 1 + dewey.children_of("51").len());
+        assert!(tree.lines().skip(1).take(dewey.children_of("51").len() - 1).all(|line| line.starts_with("├── ")));
+
+        let nested = dewey.render_tree("0", 2, None).unwrap();
+        assert!(nested.lines().any(|line| line.starts_with("│   ├── 001 ")), "Expected continued branches");
+        assert!(nested.lines().any(|line| line.starts_with("    └── 09")), "Expected the last branch to end");
+        assert_eq!(nested.lines().count(), dewey.get_matches("0").len());
+
+        let narrow = dewey.render_tree("", 0, Some(12)).unwrap();
+        assert_eq!(narrow.lines().count(), dewey.categories().len(), "Expected one root per main class");
+        assert!(narrow.lines().all(|line| line.chars().count() <= 12) && narrow.contains('…'), "Expected long lines to be cut");
+        assert!(dewey.render_tree("008", 1, None).is_none());
+    }
+}
@@ -0,0 +1,187 @@
+use std::collections::HashMap;
+
+use aho_corasick::{ AhoCorasick, AhoCorasickBuilder, MatchKind };
+
+use crate::{ search, Class, Dewey };
+
+/// Automaton over every (assigned) class name of a dataset, as written & folded (see [search::fold])
+#[derive(Debug)]
+pub(crate) struct Scanner {
+    automaton: AhoCorasick,
+    classes: Vec<Vec<Class>>,
+}
+
+impl Scanner {
+    pub(crate) fn new(dewey: &Dewey) -> Self {
+        let mut names: Vec<(String, Vec<Class>)> = Vec::new();
+        let mut positions: HashMap<String, usize> = HashMap::new();
+        for class in dewey.classes().iter().map(|item: (Vec<u8>, &Class)| item.1) {
+            if search::is_unused(&class.name) {
+                continue;
+            }
+
+            let name = class.name.to_lowercase();
+            let folded = search::fold(&class.name);
+            let variants = if folded == name { vec![name] } else { vec![name, folded] };
+            for name in variants {
+                match positions.get(&name) {
+                    Some(&position) => names[position].1.push(class.clone()),
+                    None => {
+                        positions.insert(name.clone(), names.len());
+                        names.push((name, vec![class.clone()]));
+                    }
+                }
+            }
+        }
+
+        let automaton = AhoCorasickBuilder::new()
+            .ascii_case_insensitive(true)
+            .match_kind(MatchKind::Standard)
+            .build(names.iter().map(|(name, _)| name))
+            .expect("Failed to build class name automaton");
+
+        Self {
+            automaton,
+            classes: names.into_iter().map(|(_, classes)| classes).collect(),
+        }
+    }
+}
+
+/// Occurrence of a class name within a document
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct TextMatch {
+    /// The [Class] whose name was found
+    pub class: Class,
+
+    /// Byte offset of the start of the occurrence
+    pub start: usize,
+
+    /// Byte offset of the end of the occurrence (exclusive)
+    pub end: usize,
+}
+
+/// Class suggested for a document by [Dewey::classify_documents]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct ClassCandidate {
+    /// The suggested [Class]
+    pub class: Class,
+
+    /// Number of times the class's name occurs in the document
+    pub occurrences: usize,
+
+    /// Ranking score (occurrences, weighted towards more specific classes)
+    pub score: f64,
+}
+
+fn is_boundary(c: Option<char>) -> bool {
+    c.is_none_or(|c| !c.is_alphanumeric())
+}
+
+impl Dewey {
+    /// Finds every occurrence of a class name in a document (case-insensitive, on word boundaries)
+    ///
+    /// Names also match without their diacritics & with `and` in place of `&` (ie `information and general works`). Uses an Aho-Corasick automaton over all class names, built the first time this is called. Classes marked as unassigned or no longer used are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// - `document` (`impl AsRef<str>`) - Text to scan
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<TextMatch>` - [Vec] of occurrences, ordered by position (overlapping occurrences are all included)
+    pub fn scan_text(&self, document: impl AsRef<str>) -> Vec<TextMatch> {
+        let document = document.as_ref();
+        let scanner = self.dataset().scanner.get_or_init(|| Scanner::new(self));
+
+        let mut matches = Vec::new();
+        for found in scanner.automaton.find_overlapping_iter(document) {
+            let (start, end) = (found.start(), found.end());
+            if !is_boundary(document[..start].chars().next_back()) || !is_boundary(document[end..].chars().next()) {
+                continue;
+            }
+
+            for class in &scanner.classes[found.pattern().as_usize()] {
+                matches.push(TextMatch { class: class.clone(), start, end });
+            }
+        }
+
+        matches.sort_by(|a, b| (a.start, a.end, &a.class.code).cmp(&(b.start, b.end, &b.class.code)));
+        matches
+    }
+
+    /// Ranks candidate classes for each document of a corpus, based on the class names occurring in it
+    ///
+    /// Each document is scanned once with the automaton used by [Dewey::scan_text]. Every occurrence of a class's name counts towards its score, weighted by the class's depth so that specific classes outrank broad ones.
+    ///
+    /// # Arguments
+    ///
+    /// - `documents` (`impl IntoIterator<Item = impl AsRef<str>>`) - Documents to classify
+    ///
+    /// # Returns
+    ///
+    /// - `impl Iterator<Item = Vec<ClassCandidate>>` - Lazily computed candidates for each document (in input order), best first
+    pub fn classify_documents<D: AsRef<str>>(
+        &self,
+        documents: impl IntoIterator<Item = D>
+    ) -> impl Iterator<Item = Vec<ClassCandidate>> {
+        let dewey = *self;
+        documents.into_iter().map(move |document| {
+            let mut candidates: HashMap<_, ClassCandidate> = HashMap::new();
+            for found in dewey.scan_text(document) {
+                candidates
+                    .entry(found.class.code.clone())
+                    .or_insert_with(|| ClassCandidate { class: found.class, occurrences: 0, score: 0.0 })
+                    .occurrences += 1;
+            }
+
+            let mut candidates = candidates.into_values().collect::<Vec<_>>();
+            for candidate in &mut candidates {
+                candidate.score = (candidate.occurrences as f64) * ((candidate.class.depth() + 1) as f64);
+            }
+            candidates.sort_by(|a, b| b.score.total_cmp(&a.score).then_with(|| a.class.code.cmp(&b.class.code)));
+            candidates
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_scan_text() {
+        let document = "A finding aid covering MATHEMATICS, music and some artwork.";
+        let matches = Dewey::standard().scan_text(document);
+
+        let mathematics = matches.iter().find(|m| m.class.code == "51").expect("Expected Mathematics");
+        assert_eq!(&document[mathematics.start..mathematics.end], "MATHEMATICS");
+        assert!(matches.iter().any(|m| m.class.code == "78"), "Expected Music");
+        assert!(!matches.iter().any(|m| m.class.name == "Arts"), "Names should only match whole words");
+
+        let caption = Dewey::standard().get_class("0").unwrap().name;
+        let folded = format!("Shelved under {}.", caption.replace('&', "and"));
+        assert!(Dewey::standard().scan_text(&folded).iter().any(|m| m.class.code == "0"), "Expected folded names to match");
+
+        let document = "Filed under No longer used—formerly Emotions, then Not assigned or no longer used.";
+        assert!(Dewey::standard().scan_text(document).iter().all(|m| !search::is_unused(&m.class.name)), "Expected unused classes to be skipped");
+    }
+
+    #[test]
+    fn test_classify_documents() {
+        let results = Dewey::standard()
+            .classify_documents(["Music, music and more music. Also some mathematics.", "Nothing relevant"])
+            .collect::<Vec<_>>();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0][0].class.code, "780", "Expected the most specific, most frequent class first");
+        assert_eq!(results[0][0].occurrences, 3);
+        let codes = results[0].iter().map(|candidate| &candidate.class.code).collect::<std::collections::HashSet<_>>();
+        assert_eq!(codes.len(), results[0].len(), "Expected each class once");
+        assert!(results[1].is_empty(), "Expected no candidates");
+    }
+}
@@ -0,0 +1,182 @@
+//! Scheme-agnostic lookup & traversal, for code that shouldn't depend on DDC specifically
+
+use crate::{ Class, Dewey };
+
+/// Lookup & traversal surface of a classification scheme, implemented by [Dewey]
+///
+/// Downstream code written against this trait can swap the DDC for a custom local scheme, another classification, or a dataset loaded at runtime.
+///
+/// ```rust
+/// use dewey_decimal::{ ClassificationScheme, Dewey };
+///
+/// fn breadcrumb<S: ClassificationScheme>(scheme: &S, code: &str) -> Vec<String> {
+///     let mut names = Vec::new();
+///     let mut current = scheme.get_class(code);
+///     while let Some(class) = current {
+///         current = scheme.parent(scheme.code(&class));
+///         names.insert(0, scheme.name(&class).to_string());
+///     }
+///     names
+/// }
+///
+/// assert_eq!(breadcrumb(&Dewey::standard(), "516"), ["Science", "Mathematics", "Geometry"]);
+/// ```
+pub trait ClassificationScheme {
+    /// Class of this scheme
+    type Class;
+
+    /// Gets the code of a class
+    ///
+    /// # Arguments
+    ///
+    /// - `class` (`&Self::Class`) - Class to get the code of
+    ///
+    /// # Returns
+    ///
+    /// - `&str` - The code of the class
+    fn code<'a>(&self, class: &'a Self::Class) -> &'a str;
+
+    /// Gets the name of a class
+    ///
+    /// # Arguments
+    ///
+    /// - `class` (`&Self::Class`) - Class to get the name of
+    ///
+    /// # Returns
+    ///
+    /// - `&str` - The name of the class
+    fn name<'a>(&self, class: &'a Self::Class) -> &'a str;
+
+    /// Gets a class by code
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`&str`) - Code of the class
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Self::Class>` - The class, or [None] if the scheme has no class with this code
+    fn get_class(&self, code: &str) -> Option<Self::Class>;
+
+    /// Gets the direct children of a class
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`&str`) - Code of the parent class, or an empty string for the top-level classes
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<Self::Class>` - The children, in the scheme's order
+    fn children(&self, code: &str) -> Vec<Self::Class>;
+
+    /// Gets the parent of a class
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`&str`) - Code of the class
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Self::Class>` - The parent, or [None] for top-level & unknown classes
+    fn parent(&self, code: &str) -> Option<Self::Class>;
+
+    /// Searches the scheme's class names
+    ///
+    /// # Arguments
+    ///
+    /// - `query` (`&str`) - Text to search for
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<Self::Class>` - Matching classes, best first
+    fn search(&self, query: &str) -> Vec<Self::Class>;
+}
+
+impl ClassificationScheme for Dewey {
+    type Class = Class;
+
+    fn code<'a>(&self, class: &'a Class) -> &'a str {
+        &class.code
+    }
+
+    fn name<'a>(&self, class: &'a Class) -> &'a str {
+        &class.name
+    }
+
+    fn get_class(&self, code: &str) -> Option<Class> {
+        Dewey::get_class(self, code)
+    }
+
+    fn children(&self, code: &str) -> Vec<Class> {
+        self.get_direct_children(code)
+    }
+
+    fn parent(&self, code: &str) -> Option<Class> {
+        self.get_parent(code)
+    }
+
+    fn search(&self, query: &str) -> Vec<Class> {
+        Dewey::search(self, query)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Local scheme with two levels, keyed by letters
+    struct Local;
+
+    impl ClassificationScheme for Local {
+        type Class = (&'static str, &'static str);
+
+        fn code<'a>(&self, class: &'a Self::Class) -> &'a str {
+            class.0
+        }
+
+        fn name<'a>(&self, class: &'a Self::Class) -> &'a str {
+            class.1
+        }
+
+        fn get_class(&self, code: &str) -> Option<Self::Class> {
+            [("A", "Archives"), ("AB", "Board minutes")].into_iter().find(|class| class.0 == code)
+        }
+
+        fn children(&self, code: &str) -> Vec<Self::Class> {
+            match code {
+                "" => vec![("A", "Archives")],
+                "A" => vec![("AB", "Board minutes")],
+                _ => Vec::new(),
+            }
+        }
+
+        fn parent(&self, code: &str) -> Option<Self::Class> {
+            (code.len() > 1).then(|| self.get_class(&code[..1])).flatten()
+        }
+
+        fn search(&self, query: &str) -> Vec<Self::Class> {
+            ["A", "AB"].into_iter().filter_map(|code| self.get_class(code)).filter(|class| class.1.contains(query)).collect()
+        }
+    }
+
+    fn leaves<S: ClassificationScheme>(scheme: &S, code: &str) -> Vec<String> {
+        scheme
+            .children(code)
+            .iter()
+            .flat_map(|child| match scheme.children(scheme.code(child)) {
+                children if children.is_empty() => vec![scheme.code(child).to_string()],
+                _ => leaves(scheme, scheme.code(child)),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_schemes() {
+        let dewey = Dewey::standard();
+        assert_eq!(leaves(&Local, ""), ["AB"]);
+        assert_eq!(leaves(&dewey, "51").len(), dewey.children_of("51").len());
+        assert_eq!(ClassificationScheme::parent(&dewey, "516").map(|class| class.code), Some("51".into()));
+        assert_eq!(ClassificationScheme::search(&dewey, "geometry"), dewey.search("geometry"));
+        assert_eq!(Local.parent("AB").map(|class| Local.name(&class).to_string()).as_deref(), Some("Archives"));
+    }
+}
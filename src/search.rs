@@ -0,0 +1,360 @@
+use std::{ cmp::Reverse, collections::{ BTreeMap, HashSet } };
+
+use unicode_normalization::{ char::is_combining_mark, UnicodeNormalization };
+
+use crate::{ lenient_code, Class, Dewey };
+
+/// Words too common in class names to be worth searching for (kept in sync with the keyword index generated by `build.rs`)
+const STOPWORDS: &[&str] = &["and", "for", "in", "of", "on", "the", "to", "with"];
+
+/// Normalizes text for matching: lowercases it, strips diacritics (ie `Français` becomes `francais`), expands ligatures, spells out `&` as `and`, and collapses whitespace (kept in sync with `fold` in `build.rs`)
+pub(crate) fn fold(text: &str) -> String {
+    let mut folded = String::with_capacity(text.len());
+    for c in text.nfkd().filter(|c| !is_combining_mark(*c)).flat_map(char::to_lowercase) {
+        match c {
+            '&' => folded.push_str(" and "),
+            'ß' => folded.push_str("ss"),
+            'æ' => folded.push_str("ae"),
+            'œ' => folded.push_str("oe"),
+            'ø' => folded.push('o'),
+            'ł' => folded.push('l'),
+            'đ' => folded.push('d'),
+            c => folded.push(c),
+        }
+    }
+    folded.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Splits text into normalized keywords (see [fold]), skipping [STOPWORDS]
+pub(crate) fn tokenize(text: &str) -> Vec<String> {
+    fold(text)
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|token| !token.is_empty() && !STOPWORDS.contains(token))
+        .map(str::to_string)
+        .collect()
+}
+
+/// Markers of classes that aren't in use, which are left out of the keyword index
+pub(crate) const UNUSED_MARKERS: &[&str] = &["unassigned", "not assigned", "no longer used"];
+
+/// Checks whether a class name marks it as unassigned or no longer used (ie `[Unassigned]` or `No longer used—formerly Prayer`)
+pub(crate) fn is_unused(name: &str) -> bool {
+    let name = fold(name);
+    UNUSED_MARKERS.iter().any(|marker| name.contains(marker))
+}
+
+/// Builds a keyword index like the one generated by `build.rs`, for datasets loaded at runtime
+///
+/// # Arguments
+///
+/// - `names` (`&[(&str, &str)]`) - Normalized class names (see [fold]) & their codes
+///
+/// # Returns
+///
+/// - `Vec<(String, Vec<String>)>` - Keywords & the codes of the classes using them, sorted by keyword
+pub(crate) fn keyword_index(names: &[(&str, &str)]) -> Vec<(String, Vec<String>)> {
+    let mut keywords: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for (name, code) in names {
+        if UNUSED_MARKERS.iter().any(|marker| name.contains(marker)) {
+            continue;
+        }
+
+        for token in tokenize(name) {
+            let codes = keywords.entry(token).or_default();
+            if !codes.iter().any(|existing| existing == code) {
+                codes.push(code.to_string());
+            }
+        }
+    }
+
+    keywords
+        .into_iter()
+        .map(|(keyword, mut codes)| {
+            codes.sort();
+            (keyword, codes)
+        })
+        .collect()
+}
+
+/// Gets the entries of the keyword index whose keyword starts with the token
+pub(crate) fn keywords_starting_with<'a>(
+    keywords: &'static [(&'static str, &'static [&'static str])],
+    token: &'a str
+) -> impl Iterator<Item = &'static (&'static str, &'static [&'static str])> + use<'a> {
+    let start = keywords.partition_point(|(keyword, _)| *keyword < token);
+    keywords[start..].iter().take_while(move |(keyword, _)| keyword.starts_with(token))
+}
+
+/// Gets the codes of the classes with a keyword starting with the token, using the keyword index
+fn codes_with_keyword(
+    keywords: &'static [(&'static str, &'static [&'static str])],
+    token: &str
+) -> impl Iterator<Item = &'static str> {
+    keywords_starting_with(keywords, token).flat_map(|(_, codes)| codes.iter().copied())
+}
+
+/// Ranks a class name against query keywords, as in [Dewey::search] (lower is better)
+///
+/// Keys compare by the number of keywords matched, then by whether they were matched as whole words, then by how early in the name the first match is, and finally by how short the name is.
+pub(crate) fn keyword_rank(tokens: &[String], name: &str) -> (Reverse<usize>, Reverse<usize>, usize, usize) {
+    let words = tokenize(name);
+    let mut matched = 0;
+    let mut exact = 0;
+    let mut first = usize::MAX;
+    for token in tokens {
+        if let Some(position) = words.iter().position(|word| word == token) {
+            (matched, exact, first) = (matched + 1, exact + 1, first.min(position));
+        } else if let Some(position) = words.iter().position(|word| word.starts_with(token.as_str())) {
+            (matched, first) = (matched + 1, first.min(position));
+        }
+    }
+    (Reverse(matched), Reverse(exact), first, words.len())
+}
+
+/// Ranks a class name against every phrasing of a query, keeping the best rank (see [keyword_rank])
+pub(crate) fn best_keyword_rank(phrasings: &[Vec<String>], name: &str) -> (Reverse<usize>, Reverse<usize>, usize, usize) {
+    phrasings
+        .iter()
+        .map(|tokens| keyword_rank(tokens, name))
+        .min()
+        .unwrap_or_else(|| keyword_rank(&[], name))
+}
+
+/// Marker for a [SearchOptions] without a query yet, which can't be executed
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NoQuery;
+
+/// Marker for a [SearchOptions] with a query, which can be executed
+#[derive(Clone, Debug)]
+pub struct HasQuery(String);
+
+/// Builder for searches over class names
+///
+/// Required parameters are enforced at compile time: [SearchOptions::execute] is only available once [SearchOptions::query] has been called.
+///
+/// ```rust
+/// use dewey_decimal::Dewey;
+///
+/// let results = Dewey::standard().search_options().query("poetry").within("8").limit(5).execute();
+/// assert!(results.len() <= 5 && results.iter().all(|class| class.code.starts_with('8')));
+/// ```
+///
+/// ```compile_fail
+/// use dewey_decimal::Dewey;
+///
+/// // No query has been set, so this doesn't compile
+/// let results = Dewey::standard().search_options().within("8").execute();
+/// ```
+#[derive(Clone, Debug)]
+pub struct SearchOptions<Q = NoQuery> {
+    dewey: Dewey,
+    query: Q,
+    within: String,
+    limit: Option<usize>,
+    synonyms: bool,
+}
+
+impl<Q> SearchOptions<Q> {
+    /// Sets the text to search for (ignoring case & diacritics)
+    ///
+    /// # Arguments
+    ///
+    /// - `text` (`impl AsRef<str>`) - Text to search for in class names
+    ///
+    /// # Returns
+    ///
+    /// - `SearchOptions<HasQuery>` - The updated [SearchOptions], which can now be executed
+    pub fn query(self, text: impl AsRef<str>) -> SearchOptions<HasQuery> {
+        SearchOptions {
+            dewey: self.dewey,
+            query: HasQuery(text.as_ref().to_string()),
+            within: self.within,
+            limit: self.limit,
+            synonyms: self.synonyms,
+        }
+    }
+
+    /// Restricts the search to a subtree (including its root)
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code of the subtree's root, matched leniently (ie ` 5 `, `5XX` & `005/13` work)
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The updated [SearchOptions]
+    pub fn within(mut self, code: impl AsRef<str>) -> Self {
+        self.within = lenient_code(code.as_ref());
+        self
+    }
+
+    /// Limits the number of results
+    ///
+    /// # Arguments
+    ///
+    /// - `limit` (`usize`) - Maximum number of classes to return
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The updated [SearchOptions]
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets whether the query is expanded with [Dewey::synonyms] (enabled by default)
+    ///
+    /// # Arguments
+    ///
+    /// - `enabled` (`bool`) - Whether to consult the synonym dictionary
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The updated [SearchOptions]
+    pub fn synonyms(mut self, enabled: bool) -> Self {
+        self.synonyms = enabled;
+        self
+    }
+}
+
+impl SearchOptions<HasQuery> {
+    /// Executes the search
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<Class>` - [Vec] of matching [Class] instances, ranked as in [Dewey::classes_named_containing]
+    pub fn execute(&self) -> Vec<Class> {
+        let results = if self.synonyms {
+            self.dewey.classes_named_containing(&self.query.0)
+        } else {
+            self.dewey.rank_names_containing(&[fold(&self.query.0)])
+        };
+
+        results
+            .into_iter()
+            .filter(|class| class.code.starts_with(&self.within))
+            .take(self.limit.unwrap_or(usize::MAX))
+            .collect()
+    }
+}
+
+impl Dewey {
+    /// Starts building a search over class names, see [SearchOptions]
+    ///
+    /// # Returns
+    ///
+    /// - `SearchOptions<NoQuery>` - A new [SearchOptions] without a query
+    pub fn search_options(&self) -> SearchOptions<NoQuery> {
+        SearchOptions { dewey: *self, query: NoQuery, within: String::new(), limit: None, synonyms: true }
+    }
+}
+
+impl Dewey {
+    /// Searches class names by keyword (ignoring case & diacritics, so `francais` matches `Français`)
+    ///
+    /// The query is split into keywords, each matching class names containing a word that equals or starts with it (ie `photo` matches `Photography`). Candidates are found through a keyword index generated at build time, and classes that aren't in use are never returned.
+    ///
+    /// Results are ranked by the number of keywords matched, then by whether they were matched as whole words, then by how early in the name the first match is, then by how short the name is, and finally in shelf order. Keywords are expanded through [Dewey::synonyms] (ie `maths` also matches `Mathematics`), each class being ranked by its best matching phrasing. With the `cache` feature, results are cached by keywords.
+    ///
+    /// # Arguments
+    ///
+    /// - `query` (`impl AsRef<str>`) - Keywords to search for (ie `photography`)
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<Class>` - [Vec] of matching [Class] instances, best first
+    pub fn search(&self, query: impl AsRef<str>) -> Vec<Class> {
+        let phrasings = self.search_phrasings(query.as_ref());
+        self.cached(
+            || {
+                let phrasings = phrasings.iter().map(|tokens| tokens.join("\u{1f}")).collect::<Vec<_>>();
+                format!("search:{}", phrasings.join("\u{1e}"))
+            },
+            || {
+                let candidates = phrasings
+                    .iter()
+                    .flatten()
+                    .flat_map(|token| codes_with_keyword(self.dataset().keywords, token))
+                    .collect::<HashSet<_>>();
+
+                let mut ranked = candidates
+                    .into_iter()
+                    .filter_map(|code| self.class_ref(code))
+                    .map(|class| ((best_keyword_rank(&phrasings, &class.name), &class.code), class))
+                    .collect::<Vec<_>>();
+                ranked.sort_by_key(|(key, _)| *key);
+
+                ranked.into_iter().map(|(_, class)| class.clone()).collect()
+            }
+        )
+    }
+
+    /// Splits a query into the keywords of each of its phrasings, expanded through [Dewey::synonyms] (the query's own keywords come first)
+    pub(crate) fn search_phrasings(&self, query: &str) -> Vec<Vec<String>> {
+        let mut phrasings: Vec<Vec<String>> = Vec::new();
+        for tokens in self.synonyms().expand(query).iter().map(|variant| tokenize(variant)) {
+            if !tokens.is_empty() && !phrasings.contains(&tokens) {
+                phrasings.push(tokens);
+            }
+        }
+        phrasings
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{ dataset::Entry, DeweySource };
+
+    #[test]
+    fn test_search() {
+        let dewey = Dewey::standard();
+        assert_eq!(dewey.search("photography")[0].code, "77");
+        assert_eq!(dewey.search("PSYCHOLOGY")[0].code, "15", "Expected case-insensitive matches");
+        assert!(dewey.search("photo").iter().any(|class| class.code == "779"), "Expected keyword prefixes to match");
+        assert!(!dewey.search("photography").iter().any(|class| class.code == "775"), "Expected unused classes to be skipped");
+
+        let results = dewey.search("applied psychology");
+        assert_eq!(results[0].code, "158", "Expected classes matching every keyword first");
+        assert!(dewey.search("the of").is_empty(), "Expected stopwords to be ignored");
+        assert_eq!(dewey.search("Phötögraphy")[0].code, "77", "Expected diacritics to be ignored");
+
+        assert!(dewey.search("maths").iter().any(|class| class.code == "51"), "Expected synonyms to be consulted");
+        assert!(dewey.search("poems").iter().any(|class| class.name.to_lowercase().contains("poetry")));
+        assert_eq!(dewey.search("maths")[0].code, dewey.search("mathematics")[0].code);
+    }
+
+    #[test]
+    fn test_fold() {
+        assert_eq!(fold("Français"), "francais");
+        assert_eq!(fold("Straße  &  Œuvres"), "strasse and oeuvres");
+        assert_eq!(fold("ﬁction"), "fiction");
+        assert_eq!(tokenize("Ciencias & Matemáticas"), ["ciencias", "matematicas"]);
+
+        let dewey = Dewey::standard();
+        let named = dewey.classes_named_containing("data processing and computer science");
+        assert_eq!(named[0].code, "004", "Expected `and` to match `&`");
+        assert_eq!(dewey.classes_named_like("DATA PROCESSING &")[0].code, "004");
+    }
+
+    #[test]
+    fn test_search_options() {
+        let options = Dewey::standard().search_options().within("8").query("Fiction");
+        let results = options.clone().execute();
+        assert!(!results.is_empty() && results.iter().all(|class| class.code.starts_with('8')));
+        assert_eq!(options.limit(2).execute().len(), 2);
+        for code in [" 8 ", "8xx"] {
+            assert_eq!(Dewey::standard().search_options().within(code).query("Fiction").execute(), results, "Unexpected results within {code:?}");
+        }
+
+        let entries = vec![Entry::new("0", "Computing", None), Entry::new("00", "Computer science", Some("0")), Entry::new("005", "Programming", Some("00")), Entry::new("005.13", "Programming languages", Some("005"))];
+        let dewey = Dewey::builder().source(DeweySource::Entries(entries)).build().unwrap();
+        let codes = dewey.search_options().within("005/13").query("programming").execute().into_iter().map(|class| class.code).collect::<Vec<_>>();
+        assert_eq!(codes, ["005.13"]);
+
+        let with_synonyms = Dewey::standard().search_options().query("poems").execute();
+        let without_synonyms = Dewey::standard().search_options().query("poems").synonyms(false).execute();
+        assert!(with_synonyms.iter().any(|class| class.name.to_lowercase().contains("poetry")));
+        assert!(without_synonyms.len() < with_synonyms.len(), "Expected fewer results without synonyms");
+    }
+}
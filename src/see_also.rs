@@ -0,0 +1,78 @@
+use crate::{ Class, Dewey };
+
+/// Cross-references between classes in different disciplines, mirroring the "see also" notes of the printed schedules
+///
+/// Links are symmetric: each pair is listed once, and followed in both directions.
+const SEE_ALSO: &[(&str, &str)] = &[
+    ("004", "621"),
+    ("006", "519"),
+    ("020", "025"),
+    ("070", "302"),
+    ("150", "612"),
+    ("155", "305"),
+    ("174", "658"),
+    ("320", "340"),
+    ("330", "658"),
+    ("332", "658"),
+    ("363", "613"),
+    ("370", "155"),
+    ("551", "577"),
+    ("577", "304"),
+    ("610", "613"),
+    ("720", "624"),
+    ("780", "781"),
+    ("791", "796"),
+];
+
+impl Dewey {
+    /// Gets the classes cross-referenced by the class with the provided code ("see also" links)
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code to search for
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<Class>` - [Vec] of related [Class] instances, in shelf order (empty if the class has no cross-references)
+    pub fn see_also(&self, code: impl AsRef<str>) -> Vec<Class> {
+        let code = code.as_ref().trim_matches('X');
+        let mut related = SEE_ALSO.iter()
+            .filter_map(|(a, b)| {
+                if *a == code {
+                    Some(*b)
+                } else if *b == code {
+                    Some(*a)
+                } else {
+                    None
+                }
+            })
+            .filter_map(|related| self.get_class(related))
+            .collect::<Vec<_>>();
+        related.sort_by(|a, b| a.code.cmp(&b.code));
+        related
+    }
+}
+
+impl Class {
+    /// See [Dewey::see_also]
+    pub fn see_also(&self) -> Vec<Class> {
+        Dewey::standard().see_also(&self.code)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_see_also() {
+        let computing = Dewey::standard().get_class("004").unwrap();
+        assert_eq!(computing.see_also().iter().map(|c| c.code.as_ref()).collect::<Vec<_>>(), vec!["621"]);
+        assert!(Dewey::standard().see_also("621").iter().any(|c| c.code == "004"), "Expected a symmetric link");
+        assert!(Dewey::standard().see_also("001").is_empty(), "Expected no cross-references");
+
+        for (a, b) in SEE_ALSO {
+            assert!(Dewey::standard().get_class(a).is_some() && Dewey::standard().get_class(b).is_some());
+        }
+    }
+}
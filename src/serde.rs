@@ -0,0 +1,171 @@
+//! Helpers for `#[serde(with = "...")]` attributes, validating codes when deserializing
+//!
+//! ```rust
+//! use dewey_decimal::{ Class, DdcCode };
+//! use serde::{ Deserialize, Serialize };
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Book {
+//!     #[serde(with = "dewey_decimal::serde::code")]
+//!     class: Class,
+//!
+//!     #[serde(with = "dewey_decimal::serde::code::option")]
+//!     secondary: Option<Class>,
+//!
+//!     #[serde(with = "dewey_decimal::serde::assigned")]
+//!     shelf: DdcCode,
+//! }
+//!
+//! let book: Book = serde_json::from_str(r#"{ "class": "813", "secondary": null, "shelf": "813" }"#).unwrap();
+//! assert_eq!(book.class.name, "American fiction in English");
+//! assert!(serde_json::from_str::<Book>(r#"{ "class": "81a", "secondary": null, "shelf": "813" }"#).is_err());
+//! ```
+
+/// (De)serializes a [Class](crate::Class) as its code, failing on malformed codes & codes without a class in the standard dataset
+///
+/// Codes are always serialized trimmed (ie `51`), regardless of the crate-wide [CodeStyle](crate::CodeStyle), since padded codes can be ambiguous (`510` is both the padded form of `51` and a section).
+pub mod code {
+    use ::serde::{ de::Error, Deserialize, Deserializer, Serializer };
+
+    use crate::{ Class, Dewey };
+
+    /// Serializes a class as its trimmed code
+    ///
+    /// # Arguments
+    ///
+    /// - `class` (`&Class`) - Class to serialize
+    /// - `serializer` (`S`) - Serializer to write the code to
+    ///
+    /// # Returns
+    ///
+    /// - `Result<S::Ok, S::Error>` - The serializer's result
+    pub fn serialize<S: Serializer>(class: &Class, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&class.code)
+    }
+
+    /// Deserializes a code into its class
+    ///
+    /// # Arguments
+    ///
+    /// - `deserializer` (`D`) - Deserializer to read a code from
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Class, D::Error>` - The matching [Class], or an error if the code is malformed or has no class (see [Dewey::try_get_class])
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Class, D::Error> {
+        let code = String::deserialize(deserializer)?;
+        Dewey::standard().try_get_class(code).map_err(D::Error::custom)
+    }
+
+    /// (De)serializes an optional [Class] as its code or `null`, see [the parent module](super)
+    pub mod option {
+        use ::serde::{ de::Error, Deserialize, Deserializer, Serializer };
+
+        use crate::{ Class, Dewey };
+
+        /// Serializes an optional class as its trimmed code or `null`
+        ///
+        /// # Arguments
+        ///
+        /// - `class` (`&Option<Class>`) - Class to serialize
+        /// - `serializer` (`S`) - Serializer to write the code to
+        ///
+        /// # Returns
+        ///
+        /// - `Result<S::Ok, S::Error>` - The serializer's result
+        pub fn serialize<S: Serializer>(class: &Option<Class>, serializer: S) -> Result<S::Ok, S::Error> {
+            match class {
+                Some(class) => serializer.serialize_some(&class.code),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        /// Deserializes an optional code into its class
+        ///
+        /// # Arguments
+        ///
+        /// - `deserializer` (`D`) - Deserializer to read a code or `null` from
+        ///
+        /// # Returns
+        ///
+        /// - `Result<Option<Class>, D::Error>` - The matching [Class] (or [None] for `null`), or an error if the code is malformed or has no class
+        pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Class>, D::Error> {
+            Option::<String>::deserialize(deserializer)?
+                .map(|code| Dewey::standard().try_get_class(code).map_err(D::Error::custom))
+                .transpose()
+        }
+    }
+}
+
+/// (De)serializes a [DdcCode](crate::DdcCode), additionally failing on codes without a class in the standard dataset
+///
+/// [DdcCode]'s own [Deserialize](::serde::Deserialize) implementation only checks that the code is well-formed.
+pub mod assigned {
+    use ::serde::{ de::Error, Deserialize, Deserializer, Serialize, Serializer };
+
+    use crate::{ DdcCode, Dewey, DeweyError };
+
+    /// Serializes a code
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`&DdcCode`) - Code to serialize
+    /// - `serializer` (`S`) - Serializer to write the code to
+    ///
+    /// # Returns
+    ///
+    /// - `Result<S::Ok, S::Error>` - The serializer's result
+    pub fn serialize<S: Serializer>(code: &DdcCode, serializer: S) -> Result<S::Ok, S::Error> {
+        code.serialize(serializer)
+    }
+
+    /// Deserializes a code, checking that it has a class
+    ///
+    /// # Arguments
+    ///
+    /// - `deserializer` (`D`) - Deserializer to read a code from
+    ///
+    /// # Returns
+    ///
+    /// - `Result<DdcCode, D::Error>` - The validated [DdcCode], or an error if the code is malformed or has no class
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<DdcCode, D::Error> {
+        let code = DdcCode::deserialize(deserializer)?;
+        match Dewey::standard().class_ref(&code) {
+            Some(_) => Ok(code),
+            None => Err(D::Error::custom(DeweyError::NotFound(code.to_string()))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ::serde::{ Deserialize, Serialize };
+
+    use crate::{ Class, DdcCode };
+
+    #[derive(Serialize, Deserialize)]
+    struct Record {
+        #[serde(with = "super::code")]
+        class: Class,
+
+        #[serde(with = "super::code::option", default)]
+        secondary: Option<Class>,
+
+        #[serde(with = "super::assigned")]
+        shelf: DdcCode,
+    }
+
+    #[test]
+    fn test_serde_helpers() {
+        let record: Record = serde_json::from_str(r#"{ "class": "51X", "secondary": "516", "shelf": "813" }"#).unwrap();
+        assert_eq!((record.class.code.as_ref(), record.secondary.as_ref().map(|class| class.code.as_ref())), ("51", Some("516")));
+        assert_eq!(serde_json::to_string(&record).unwrap(), r#"{"class":"51","secondary":"516","shelf":"813"}"#);
+
+        let parse = |json: &str| serde_json::from_str::<Record>(json).err().map(|error| error.to_string());
+        assert!(parse(r#"{ "class": "5", "shelf": "5" }"#).is_none(), "Expected a missing option to default to None");
+        assert!(parse(r#"{ "class": "81a", "shelf": "5" }"#).unwrap().contains("invalid character 'a'"));
+        assert!(parse(r#"{ "class": "008", "shelf": "5" }"#).unwrap().contains("no class with code 008"));
+        assert!(parse(r#"{ "class": "5", "secondary": "008", "shelf": "5" }"#).is_some());
+        assert!(parse(r#"{ "class": "5", "shelf": "008" }"#).unwrap().contains("no class with code 008"));
+    }
+}
@@ -0,0 +1,185 @@
+use std::{ fs::{ self, File }, io::{ self, BufWriter, Write }, path::Path };
+
+use crate::Dewey;
+
+/// Styles of the generated browser
+const STYLE: &str = r#"
+body { font-family: system-ui, sans-serif; margin: 0 auto; max-width: 60rem; padding: 1rem; color: #222; }
+input { width: 100%; box-sizing: border-box; font-size: 1rem; padding: 0.5rem; margin-bottom: 1rem; }
+ul { list-style: none; padding-left: 1.25rem; margin: 0; }
+#tree > ul { padding-left: 0; }
+summary, .leaf { cursor: default; padding: 0.1rem 0; }
+summary { cursor: pointer; }
+.leaf { padding-left: 1rem; }
+.code { font-family: ui-monospace, monospace; font-weight: bold; margin-right: 0.5rem; }
+.path { color: #777; font-size: 0.85rem; margin-left: 0.5rem; }
+#results li { padding: 0.2rem 0; }
+"#;
+
+/// Script rendering the collapsible tree & searching classes client-side
+const SCRIPT: &str = r#"
+const classes = JSON.parse(document.getElementById("classes").textContent);
+const byCode = new Map(classes.map((entry) => [entry[0], entry]));
+const children = new Map();
+for (const entry of classes) {
+    const parent = entry[2] ?? "";
+    if (!children.has(parent)) children.set(parent, []);
+    children.get(parent).push(entry);
+}
+
+function label(entry) {
+    const fragment = document.createDocumentFragment();
+    const code = document.createElement("span");
+    code.className = "code";
+    code.textContent = entry[3];
+    fragment.append(code, entry[1]);
+    return fragment;
+}
+
+function branch(parent) {
+    const list = document.createElement("ul");
+    for (const entry of children.get(parent) ?? []) {
+        const item = document.createElement("li");
+        if (children.has(entry[0])) {
+            const details = document.createElement("details");
+            const summary = document.createElement("summary");
+            summary.append(label(entry));
+            details.append(summary);
+            details.addEventListener("toggle", () => {
+                if (details.open && details.children.length === 1) details.append(branch(entry[0]));
+            });
+            item.append(details);
+        } else {
+            const leaf = document.createElement("div");
+            leaf.className = "leaf";
+            leaf.append(label(entry));
+            item.append(leaf);
+        }
+        list.append(item);
+    }
+    return list;
+}
+
+function path(entry) {
+    const names = [];
+    for (let parent = entry[2]; parent; parent = byCode.get(parent)?.[2]) names.unshift(byCode.get(parent)?.[1]);
+    return names.join(" > ");
+}
+
+const tree = document.getElementById("tree");
+const results = document.getElementById("results");
+tree.append(branch(""));
+
+document.getElementById("search").addEventListener("input", (event) => {
+    const query = event.target.value.trim().toLowerCase();
+    results.replaceChildren();
+    tree.hidden = query.length > 0;
+    if (!query) return;
+
+    const words = query.split(/\s+/);
+    const matches = classes.filter((entry) =>
+        entry[0].startsWith(query) || entry[3].startsWith(query) || words.every((word) => entry[1].toLowerCase().includes(word))
+    );
+    for (const entry of matches.slice(0, 200)) {
+        const item = document.createElement("li");
+        const context = document.createElement("span");
+        context.className = "path";
+        context.textContent = path(entry);
+        item.append(label(entry), context);
+        results.append(item);
+    }
+});
+"#;
+
+/// Escapes a string as a JSON string literal that is safe to embed in a `<script>` element
+fn json_string(value: &str) -> String {
+    let mut output = String::from("\"");
+    for c in value.chars() {
+        match c {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '<' => output.push_str("\\u003c"),
+            c if c.is_control() => output.push_str(&format!("\\u{:04x}", c as u32)),
+            c => output.push(c),
+        }
+    }
+    output.push('"');
+    output
+}
+
+impl Dewey {
+    /// Writes a self-contained HTML page for browsing the classification, with a collapsible tree & client-side search
+    ///
+    /// Classes are embedded in the page as JSON (`[code, name, parent code, displayed code]` arrays, where codes are trimmed & the displayed code is in the crate-wide [CodeStyle](crate::CodeStyle)), and the tree is rendered lazily as branches are opened, so the page works offline without any other files.
+    ///
+    /// # Arguments
+    ///
+    /// - `writer` (`impl Write`) - Destination of the page
+    ///
+    /// # Returns
+    ///
+    /// - `io::Result<()>` - An error if writing fails
+    pub fn write_site(&self, mut writer: impl Write) -> io::Result<()> {
+        writeln!(writer, "<!DOCTYPE html>")?;
+        writeln!(writer, "<html lang=\"en\">")?;
+        writeln!(writer, "<head>")?;
+        writeln!(writer, "<meta charset=\"utf-8\">")?;
+        writeln!(writer, "<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">")?;
+        writeln!(writer, "<title>Dewey Decimal Classification</title>")?;
+        writeln!(writer, "<style>{STYLE}</style>")?;
+        writeln!(writer, "</head>")?;
+        writeln!(writer, "<body>")?;
+        writeln!(writer, "<h1>Dewey Decimal Classification</h1>")?;
+        writeln!(writer, "<input id=\"search\" type=\"search\" placeholder=\"Search codes & names\" autofocus>")?;
+        writeln!(writer, "<ul id=\"results\"></ul>")?;
+        writeln!(writer, "<div id=\"tree\"></div>")?;
+
+        write!(writer, "<script id=\"classes\" type=\"application/json\">[")?;
+        for (index, class) in self.all_ref().enumerate() {
+            let parent = class.parent_code().map_or_else(|| "null".to_string(), json_string);
+            let separator = if index > 0 { "," } else { "" };
+            write!(writer, "{separator}[{},{},{parent},{}]", json_string(&class.code), json_string(&class.name), json_string(&class.display_code()))?;
+        }
+        writeln!(writer, "]</script>")?;
+
+        writeln!(writer, "<script>{SCRIPT}</script>")?;
+        writeln!(writer, "</body>")?;
+        writeln!(writer, "</html>")
+    }
+
+    /// Generates a static classification browser in a directory, ie for hosting on an intranet (see [Dewey::write_site])
+    ///
+    /// # Arguments
+    ///
+    /// - `dir` (`impl AsRef<Path>`) - Directory to write `index.html` to, created if it doesn't exist
+    ///
+    /// # Returns
+    ///
+    /// - `io::Result<()>` - An error if the directory or page can't be written
+    pub fn generate_site(&self, dir: impl AsRef<Path>) -> io::Result<()> {
+        fs::create_dir_all(&dir)?;
+        let mut writer = BufWriter::new(File::create(dir.as_ref().join("index.html"))?);
+        self.write_site(&mut writer)?;
+        writer.flush()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_generate_site() {
+        let directory = std::env::temp_dir().join(format!("dewey-site-{}", std::process::id()));
+        Dewey::standard().generate_site(&directory).unwrap();
+        let page = fs::read_to_string(directory.join("index.html")).unwrap();
+        fs::remove_dir_all(&directory).unwrap();
+
+        let data = page.split("type=\"application/json\">").nth(1).and_then(|rest| rest.split("</script>").next()).unwrap();
+        assert_eq!(data.matches("],[").count() + 1, Dewey::standard().all().len(), "Expected one entry per class");
+        assert!(data.starts_with("[[\"0\",") && data.contains(&format!("[\"516\",{},\"51\",\"516\"]", json_string("Geometry"))));
+        assert!(page.trim_end().ends_with("</html>"));
+
+        assert_eq!(json_string("a \"b\" </script>\n"), "\"a \\\"b\\\" \\u003c/script>\\u000a\"");
+    }
+}
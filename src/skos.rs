@@ -0,0 +1,159 @@
+use std::{ fs::File, io::{ self, BufWriter, Write }, path::Path };
+
+use crate::{ Class, Dewey };
+
+/// Namespace of the SKOS vocabulary
+const SKOS: &str = "http://www.w3.org/2004/02/skos/core#";
+
+/// Serialization of the SKOS documents written by [Dewey::write_skos]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum SkosFormat {
+    /// Turtle (`text/turtle`)
+    #[default]
+    Turtle,
+
+    /// RDF/XML (`application/rdf+xml`)
+    RdfXml,
+}
+
+fn escape_turtle(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n").replace('\r', "\\r")
+}
+
+fn escape_xml(value: &str) -> String {
+    value.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+impl Dewey {
+    fn write_skos_turtle(&self, writer: &mut impl Write, base: &str) -> io::Result<()> {
+        writeln!(writer, "@prefix skos: <{SKOS}> .")?;
+        writeln!(writer)?;
+        let top = self.children_of("").iter().map(|class| format!("<{base}{}>", class.code)).collect::<Vec<_>>();
+        writeln!(writer, "<{base}> a skos:ConceptScheme ;")?;
+        writeln!(writer, "    skos:prefLabel \"Dewey Decimal Classification\"@en ;")?;
+        writeln!(writer, "    skos:hasTopConcept {} .", top.join(", "))?;
+
+        for class in self.all_ref() {
+            writeln!(writer)?;
+            writeln!(writer, "<{base}{}> a skos:Concept ;", class.code)?;
+            writeln!(writer, "    skos:inScheme <{base}> ;")?;
+            writeln!(writer, "    skos:notation \"{}\" ;", escape_turtle(&class.display_code()))?;
+            match class.parent_code() {
+                Some(parent) => writeln!(writer, "    skos:broader <{base}{parent}> ;")?,
+                None => writeln!(writer, "    skos:topConceptOf <{base}> ;")?,
+            }
+
+            let narrower = self.children_of(&class.code).iter().map(|child| format!("<{base}{}>", child.code)).collect::<Vec<_>>();
+            if !narrower.is_empty() {
+                writeln!(writer, "    skos:narrower {} ;", narrower.join(", "))?;
+            }
+            writeln!(writer, "    skos:prefLabel \"{}\"@en .", escape_turtle(&class.name))?;
+        }
+        Ok(())
+    }
+
+    fn write_skos_concept_xml(&self, writer: &mut impl Write, base: &str, class: &Class) -> io::Result<()> {
+        writeln!(writer, "  <skos:Concept rdf:about=\"{}{}\">", escape_xml(base), class.code)?;
+        writeln!(writer, "    <skos:inScheme rdf:resource=\"{}\"/>", escape_xml(base))?;
+        writeln!(writer, "    <skos:notation>{}</skos:notation>", escape_xml(&class.display_code()))?;
+        writeln!(writer, "    <skos:prefLabel xml:lang=\"en\">{}</skos:prefLabel>", escape_xml(&class.name))?;
+        match class.parent_code() {
+            Some(parent) => writeln!(writer, "    <skos:broader rdf:resource=\"{}{parent}\"/>", escape_xml(base))?,
+            None => writeln!(writer, "    <skos:topConceptOf rdf:resource=\"{}\"/>", escape_xml(base))?,
+        }
+        for child in self.children_of(&class.code) {
+            writeln!(writer, "    <skos:narrower rdf:resource=\"{}{}\"/>", escape_xml(base), child.code)?;
+        }
+        writeln!(writer, "  </skos:Concept>")
+    }
+
+    fn write_skos_xml(&self, writer: &mut impl Write, base: &str) -> io::Result<()> {
+        writeln!(writer, "<?xml version=\"1.0\" encoding=\"UTF-8\"?>")?;
+        writeln!(writer, "<rdf:RDF xmlns:rdf=\"http://www.w3.org/1999/02/22-rdf-syntax-ns#\" xmlns:skos=\"{SKOS}\">")?;
+        writeln!(writer, "  <skos:ConceptScheme rdf:about=\"{}\">", escape_xml(base))?;
+        writeln!(writer, "    <skos:prefLabel xml:lang=\"en\">Dewey Decimal Classification</skos:prefLabel>")?;
+        for class in self.children_of("") {
+            writeln!(writer, "    <skos:hasTopConcept rdf:resource=\"{}{}\"/>", escape_xml(base), class.code)?;
+        }
+        writeln!(writer, "  </skos:ConceptScheme>")?;
+
+        for class in self.all_ref() {
+            self.write_skos_concept_xml(writer, base, class)?;
+        }
+        writeln!(writer, "</rdf:RDF>")
+    }
+
+    /// Writes the classification as a SKOS concept scheme, ie for loading into a triple store
+    ///
+    /// The scheme's IRI is `base`, and every class is a `skos:Concept` with the IRI `base` followed by its trimmed code (ie `https://example.org/ddc/51`). Concepts have a `skos:notation` (in the crate-wide [CodeStyle](crate::CodeStyle)), an English `skos:prefLabel`, and `skos:broader`/`skos:narrower` relations, with main classes as the scheme's top concepts.
+    ///
+    /// # Arguments
+    ///
+    /// - `writer` (`impl Write`) - Destination of the document
+    /// - `format` (`SkosFormat`) - Serialization to write
+    /// - `base` (`&str`) - IRI of the scheme, used as the prefix of concept IRIs (ie `https://example.org/ddc/`)
+    ///
+    /// # Returns
+    ///
+    /// - `io::Result<()>` - An error if writing fails
+    pub fn write_skos(&self, mut writer: impl Write, format: SkosFormat, base: &str) -> io::Result<()> {
+        match format {
+            SkosFormat::Turtle => self.write_skos_turtle(&mut writer, base),
+            SkosFormat::RdfXml => self.write_skos_xml(&mut writer, base),
+        }
+    }
+
+    /// Exports the classification as a SKOS concept scheme to a file, see [Dewey::write_skos]
+    ///
+    /// # Arguments
+    ///
+    /// - `path` (`impl AsRef<Path>`) - Path of the file to write
+    /// - `format` (`SkosFormat`) - Serialization to write
+    /// - `base` (`&str`) - IRI of the scheme, used as the prefix of concept IRIs
+    ///
+    /// # Returns
+    ///
+    /// - `io::Result<()>` - An error if writing fails
+    pub fn export_skos(&self, path: impl AsRef<Path>, format: SkosFormat, base: &str) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_skos(&mut writer, format, base)?;
+        writer.flush()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const BASE: &str = "https://example.org/ddc/";
+
+    #[test]
+    fn test_write_skos_turtle() {
+        let mut output = Vec::new();
+        Dewey::standard().write_skos(&mut output, SkosFormat::Turtle, BASE).unwrap();
+        let turtle = String::from_utf8(output).unwrap();
+
+        assert_eq!(turtle.matches("a skos:Concept ;").count(), Dewey::standard().all().len(), "Expected one concept per class");
+        let concept = turtle.split("\n\n").find(|block| block.starts_with("<https://example.org/ddc/516> ")).expect("Expected a concept for 516");
+        assert!(concept.contains("skos:broader <https://example.org/ddc/51> ;"), "Expected the parent as broader concept");
+        assert!(concept.contains("skos:notation \"516\" ;"));
+        assert!(turtle.contains("skos:narrower <https://example.org/ddc/510>, "), "Expected children as narrower concepts");
+        assert!(turtle.contains("skos:hasTopConcept <https://example.org/ddc/0>, "));
+    }
+
+    #[test]
+    fn test_write_skos_xml() {
+        let mut output = Vec::new();
+        Dewey::standard().write_skos(&mut output, SkosFormat::RdfXml, BASE).unwrap();
+        let xml = String::from_utf8(output).unwrap();
+
+        assert_eq!(xml.matches("<skos:Concept ").count(), Dewey::standard().all().len(), "Expected one concept per class");
+        assert_eq!(xml.matches("<skos:Concept ").count(), xml.matches("</skos:Concept>").count(), "Expected balanced elements");
+        assert!(xml.contains("<skos:broader rdf:resource=\"https://example.org/ddc/51\"/>"));
+        assert!(!xml.contains(" & "), "Expected names to be escaped");
+        assert!(xml.trim_end().ends_with("</rdf:RDF>"));
+    }
+}
@@ -0,0 +1,113 @@
+//! Database column implementations, storing codes & classes as their trimmed code in text columns
+
+/// `sqlx` implementations for [DdcCode](crate::DdcCode) & [Class](crate::Class)
+#[cfg(feature = "sqlx")]
+mod sqlx_impls {
+    use sqlx::{ encode::IsNull, error::BoxDynError, Database, Decode, Encode, Type };
+
+    use crate::{ Class, DdcCode, Dewey };
+
+    impl<DB: Database> Type<DB> for DdcCode where String: Type<DB> {
+        fn type_info() -> DB::TypeInfo {
+            <String as Type<DB>>::type_info()
+        }
+
+        fn compatible(ty: &DB::TypeInfo) -> bool {
+            <String as Type<DB>>::compatible(ty)
+        }
+    }
+
+    impl<'q, DB: Database> Encode<'q, DB> for DdcCode where String: Encode<'q, DB> {
+        fn encode_by_ref(&self, buf: &mut DB::ArgumentBuffer<'q>) -> Result<IsNull, BoxDynError> {
+            self.as_str().to_owned().encode(buf)
+        }
+    }
+
+    impl<'r, DB: Database> Decode<'r, DB> for DdcCode where String: Decode<'r, DB> {
+        fn decode(value: DB::ValueRef<'r>) -> Result<Self, BoxDynError> {
+            Ok(DdcCode::new(String::decode(value)?)?)
+        }
+    }
+
+    impl<DB: Database> Type<DB> for Class where String: Type<DB> {
+        fn type_info() -> DB::TypeInfo {
+            <String as Type<DB>>::type_info()
+        }
+
+        fn compatible(ty: &DB::TypeInfo) -> bool {
+            <String as Type<DB>>::compatible(ty)
+        }
+    }
+
+    impl<'q, DB: Database> Encode<'q, DB> for Class where String: Encode<'q, DB> {
+        fn encode_by_ref(&self, buf: &mut DB::ArgumentBuffer<'q>) -> Result<IsNull, BoxDynError> {
+            self.code.to_string().encode(buf)
+        }
+    }
+
+    /// Decodes a code into its class from the standard dataset, failing on malformed codes & codes without a class
+    impl<'r, DB: Database> Decode<'r, DB> for Class where String: Decode<'r, DB> {
+        fn decode(value: DB::ValueRef<'r>) -> Result<Self, BoxDynError> {
+            Ok(Dewey::standard().try_get_class(String::decode(value)?)?)
+        }
+    }
+}
+
+/// `diesel` implementations for [DdcCode](crate::DdcCode), which derives `AsExpression` & `FromSqlRow` for [Text]
+#[cfg(feature = "diesel")]
+mod diesel_impls {
+    use diesel::{ backend::Backend, deserialize::{ self, FromSql }, serialize::{ self, Output, ToSql }, sql_types::Text };
+
+    use crate::DdcCode;
+
+    impl<DB: Backend> ToSql<Text, DB> for DdcCode where str: ToSql<Text, DB> {
+        fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+            self.as_str().to_sql(out)
+        }
+    }
+
+    /// Decodes & validates a code, failing on malformed codes
+    impl<DB: Backend> FromSql<Text, DB> for DdcCode where String: FromSql<Text, DB> {
+        fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+            Ok(DdcCode::new(String::from_sql(bytes)?)?)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[cfg(feature = "sqlx")]
+    #[test]
+    fn test_sqlx() {
+        use sqlx::{ encode::IsNull, postgres::{ PgArgumentBuffer, Postgres }, Encode, Type };
+
+        use crate::{ DdcCode, Dewey };
+
+        assert_eq!(<DdcCode as Type<Postgres>>::type_info(), <String as Type<Postgres>>::type_info());
+        assert!(<crate::Class as Type<Postgres>>::compatible(&<String as Type<Postgres>>::type_info()));
+
+        fn encode<'q>(value: &impl Encode<'q, Postgres>) -> Vec<u8> {
+            let mut buf = PgArgumentBuffer::default();
+            assert!(matches!(value.encode_by_ref(&mut buf).unwrap(), IsNull::No));
+            buf.to_vec()
+        }
+        assert_eq!(encode(&DdcCode::new("516.3").unwrap()), b"516.3");
+        assert_eq!(encode(&Dewey::standard().get_class("516").unwrap()), b"516", "Expected classes stored as their code");
+    }
+
+    #[cfg(feature = "diesel")]
+    #[test]
+    fn test_diesel() {
+        use diesel::{ dsl::sql, sql_types::Text, Connection, IntoSql, RunQueryDsl, SqliteConnection };
+
+        use crate::DdcCode;
+
+        let mut connection = SqliteConnection::establish(":memory:").unwrap();
+        let code = DdcCode::new("516.3").unwrap();
+        let stored = diesel::select(code.clone().into_sql::<Text>()).get_result::<String>(&mut connection).unwrap();
+        assert_eq!(stored, "516.3", "Expected codes stored as their trimmed code");
+        let loaded = diesel::select(code.clone().into_sql::<Text>()).get_result::<DdcCode>(&mut connection).unwrap();
+        assert_eq!(loaded, code);
+        assert!(diesel::select(sql::<Text>("'51a'")).get_result::<DdcCode>(&mut connection).is_err(), "Expected malformed codes to fail");
+    }
+}
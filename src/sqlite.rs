@@ -0,0 +1,88 @@
+use std::path::Path;
+
+use rusqlite::{ params, Connection };
+
+use crate::Dewey;
+
+/// Statements creating the table written by [Dewey::seed_sqlite], along with an index for looking up children
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS classes (
+    code TEXT PRIMARY KEY NOT NULL,
+    name TEXT NOT NULL,
+    parent_code TEXT REFERENCES classes (code),
+    depth INTEGER NOT NULL,
+    has_children INTEGER NOT NULL,
+    display_code TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS classes_parent_code ON classes (parent_code);
+";
+
+impl Dewey {
+    /// Creates a `classes` table in a SQLite database & fills it with every class, in a single transaction
+    ///
+    /// The table has the same columns as [Dewey::write_csv]: `code` (the primary key) & `parent_code` (`NULL` for main classes, referencing `code`), always trimmed since padded codes aren't unique, `name`, `depth` (see [Class::depth](crate::Class::depth)), `has_children` (`0` or `1`), and `display_code` in the crate-wide [CodeStyle](crate::CodeStyle). Existing tables are reused & rows replaced, so seeding twice is harmless.
+    ///
+    /// # Arguments
+    ///
+    /// - `connection` (`&mut Connection`) - Database to seed
+    ///
+    /// # Returns
+    ///
+    /// - `rusqlite::Result<()>` - An error if a statement fails, in which case nothing is written
+    pub fn seed_sqlite(&self, connection: &mut Connection) -> rusqlite::Result<()> {
+        let transaction = connection.transaction()?;
+        transaction.execute_batch(SCHEMA)?;
+        {
+            let mut insert = transaction.prepare(
+                "INSERT OR REPLACE INTO classes (code, name, parent_code, depth, has_children, display_code) VALUES (?1, ?2, ?3, ?4, ?5, ?6)"
+            )?;
+            for class in self.all_ref() {
+                insert.execute(params![class.code, class.name, class.parent_code(), class.depth(), class.has_children, class.display_code()])?;
+            }
+        }
+        transaction.commit()
+    }
+
+    /// Exports every class to a SQLite database file, creating it if it doesn't exist, see [Dewey::seed_sqlite]
+    ///
+    /// # Arguments
+    ///
+    /// - `path` (`impl AsRef<Path>`) - Path of the database
+    ///
+    /// # Returns
+    ///
+    /// - `rusqlite::Result<()>` - An error if the database can't be opened or written
+    pub fn export_sqlite(&self, path: impl AsRef<Path>) -> rusqlite::Result<()> {
+        self.seed_sqlite(&mut Connection::open(path)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_seed_sqlite() {
+        let dewey = Dewey::standard();
+        let mut connection = Connection::open_in_memory().unwrap();
+        dewey.seed_sqlite(&mut connection).unwrap();
+        dewey.seed_sqlite(&mut connection).unwrap();
+
+        let count: usize = connection.query_row("SELECT COUNT(*) FROM classes", [], |row| row.get(0)).unwrap();
+        assert_eq!(count, dewey.all().len(), "Expected one row per class, even when seeded twice");
+
+        let geometry: (String, Option<String>, usize, bool, String) = connection
+            .query_row("SELECT name, parent_code, depth, has_children, display_code FROM classes WHERE code = '516'", [], |row| {
+                Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?, row.get(4)?))
+            })
+            .unwrap();
+        assert_eq!(geometry, ("Geometry".to_string(), Some("51".to_string()), 2, false, "516".to_string()));
+        let mathematics: String = connection.query_row("SELECT name FROM classes WHERE code = '510'", [], |row| row.get(0)).unwrap();
+        assert_eq!(mathematics, dewey.get_class("510").unwrap().name, "Expected 51 & 510 to be separate rows");
+
+        let children: usize = connection.query_row("SELECT COUNT(*) FROM classes WHERE parent_code = '51'", [], |row| row.get(0)).unwrap();
+        assert_eq!(children, dewey.children_of("51").len());
+        let main: usize = connection.query_row("SELECT COUNT(*) FROM classes WHERE parent_code IS NULL", [], |row| row.get(0)).unwrap();
+        assert_eq!(main, dewey.categories().len());
+    }
+}
@@ -0,0 +1,142 @@
+//! Summary statistics over a dataset, for collection-development dashboards
+
+use std::collections::BTreeMap;
+
+use crate::{ Class, Dewey };
+
+/// Statistics of the subtree under a main class or division
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct CategoryStats {
+    /// Root of the subtree
+    pub class: Class,
+
+    /// Number of classes in the subtree, including its root
+    pub classes: usize,
+
+    /// Number of classes in the subtree without children
+    pub leaves: usize,
+
+    /// Depth of the deepest class in the subtree (see [Class::depth])
+    pub max_depth: usize,
+
+    /// Number of works in the subtree, according to OpenLibrary (requires the `count` feature)
+    #[cfg(feature = "count")]
+    pub works: u64,
+}
+
+impl CategoryStats {
+    fn new(class: &Class) -> Self {
+        CategoryStats {
+            class: class.clone(),
+            classes: 0,
+            leaves: 0,
+            max_depth: 0,
+            #[cfg(feature = "count")]
+            works: class.work_count,
+        }
+    }
+
+    fn add(&mut self, class: &Class) {
+        self.classes += 1;
+        self.leaves += usize::from(!class.has_children);
+        self.max_depth = self.max_depth.max(class.depth());
+    }
+}
+
+/// Statistics of a whole dataset, returned by [Dewey::stats]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct DatasetStats {
+    /// Number of classes in the dataset
+    pub classes: usize,
+
+    /// Number of classes without children
+    pub leaves: usize,
+
+    /// Depth of the deepest class (see [Class::depth])
+    pub max_depth: usize,
+
+    /// Number of works across the main classes, according to OpenLibrary (requires the `count` feature)
+    #[cfg(feature = "count")]
+    pub works: u64,
+
+    /// Statistics of each main class, in shelf order
+    pub main_classes: Vec<CategoryStats>,
+
+    /// Statistics of each division, in shelf order
+    pub divisions: Vec<CategoryStats>,
+}
+
+impl Dewey {
+    /// Computes summary statistics of this dataset, in a single pass over its classes
+    ///
+    /// # Returns
+    ///
+    /// - `DatasetStats` - Class, leaf & depth totals, overall and for each main class & division
+    pub fn stats(&self) -> DatasetStats {
+        let mut main_classes: BTreeMap<&str, CategoryStats> = BTreeMap::new();
+        let mut divisions: BTreeMap<&str, CategoryStats> = BTreeMap::new();
+        let mut stats = DatasetStats {
+            classes: 0,
+            leaves: 0,
+            max_depth: 0,
+            #[cfg(feature = "count")]
+            works: 0,
+            main_classes: Vec::new(),
+            divisions: Vec::new(),
+        };
+
+        for class in self.all_ref() {
+            stats.classes += 1;
+            stats.leaves += usize::from(!class.has_children);
+            stats.max_depth = stats.max_depth.max(class.depth());
+
+            for (categories, len) in [(&mut main_classes, 1), (&mut divisions, 2)] {
+                let Some(code) = class.code.get(..len) else {
+                    continue;
+                };
+                if code.len() == class.code.len() {
+                    categories.insert(code, CategoryStats::new(class));
+                }
+                if let Some(category) = categories.get_mut(code) {
+                    category.add(class);
+                }
+            }
+        }
+
+        #[cfg(feature = "count")]
+        {
+            stats.works = main_classes.values().map(|category| category.works).sum();
+        }
+        stats.main_classes = main_classes.into_values().collect();
+        stats.divisions = divisions.into_values().collect();
+        stats
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let dewey = Dewey::standard();
+        let stats = dewey.stats();
+        assert_eq!((stats.classes, stats.main_classes.len()), (dewey.all().len(), 10));
+        assert_eq!(stats.leaves, dewey.all_ref().filter(|class| !class.has_children).count());
+        assert_eq!(stats.main_classes.iter().map(|category| category.classes).sum::<usize>(), stats.classes);
+
+        let mathematics = stats.divisions.iter().find(|category| category.class.code == "51").unwrap();
+        assert_eq!(mathematics.classes, dewey.get_matches("51").len());
+        assert_eq!((mathematics.classes - mathematics.leaves, mathematics.max_depth), (1, 2), "Expected only the division to have children");
+        assert!(stats.divisions.iter().all(|category| category.class.code.len() == 2));
+
+        #[cfg(feature = "count")]
+        assert_eq!(stats.works, stats.main_classes.iter().map(|category| category.class.work_count).sum::<u64>());
+    }
+}
@@ -0,0 +1,83 @@
+//! `proptest` strategies generating classes & codes that exist in the standard dataset
+//!
+//! [Class] and [DdcCode] implement `proptest::arbitrary::Arbitrary` with these strategies, so `any::<Class>()` & `any::<DdcCode>()` only produce assigned codes.
+//!
+//! ```rust
+//! use dewey_decimal::{ strategy, Dewey };
+//! use proptest::{ prelude::*, test_runner::TestRunner };
+//!
+//! TestRunner::default()
+//!     .run(&strategy::prefix(), |prefix| {
+//!         prop_assert!(!Dewey::standard().get_matches(prefix).is_empty());
+//!         Ok(())
+//!     })
+//!     .unwrap();
+//! ```
+
+use std::sync::LazyLock;
+
+use proptest::{ arbitrary::Arbitrary, prelude::*, sample::{ select, Select } };
+
+use crate::{ Class, DdcCode, Dewey };
+
+static CLASSES: LazyLock<Vec<Class>> = LazyLock::new(|| Dewey::standard().all());
+
+/// Strategy generating classes from the standard dataset
+///
+/// # Returns
+///
+/// - `Select<Class>` - Strategy drawing uniformly from every [Class]
+pub fn class() -> Select<Class> {
+    select(&CLASSES[..])
+}
+
+/// Strategy generating codes that have a class in the standard dataset
+///
+/// # Returns
+///
+/// - `impl Strategy<Value = DdcCode>` - Strategy drawing uniformly from every assigned code
+pub fn code() -> impl Strategy<Value = DdcCode> {
+    class().prop_map(|class| class.ddc_code())
+}
+
+/// Strategy generating prefixes of assigned codes, which match at least one class (see [Dewey::get_matches])
+///
+/// # Returns
+///
+/// - `impl Strategy<Value = String>` - Strategy generating non-empty prefixes (ie `5`, `51` or `516`)
+pub fn prefix() -> impl Strategy<Value = String> {
+    class()
+        .prop_flat_map(|class| (1..=class.code.len()).prop_map(move |len| class.code[..len].trim_end_matches('.').to_string()))
+}
+
+impl Arbitrary for Class {
+    type Parameters = ();
+    type Strategy = Select<Class>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        class()
+    }
+}
+
+impl Arbitrary for DdcCode {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<DdcCode>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        code().boxed()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn test_strategies(class in any::<Class>(), code in any::<DdcCode>(), prefix in prefix()) {
+            prop_assert!(Dewey::standard().get_class(&class.code).is_some());
+            prop_assert!(code.class().is_some(), "Expected {code} to be assigned");
+            prop_assert!(!prefix.is_empty() && !Dewey::standard().get_matches(&prefix).is_empty(), "Expected {prefix} to match");
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use std::{ fmt, sync::atomic::{ AtomicU8, Ordering } };
+
+use crate::{ Class, Dewey };
+
+/// Style of the codes emitted by display, serialization, and export APIs
+///
+/// Codes are always stored and looked up in their trimmed form, since padding is lossy (ie both `51` and `510` pad to `510`). The style only affects output.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum CodeStyle {
+    /// Codes as stored, without padding (ie `0`, `81`, `813`)
+    #[default]
+    Trimmed,
+
+    /// Canonical DDC notation, padded to three digits (ie `000`, `810`, `813`)
+    Padded,
+}
+
+static CODE_STYLE: AtomicU8 = AtomicU8::new(0);
+
+impl CodeStyle {
+    /// Formats a code in this style
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`&str`) - Trimmed code to format
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The formatted code
+    pub fn format(&self, code: &str) -> String {
+        match self {
+            CodeStyle::Trimmed => code.to_string(),
+            CodeStyle::Padded => format!("{code:0<3}"),
+        }
+    }
+}
+
+impl Dewey {
+    /// Gets the crate-wide style of emitted codes
+    ///
+    /// # Returns
+    ///
+    /// - `CodeStyle` - The current [CodeStyle] ([CodeStyle::Trimmed] unless changed)
+    pub fn code_style() -> CodeStyle {
+        match CODE_STYLE.load(Ordering::Relaxed) {
+            1 => CodeStyle::Padded,
+            _ => CodeStyle::Trimmed,
+        }
+    }
+
+    /// Sets the crate-wide style of emitted codes
+    ///
+    /// Applies to [Class::display_code], the [fmt::Display] implementation of [Class], serialization of [Class] codes, rendered listings, and the notations & labels of exports. Exports keying or linking classes by code (ie the `code` & `parent_code` columns of [Dewey::write_csv], or the links of the static site) keep codes trimmed, since padded codes aren't unique, and add a separate `display_code` column where it's useful.
+    ///
+    /// # Arguments
+    ///
+    /// - `style` (`CodeStyle`) - The new [CodeStyle]
+    pub fn set_code_style(style: CodeStyle) {
+        CODE_STYLE.store(style as u8, Ordering::Relaxed);
+    }
+}
+
+impl Class {
+    /// Gets the code of this class, formatted in the crate-wide [CodeStyle] (see [Dewey::set_code_style])
+    ///
+    /// Use [Class::normalized_code] to always get the padded `000`–`999` form.
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The formatted code
+    pub fn display_code(&self) -> String {
+        Dewey::code_style().format(&self.code)
+    }
+}
+
+impl fmt::Display for Class {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.display_code(), self.name)
+    }
+}
+
+#[cfg(feature = "serde")]
+pub(crate) fn serialize_code<S: serde::Serializer>(code: &str, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&Dewey::code_style().format(code))
+}
+
+#[cfg(feature = "serde")]
+pub(crate) fn serialize_parent_code<S: serde::Serializer>(
+    code: &Option<std::borrow::Cow<'static, str>>,
+    serializer: S
+) -> Result<S::Ok, S::Error> {
+    match code {
+        Some(code) => serializer.serialize_some(&Dewey::code_style().format(code)),
+        None => serializer.serialize_none(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_code_style() {
+        assert_eq!(CodeStyle::Padded.format("81"), "810");
+        assert_eq!(CodeStyle::Padded.format("0"), "000");
+        assert_eq!(CodeStyle::Trimmed.format("81"), "81");
+
+        let class = Dewey::standard().get_class("81").unwrap();
+        assert_eq!(Dewey::code_style(), CodeStyle::Trimmed);
+        assert_eq!(class.to_string(), format!("81 {}", class.name));
+    }
+}
@@ -0,0 +1,169 @@
+use std::collections::{ HashMap, HashSet };
+
+use crate::{ relative_index::terms_within, search::{ keywords_starting_with, tokenize }, Class, Dewey };
+
+/// Source of raw classification guesses for free text, ie a learned classifier
+///
+/// Backends only produce codes & scores: resolving them to existing classes, merging duplicates, and ranking is left to [Dewey::suggest_for_text_with], so backends can be swapped without changing results downstream.
+pub trait SuggestBackend {
+    /// Error returned when classification fails
+    type Error: std::error::Error;
+
+    /// Guesses codes for a piece of text
+    ///
+    /// # Arguments
+    ///
+    /// - `text` (`&str`) - Text to classify (ie a title or abstract)
+    /// - `limit` (`usize`) - Maximum number of guesses wanted (backends may return more)
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Vec<(String, f64)>, Self::Error>` - Guessed codes (ie `516` or `516.3`) with their scores, higher is better
+    fn suggest(&self, text: &str, limit: usize) -> Result<Vec<(String, f64)>, Self::Error>;
+}
+
+/// Class suggested for a piece of text by [Dewey::suggest_for_text_with] or [Dewey::suggest_from_text]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct Suggestion {
+    /// The suggested [Class]
+    pub class: Class,
+
+    /// Score assigned by the backend (the best score if several guesses resolved to this class)
+    pub score: f64,
+}
+
+impl Dewey {
+    /// Suggests classes for a piece of text using a classification backend
+    ///
+    /// Every guess is resolved to the most specific existing class it falls under with [Dewey::classify] (so `516.35` resolves to `516`), guesses that don't resolve (or resolve to classes the arrangement policies leave unused, see [Dewey::arrangement_policies]) are dropped, and guesses resolving to the same class are merged, keeping the best score.
+    ///
+    /// # Arguments
+    ///
+    /// - `backend` (`&impl SuggestBackend`) - Backend producing the raw guesses
+    /// - `text` (`impl AsRef<str>`) - Text to classify
+    /// - `limit` (`usize`) - Maximum number of suggestions to return
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Vec<Suggestion>, B::Error>` - Up to `limit` [Suggestion]s, best first (ties in shelf order), or the backend's error
+    pub fn suggest_for_text_with<B: SuggestBackend>(
+        &self,
+        backend: &B,
+        text: impl AsRef<str>,
+        limit: usize
+    ) -> Result<Vec<Suggestion>, B::Error> {
+        let mut scores: HashMap<String, Suggestion> = HashMap::new();
+        for (code, score) in backend.suggest(text.as_ref(), limit)? {
+            let Some(class) = self.classify(&code).filter(|class| self.is_arranged(&class.code)) else {
+                continue;
+            };
+
+            match scores.get_mut(class.code.as_ref()) {
+                Some(existing) => existing.score = existing.score.max(score),
+                None => {
+                    scores.insert(class.code.to_string(), Suggestion { class, score });
+                }
+            }
+        }
+
+        let mut suggestions = scores.into_values().collect::<Vec<_>>();
+        suggestions.sort_by(|a, b| b.score.total_cmp(&a.score).then_with(|| a.class.code.cmp(&b.class.code)));
+        suggestions.truncate(limit);
+        Ok(suggestions)
+    }
+
+    /// Suggests classes for a title or list of subjects, as a best-guess starting point for cataloging
+    ///
+    /// The text is tokenized like a [search](Dewey::search), and every class whose name contains a token scores the token's inverse document frequency (so rare words like `geometry` outweigh common ones like `general`), halved for prefix matches. Terms of the built-in Relative Index found in the text (see [Dewey::index_lookup]) add to the class their number falls under, each word counting as much as the rarest possible caption word. Classes the arrangement policies leave unused aren't suggested (see [Dewey::arrangement_policies]).
+    ///
+    /// # Arguments
+    ///
+    /// - `text` (`impl AsRef<str>`) - Title or subjects to classify (ie `Introduction to machine learning`)
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<Suggestion>` - Every candidate [Suggestion], best first (ties in shelf order)
+    pub fn suggest_from_text(&self, text: impl AsRef<str>) -> Vec<Suggestion> {
+        let dataset = self.dataset();
+        let tokens = tokenize(text.as_ref());
+        let total = dataset.names.len() as f64;
+
+        let mut scores: HashMap<String, f64> = HashMap::new();
+        for token in tokens.iter().collect::<HashSet<_>>() {
+            let mut weights: HashMap<&str, f64> = HashMap::new();
+            for (keyword, codes) in keywords_starting_with(dataset.keywords, token) {
+                let weight = if keyword == token { 1.0 } else { 0.5 };
+                for code in codes.iter() {
+                    let best = weights.entry(code).or_default();
+                    *best = best.max(weight);
+                }
+            }
+
+            let idf = (1.0 + total / weights.len().max(1) as f64).ln();
+            for (code, weight) in weights {
+                *scores.entry(code.to_string()).or_default() += weight * idf;
+            }
+        }
+
+        for (words, number) in terms_within(&tokens) {
+            if let Some(class) = self.classify(number) {
+                *scores.entry(class.code.into_owned()).or_default() += words as f64 * (1.0 + total).ln();
+            }
+        }
+
+        let mut suggestions = scores
+            .into_iter()
+            .filter(|(code, _)| self.is_arranged(code))
+            .filter_map(|(code, score)| self.class_ref(&code).map(|class| Suggestion { class: class.clone(), score }))
+            .collect::<Vec<_>>();
+        suggestions.sort_by(|a, b| b.score.total_cmp(&a.score).then_with(|| a.class.code.cmp(&b.class.code)));
+        suggestions
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::convert::Infallible;
+
+    use super::*;
+
+    struct FixedBackend(Vec<(&'static str, f64)>);
+
+    impl SuggestBackend for FixedBackend {
+        type Error = Infallible;
+
+        fn suggest(&self, _: &str, _: usize) -> Result<Vec<(String, f64)>, Self::Error> {
+            Ok(self.0.iter().map(|(code, score)| (code.to_string(), *score)).collect())
+        }
+    }
+
+    #[test]
+    fn test_suggest_for_text_with() {
+        let backend = FixedBackend(vec![("516.35", 0.4), ("516", 0.7), ("78X", 0.2), ("abc", 0.9), ("51", 0.2)]);
+        let suggestions = Dewey::standard().suggest_for_text_with(&backend, "Algebraic geometry", 10).unwrap();
+
+        let codes = suggestions.iter().map(|suggestion| suggestion.class.code.as_ref()).collect::<Vec<_>>();
+        assert_eq!(codes, vec!["516", "51", "78"], "Expected resolved & merged guesses, best first");
+        assert_eq!(suggestions[0].score, 0.7, "Expected the best score to be kept");
+
+        let limited = Dewey::standard().suggest_for_text_with(&backend, "Algebraic geometry", 1).unwrap();
+        assert_eq!(limited.len(), 1);
+    }
+
+    #[test]
+    fn test_suggest_from_text() {
+        let dewey = Dewey::standard();
+        let best = |text: &str| dewey.suggest_from_text(text).first().map(|suggestion| suggestion.class.code.clone());
+        assert_eq!(best("Introduction to machine learning").as_deref(), Some("006"), "Expected the relative index to be consulted");
+        assert_eq!(best("A beginner's guide to beekeeping").as_deref(), Some("638"));
+        assert_eq!(best("Collected poems: American poetry").as_deref().map(|code| &code[..1]), Some("8"));
+
+        let suggestions = dewey.suggest_from_text("Euclidean geometry");
+        assert!(suggestions.windows(2).all(|pair| pair[0].score >= pair[1].score), "Expected the best suggestions first");
+        assert!(suggestions.iter().any(|suggestion| suggestion.class.code == "516"));
+        assert!(dewey.suggest_from_text("the of and").is_empty() && dewey.suggest_from_text("").is_empty());
+    }
+}
@@ -0,0 +1,183 @@
+use std::sync::{ LazyLock, RwLock };
+
+use crate::{ search::fold, Dewey };
+
+/// Synonym groups built into the crate, bridging common patron vocabulary and DDC captions
+const BUILTIN_GROUPS: &[&[&str]] = &[
+    &["sci-fi", "science fiction"],
+    &["cars", "automobiles", "motor vehicles"],
+    &["computers", "computer science", "computing", "data processing"],
+    &["maths", "math", "mathematics"],
+    &["cooking", "cookery", "food & drink"],
+    &["kids", "children"],
+    &["pets", "animals"],
+    &["bios", "biography", "biographies"],
+    &["travel", "geography & travel"],
+    &["comedy", "humor", "humour"],
+    &["poems", "poetry"],
+    &["gardening", "horticulture", "garden crops"],
+    &["religion", "faith"],
+    &["health", "medicine"],
+];
+
+static SYNONYMS: LazyLock<RwLock<Synonyms>> = LazyLock::new(|| RwLock::new(Synonyms::builtin()));
+
+/// Dictionary of synonym groups consulted when matching text against class names
+///
+/// Every term in a group is considered equivalent to every other term in that group. Terms are matched ignoring case & diacritics, on word boundaries.
+#[derive(Clone, Debug, Default)]
+pub struct Synonyms {
+    groups: Vec<Vec<String>>,
+}
+
+impl Synonyms {
+    /// Creates an empty dictionary
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - A new [Synonyms] without any groups
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a dictionary containing the built-in synonym groups
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - A new [Synonyms] with a small default set of groups
+    pub fn builtin() -> Self {
+        let mut synonyms = Self::new();
+        for group in BUILTIN_GROUPS {
+            synonyms.add_group(group.iter());
+        }
+        synonyms
+    }
+
+    /// Adds a group of equivalent terms
+    ///
+    /// # Arguments
+    ///
+    /// - `terms` (`impl IntoIterator<Item = impl AsRef<str>>`) - Terms to treat as synonyms (ie `["cars", "automobiles"]`)
+    pub fn add_group(&mut self, terms: impl IntoIterator<Item = impl AsRef<str>>) {
+        let group = terms
+            .into_iter()
+            .map(|term| fold(term.as_ref()))
+            .filter(|term| !term.is_empty())
+            .collect::<Vec<_>>();
+
+        if group.len() > 1 {
+            self.groups.push(group);
+        }
+    }
+
+    /// Gets all the registered synonym groups
+    ///
+    /// # Returns
+    ///
+    /// - `&[Vec<String>]` - Registered groups of normalized terms (lowercased, without diacritics)
+    pub fn groups(&self) -> &[Vec<String>] {
+        &self.groups
+    }
+
+    /// Expands a query into every equivalent phrasing
+    ///
+    /// # Arguments
+    ///
+    /// - `query` (`impl AsRef<str>`) - Query to expand
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<String>` - The normalized query, followed by every variant produced by substituting synonyms
+    pub fn expand(&self, query: impl AsRef<str>) -> Vec<String> {
+        let mut variants = vec![fold(query.as_ref())];
+
+        for group in &self.groups {
+            let mut expanded = Vec::new();
+            for variant in &variants {
+                for term in group.iter().filter(|term| find_word(variant, term).is_some()) {
+                    for other in group.iter().filter(|other| *other != term) {
+                        expanded.push(replace_word(variant, term, other));
+                    }
+                }
+            }
+
+            for variant in expanded {
+                if !variants.contains(&variant) {
+                    variants.push(variant);
+                }
+            }
+        }
+
+        variants
+    }
+}
+
+fn is_boundary(c: Option<char>) -> bool {
+    c.is_none_or(|c| !c.is_alphanumeric())
+}
+
+fn find_word(haystack: &str, needle: &str) -> Option<usize> {
+    haystack.match_indices(needle).map(|(index, _)| index).find(|index| {
+        is_boundary(haystack[..*index].chars().next_back()) &&
+            is_boundary(haystack[index + needle.len()..].chars().next())
+    })
+}
+
+fn replace_word(haystack: &str, needle: &str, with: &str) -> String {
+    let mut result = haystack.to_string();
+    let mut offset = 0;
+    while let Some(index) = find_word(&result[offset..], needle) {
+        let index = offset + index;
+        result.replace_range(index..index + needle.len(), with);
+        offset = index + with.len();
+    }
+    result
+}
+
+impl Dewey {
+    /// Gets a snapshot of the global synonym dictionary
+    ///
+    /// # Returns
+    ///
+    /// - `Synonyms` - The currently registered [Synonyms] (the built-in set unless replaced)
+    pub fn synonyms(&self) -> Synonyms {
+        SYNONYMS.read().unwrap().clone()
+    }
+
+    /// Registers an additional synonym group in the global dictionary
+    ///
+    /// # Arguments
+    ///
+    /// - `terms` (`impl IntoIterator<Item = impl AsRef<str>>`) - Terms to treat as synonyms (ie `["sci-fi", "science fiction"]`)
+    pub fn register_synonyms(&self, terms: impl IntoIterator<Item = impl AsRef<str>>) {
+        SYNONYMS.write().unwrap().add_group(terms);
+    }
+
+    /// Replaces the global synonym dictionary
+    ///
+    /// # Arguments
+    ///
+    /// - `synonyms` (`Synonyms`) - The new dictionary (use [Synonyms::new] to disable synonyms entirely)
+    pub fn set_synonyms(&self, synonyms: Synonyms) {
+        *SYNONYMS.write().unwrap() = synonyms;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_expand() {
+        let synonyms = Synonyms::builtin();
+        let variants = synonyms.expand("Cars");
+        assert_eq!(variants[0], "cars");
+        assert!(variants.contains(&"automobiles".to_string()), "Expected synonym");
+
+        assert_eq!(synonyms.expand("oscars").len(), 1, "Synonyms should only match whole words");
+
+        let mut synonyms = Synonyms::new();
+        synonyms.add_group(["tv", "television"]);
+        assert_eq!(synonyms.expand("tv shows"), vec!["tv shows", "television shows"]);
+    }
+}
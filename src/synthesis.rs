@@ -0,0 +1,256 @@
+use std::fmt;
+
+use crate::{ Class, DdcCode, Dewey, ParseCodeError, Table, TableEntry };
+
+/// Errors produced when building a number with a [NumberBuilder]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NumberBuildError {
+    /// The base number isn't valid DDC notation
+    InvalidBase(ParseCodeError),
+
+    /// The base number doesn't fall under any known class
+    UnknownBase(String),
+
+    /// The table has no entry with this notation
+    UnknownNotation {
+        /// Table the notation was looked up in
+        table: Table,
+
+        /// Notation that was looked up
+        notation: String,
+    },
+
+    /// Notation added from a schedule isn't made of digits only
+    InvalidDigits(String),
+
+    /// The built number ends with a zero after the decimal point, which DDC numbers never do
+    TrailingZero(String),
+
+    /// The built number doesn't fall under any known class
+    Unclassified(String),
+}
+
+impl fmt::Display for NumberBuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NumberBuildError::InvalidBase(error) => write!(f, "invalid base number: {error}"),
+            NumberBuildError::UnknownBase(base) => write!(f, "base number doesn't match any class: {base}"),
+            NumberBuildError::UnknownNotation { table, notation } => write!(f, "no notation {notation:?} in table {table}"),
+            NumberBuildError::InvalidDigits(digits) => write!(f, "notation must be digits only: {digits:?}"),
+            NumberBuildError::TrailingZero(number) => write!(f, "number {number} ends with a zero after the decimal point"),
+            NumberBuildError::Unclassified(number) => write!(f, "number doesn't match any class: {number}"),
+        }
+    }
+}
+
+impl std::error::Error for NumberBuildError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            NumberBuildError::InvalidBase(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Number built by a [NumberBuilder]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SynthesizedNumber {
+    /// The built number (ie `823.914`)
+    pub number: DdcCode,
+
+    /// The most specific [Class] the number falls under (see [Dewey::classify])
+    pub class: Class,
+
+    /// Table notation that was added to the base number, in order
+    pub notations: Vec<TableEntry>,
+}
+
+/// Builds DDC numbers by adding table (and schedule) notation to a base number, following the summary-level synthesis rules
+///
+/// Trailing zeros are dropped from the base number (ie `820` becomes `82`) before notation is appended, and the decimal point is placed after the third digit, so `51` + T1 `—09` gives `510.9` and `5` + T1 `—09` gives `509`.
+/// Errors are reported by [NumberBuilder::build], so steps can be chained freely.
+///
+/// ```rust
+/// use dewey_decimal::{ Dewey, Table };
+///
+/// // English fiction (T3A —3) of 1945-1999 (—914, from the period table under 820)
+/// let built = Dewey::standard().number_builder("820").add_notation(Table::T3A, "3").add_digits("914").build().unwrap();
+/// assert_eq!(built.number.as_str(), "823.914");
+/// assert_eq!(built.class.code, "823");
+/// ```
+#[derive(Clone, Debug)]
+pub struct NumberBuilder {
+    dewey: Dewey,
+    base: String,
+    digits: String,
+    notations: Vec<TableEntry>,
+    error: Option<NumberBuildError>,
+}
+
+impl NumberBuilder {
+    /// Creates a builder for a base number, validated against the standard dataset
+    ///
+    /// # Arguments
+    ///
+    /// - `base` (`impl AsRef<str>`) - Base number, trimmed or not (ie `82` or `820`)
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - A new [NumberBuilder]
+    pub fn new(base: impl AsRef<str>) -> Self {
+        Dewey::standard().number_builder(base)
+    }
+
+    /// Adds notation from an auxiliary table
+    ///
+    /// # Arguments
+    ///
+    /// - `entry` (`TableEntry`) - Table entry to add
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The updated [NumberBuilder]
+    pub fn add_table(mut self, entry: TableEntry) -> Self {
+        self.digits.push_str(entry.notation);
+        self.notations.push(entry);
+        self
+    }
+
+    /// Adds notation from an auxiliary table, looked up by notation
+    ///
+    /// # Arguments
+    ///
+    /// - `table` (`Table`) - Table to look the notation up in
+    /// - `notation` (`impl AsRef<str>`) - Notation, with or without its leading dash (ie `—09`)
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The updated [NumberBuilder]
+    pub fn add_notation(self, table: Table, notation: impl AsRef<str>) -> Self {
+        match table.get(&notation) {
+            Some(entry) => self.add_table(entry),
+            None => self.fail(NumberBuildError::UnknownNotation { table, notation: notation.as_ref().to_string() }),
+        }
+    }
+
+    /// Adds digits from a schedule, where an "add to base number" instruction or an internal table calls for it (ie period notation under a literature)
+    ///
+    /// # Arguments
+    ///
+    /// - `digits` (`impl AsRef<str>`) - Digits to add (ie `914`)
+    ///
+    /// # Returns
+    ///
+    /// - `Self` - The updated [NumberBuilder]
+    pub fn add_digits(mut self, digits: impl AsRef<str>) -> Self {
+        let digits = digits.as_ref().trim();
+        if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+            return self.fail(NumberBuildError::InvalidDigits(digits.to_string()));
+        }
+        self.digits.push_str(digits);
+        self
+    }
+
+    /// Builds & validates the number
+    ///
+    /// # Returns
+    ///
+    /// - `Result<SynthesizedNumber, NumberBuildError>` - The built number & the [Class] it falls under, or the first error encountered
+    pub fn build(self) -> Result<SynthesizedNumber, NumberBuildError> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+
+        let number = format_number(&format!("{}{}", self.base, self.digits));
+        if number.contains('.') && number.ends_with('0') {
+            return Err(NumberBuildError::TrailingZero(number));
+        }
+        let class = self.dewey.classify(&number).ok_or_else(|| NumberBuildError::Unclassified(number.clone()))?;
+        let number = DdcCode::new(&number).map_err(NumberBuildError::InvalidBase)?;
+        Ok(SynthesizedNumber { number, class, notations: self.notations })
+    }
+
+    fn fail(mut self, error: NumberBuildError) -> Self {
+        self.error.get_or_insert(error);
+        self
+    }
+}
+
+/// Places the decimal point in a run of digits, padding it to three digits (ie `5109` to `510.9`, `82` to `820`)
+fn format_number(digits: &str) -> String {
+    if digits.len() <= 3 {
+        format!("{digits:0<3}")
+    } else {
+        format!("{}.{}", &digits[..3], &digits[3..])
+    }
+}
+
+/// Gets the base number of a code, dropping its decimal point & the trailing zeros of whole numbers (ie `82` for `820`, `5163` for `516.3`)
+fn base_number(code: &DdcCode) -> String {
+    let code = code.as_str();
+    if code.contains('.') {
+        return code.replace('.', "");
+    }
+    match code.trim_end_matches('0') {
+        "" => "0".to_string(),
+        base => base.to_string(),
+    }
+}
+
+impl Dewey {
+    /// Creates a [NumberBuilder] for a base number, validated against this dataset
+    ///
+    /// # Arguments
+    ///
+    /// - `base` (`impl AsRef<str>`) - Base number, trimmed or not (ie `82` or `820`)
+    ///
+    /// # Returns
+    ///
+    /// - `NumberBuilder` - A new [NumberBuilder]
+    pub fn number_builder(&self, base: impl AsRef<str>) -> NumberBuilder {
+        let builder = NumberBuilder { dewey: *self, base: String::new(), digits: String::new(), notations: Vec::new(), error: None };
+        let code = match DdcCode::new(base) {
+            Ok(code) => code,
+            Err(error) => return builder.fail(NumberBuildError::InvalidBase(error)),
+        };
+        if self.classify(&code).is_none() {
+            return builder.fail(NumberBuildError::UnknownBase(code.to_string()));
+        }
+        NumberBuilder { base: base_number(&code), ..builder }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_number_builder() {
+        let dewey = Dewey::standard();
+        let build = |base: &str, notations: &[(Table, &str)]| {
+            notations.iter()
+                .fold(dewey.number_builder(base), |builder, (table, notation)| builder.add_notation(*table, notation))
+                .build()
+                .map(|built| built.number.to_string())
+        };
+        assert_eq!(build("516", &[(Table::T1, "09")]).as_deref(), Ok("516.09"));
+        assert_eq!(build("510", &[(Table::T1, "09")]).as_deref(), Ok("510.9"), "Expected the base's trailing zero to be dropped");
+        assert_eq!(build("5", &[(Table::T1, "—09")]).as_deref(), Ok("509"));
+        assert_eq!(build("51", &[(Table::T1, "09"), (Table::T2, "73")]).as_deref(), Ok("510.973"));
+        assert_eq!(build("9", &[(Table::T2, "-73")]).as_deref(), Ok("973"));
+
+        let built = NumberBuilder::new("820").add_notation(Table::T3A, "3").add_digits("914").build().unwrap();
+        assert_eq!((built.number.as_str(), built.class.code.as_ref()), ("823.914", "823"));
+        assert_eq!(built.notations, [Table::T3A.get("3").unwrap()]);
+
+        assert_eq!(build("5a", &[]).unwrap_err(), NumberBuildError::InvalidBase(ParseCodeError::InvalidCharacter { character: 'a', index: 1 }));
+        assert_eq!(
+            build("516", &[(Table::T1, "9"), (Table::T2, "0")]).unwrap_err(),
+            NumberBuildError::UnknownNotation { table: Table::T1, notation: "9".to_string() },
+            "Expected the first error to be kept"
+        );
+        assert_eq!(dewey.number_builder("516").add_digits("3a").build().unwrap_err(), NumberBuildError::InvalidDigits("3a".to_string()));
+        assert_eq!(build("516", &[(Table::T1, "090")]).unwrap_err(), NumberBuildError::TrailingZero("516.090".to_string()));
+    }
+}
@@ -0,0 +1,353 @@
+use std::{ fmt, str::FromStr };
+
+/// One of the DDC's auxiliary tables, whose notation is added to schedule numbers to build more specific ones (ie `T1—09` added to `516` gives `516.09`)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "UPPERCASE"))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum Table {
+    /// Standard subdivisions (ie `—09` History, geographic treatment, biography)
+    T1,
+
+    /// Geographic areas, historical periods, biography (ie `—73` United States)
+    T2,
+
+    /// Subdivisions for works by or about individual authors
+    T3A,
+
+    /// Subdivisions for works by or about more than one author
+    T3B,
+
+    /// Notation to be added where instructed in Table 3B, 700.4, 791.4, 808–809
+    T3C,
+
+    /// Subdivisions of individual languages and language families
+    T4,
+
+    /// Ethnic and national groups
+    T5,
+
+    /// Languages
+    T6,
+}
+
+impl Table {
+    /// Every table, in order
+    pub const ALL: [Table; 8] = [Table::T1, Table::T2, Table::T3A, Table::T3B, Table::T3C, Table::T4, Table::T5, Table::T6];
+
+    /// Gets the name of this table
+    ///
+    /// # Returns
+    ///
+    /// - `&'static str` - The table's name (ie `Standard subdivisions`)
+    pub fn name(&self) -> &'static str {
+        match self {
+            Table::T1 => "Standard subdivisions",
+            Table::T2 => "Geographic areas, historical periods, biography",
+            Table::T3A => "Subdivisions for works by or about individual authors",
+            Table::T3B => "Subdivisions for works by or about more than one author",
+            Table::T3C => "Notation to be added where instructed in Table 3B, 700.4, 791.4, 808-809",
+            Table::T4 => "Subdivisions of individual languages and language families",
+            Table::T5 => "Ethnic and national groups",
+            Table::T6 => "Languages",
+        }
+    }
+
+    /// Gets every entry of this table, in shelf order
+    ///
+    /// # Returns
+    ///
+    /// - `impl Iterator<Item = TableEntry>` - Iterator over the table's entries
+    pub fn entries(&self) -> impl Iterator<Item = TableEntry> + use<> {
+        let table = *self;
+        TABLE_ENTRIES.iter().filter(move |entry| entry.table == table).copied()
+    }
+
+    /// Gets an entry of this table by notation
+    ///
+    /// # Arguments
+    ///
+    /// - `notation` (`impl AsRef<str>`) - Notation, with or without its leading dash (ie `09`, `-09` or `—09`)
+    ///
+    /// # Returns
+    ///
+    /// - `Option<TableEntry>` - The matching [TableEntry], or [None] if this table has no such entry
+    pub fn get(&self, notation: impl AsRef<str>) -> Option<TableEntry> {
+        let notation = strip_dash(notation.as_ref());
+        self.entries().find(|entry| entry.notation == notation)
+    }
+
+    /// Gets the top-level entries of this table (those without a parent)
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<TableEntry>` - [Vec] of the table's top-level entries, in shelf order
+    pub fn top_level(&self) -> Vec<TableEntry> {
+        self.entries().filter(|entry| entry.parent().is_none()).collect()
+    }
+
+    /// Gets the direct children of an entry of this table
+    ///
+    /// # Arguments
+    ///
+    /// - `notation` (`impl AsRef<str>`) - Notation of the parent entry
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<TableEntry>` - [Vec] of child entries, in shelf order (empty if the entry doesn't exist or has no children)
+    pub fn children(&self, notation: impl AsRef<str>) -> Vec<TableEntry> {
+        let Some(parent) = self.get(notation) else {
+            return Vec::new();
+        };
+        self.entries().filter(|entry| entry.parent() == Some(parent)).collect()
+    }
+}
+
+impl fmt::Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+impl FromStr for Table {
+    type Err = ParseTableError;
+
+    /// Parses a table name, ignoring case (ie `T1` or `t3a`)
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Table::ALL.into_iter()
+            .find(|table| table.to_string().eq_ignore_ascii_case(s.trim()))
+            .ok_or_else(|| ParseTableError(s.to_string()))
+    }
+}
+
+/// Error produced when parsing an unknown [Table] name, holding the input
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseTableError(pub String);
+
+impl fmt::Display for ParseTableError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown DDC table: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for ParseTableError {}
+
+/// Entry of one of the auxiliary [Table]s
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TableEntry {
+    /// Table this entry belongs to
+    pub table: Table,
+
+    /// Notation, without its leading dash (ie `09`, written `T1—09`)
+    pub notation: &'static str,
+
+    /// Name of the entry (ie `History, geographic treatment, biography`)
+    pub name: &'static str,
+}
+
+impl TableEntry {
+    /// Parses a full table notation into its entry (ie `T2—73`, `T2--73` or `t2-73`)
+    ///
+    /// # Arguments
+    ///
+    /// - `notation` (`impl AsRef<str>`) - Table name, followed by the entry's notation
+    ///
+    /// # Returns
+    ///
+    /// - `Option<TableEntry>` - The matching [TableEntry], or [None] if the table or entry doesn't exist
+    pub fn parse(notation: impl AsRef<str>) -> Option<TableEntry> {
+        let notation = notation.as_ref().trim();
+        let split = notation.find(['-', '—', '–'])?;
+        notation[..split].parse::<Table>().ok()?.get(&notation[split..])
+    }
+
+    /// Gets the parent of this entry, which is the entry with the longest notation this one starts with
+    ///
+    /// # Returns
+    ///
+    /// - `Option<TableEntry>` - The parent [TableEntry], or [None] for top-level entries
+    pub fn parent(&self) -> Option<TableEntry> {
+        (1..self.notation.len()).rev().find_map(|length| self.table.get(&self.notation[..length]))
+    }
+
+    /// Gets the ancestors of this entry, starting from its parent
+    ///
+    /// # Returns
+    ///
+    /// - `impl Iterator<Item = TableEntry>` - Iterator walking up to the entry's top-level ancestor
+    pub fn ancestors(&self) -> impl Iterator<Item = TableEntry> + use<> {
+        std::iter::successors(self.parent(), TableEntry::parent)
+    }
+
+    /// Gets the direct children of this entry
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<TableEntry>` - See [Table::children]
+    pub fn children(&self) -> Vec<TableEntry> {
+        self.table.children(self.notation)
+    }
+}
+
+impl fmt::Display for TableEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}—{}", self.table, self.notation)
+    }
+}
+
+fn strip_dash(notation: &str) -> &str {
+    notation.trim().trim_start_matches(['-', '—', '–'])
+}
+
+/// Summary of the auxiliary tables, following DDC 23, in shelf order within each table
+const TABLE_ENTRIES: &[TableEntry] = {
+    const fn entry(table: Table, notation: &'static str, name: &'static str) -> TableEntry {
+        TableEntry { table, notation, name }
+    }
+    use Table::*;
+    &[
+        entry(T1, "01", "Philosophy and theory"),
+        entry(T1, "014", "Language and communication"),
+        entry(T1, "015", "Mathematical principles"),
+        entry(T1, "019", "Psychological principles"),
+        entry(T1, "02", "Miscellany"),
+        entry(T1, "022", "Illustrations, models, miniatures"),
+        entry(T1, "028", "Auxiliary techniques and procedures; apparatus, equipment, materials"),
+        entry(T1, "0285", "Computer applications"),
+        entry(T1, "03", "Dictionaries, encyclopedias, concordances"),
+        entry(T1, "04", "Special topics"),
+        entry(T1, "05", "Serial publications"),
+        entry(T1, "06", "Organizations and management"),
+        entry(T1, "07", "Education, research, related topics"),
+        entry(T1, "071", "Education"),
+        entry(T1, "072", "Research; statistical methods"),
+        entry(T1, "08", "Groups of people"),
+        entry(T1, "09", "History, geographic treatment, biography"),
+        entry(T1, "090", "Historical periods"),
+        entry(T1, "091", "Areas, regions, places in general"),
+        entry(T1, "092", "Biography"),
+        entry(T1, "093", "Ancient world"),
+        entry(T1, "094", "Europe"),
+        entry(T1, "095", "Asia"),
+        entry(T1, "096", "Africa"),
+        entry(T1, "097", "North America"),
+        entry(T1, "098", "South America"),
+        entry(T1, "099", "Other parts of the world"),
+        entry(T2, "1", "Areas, regions, places in general; oceans and seas"),
+        entry(T2, "2", "Biography"),
+        entry(T2, "3", "Ancient world"),
+        entry(T2, "4", "Europe"),
+        entry(T2, "41", "British Isles"),
+        entry(T2, "42", "England and Wales"),
+        entry(T2, "43", "Germany and neighboring central European countries"),
+        entry(T2, "44", "France and Monaco"),
+        entry(T2, "45", "Italy, San Marino, Vatican City, Malta"),
+        entry(T2, "46", "Spain, Andorra, Gibraltar, Portugal"),
+        entry(T2, "47", "Russia and neighboring east European countries"),
+        entry(T2, "48", "Scandinavia"),
+        entry(T2, "49", "Other parts of Europe"),
+        entry(T2, "5", "Asia"),
+        entry(T2, "51", "China and adjacent areas"),
+        entry(T2, "52", "Japan"),
+        entry(T2, "53", "Arabian Peninsula and adjacent areas"),
+        entry(T2, "54", "India and neighboring south Asian countries"),
+        entry(T2, "55", "Iran"),
+        entry(T2, "56", "Middle East (Near East)"),
+        entry(T2, "57", "Siberia (Asiatic Russia)"),
+        entry(T2, "58", "Central Asia"),
+        entry(T2, "59", "Southeast Asia"),
+        entry(T2, "6", "Africa"),
+        entry(T2, "7", "North America"),
+        entry(T2, "71", "Canada"),
+        entry(T2, "72", "Mexico, Central America, West Indies, Bermuda"),
+        entry(T2, "73", "United States"),
+        entry(T2, "74", "Northeastern United States"),
+        entry(T2, "75", "Southeastern United States"),
+        entry(T2, "76", "South central United States"),
+        entry(T2, "77", "North central United States"),
+        entry(T2, "78", "Western United States"),
+        entry(T2, "79", "Great Basin and Pacific Slope region of United States"),
+        entry(T2, "8", "South America"),
+        entry(T2, "9", "Australasia, Pacific Ocean islands, Atlantic Ocean islands, Arctic islands, Antarctica, extraterrestrial worlds"),
+        entry(T3A, "1", "Poetry"),
+        entry(T3A, "2", "Drama"),
+        entry(T3A, "3", "Fiction"),
+        entry(T3A, "4", "Essays"),
+        entry(T3A, "5", "Speeches"),
+        entry(T3A, "6", "Letters"),
+        entry(T3A, "8", "Miscellaneous writings"),
+        entry(T3B, "01", "Philosophy and theory"),
+        entry(T3B, "02", "Miscellany"),
+        entry(T3B, "08", "Collections of literary texts in more than one form"),
+        entry(T3B, "09", "History, description, critical appraisal of works in more than one form"),
+        entry(T3B, "1", "Poetry"),
+        entry(T3B, "2", "Drama"),
+        entry(T3B, "3", "Fiction"),
+        entry(T3B, "4", "Essays"),
+        entry(T3B, "5", "Speeches"),
+        entry(T3B, "6", "Letters"),
+        entry(T3B, "7", "Humor and satire"),
+        entry(T3B, "8", "Miscellaneous writings"),
+        entry(T3C, "3", "Specific themes and subjects"),
+        entry(T3C, "8", "Literature for and by ethnic and national groups"),
+        entry(T3C, "9", "Literature for and by other groups of people"),
+        entry(T4, "01", "Philosophy and theory"),
+        entry(T4, "1", "Writing systems, phonology, phonetics of the standard form of the language"),
+        entry(T4, "2", "Etymology of the standard form of the language"),
+        entry(T4, "3", "Dictionaries of the standard form of the language"),
+        entry(T4, "5", "Grammar of the standard form of the language"),
+        entry(T4, "7", "Historical and geographic variations, modern nongeographic variations"),
+        entry(T4, "8", "Standard usage of the language (prescriptive linguistics)"),
+        entry(T5, "1", "North Americans"),
+        entry(T5, "2", "British, English, Anglo-Saxons"),
+        entry(T5, "3", "Germanic peoples"),
+        entry(T5, "4", "Modern Latin peoples"),
+        entry(T5, "5", "Italians, Romanians, related groups"),
+        entry(T5, "6", "Spanish, Portuguese, Catalans, related groups"),
+        entry(T5, "7", "Other Italic peoples"),
+        entry(T5, "8", "Greeks and related groups"),
+        entry(T5, "9", "Other ethnic and national groups"),
+        entry(T6, "1", "Indo-European languages"),
+        entry(T6, "2", "English and Old English (Anglo-Saxon)"),
+        entry(T6, "3", "Germanic languages"),
+        entry(T6, "4", "Romance languages"),
+        entry(T6, "5", "Italian, Dalmatian, Romanian, Rhaetian, Sardinian, Corsican"),
+        entry(T6, "6", "Spanish, Portuguese, Galician"),
+        entry(T6, "7", "Italic languages"),
+        entry(T6, "8", "Hellenic languages"),
+        entry(T6, "9", "Other languages"),
+    ]
+};
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_tables() {
+        let history = Table::T1.get("-09").unwrap();
+        assert_eq!(history.name, "History, geographic treatment, biography");
+        assert_eq!(history.to_string(), "T1—09");
+        assert_eq!(Table::T1.get("—09"), Some(history), "Expected an em dash to be accepted");
+        assert!(Table::T1.get("-9").is_none(), "T1 notation starts with 0");
+
+        let biography = Table::T1.get("092").unwrap();
+        assert_eq!(biography.parent(), Some(history));
+        assert_eq!(Table::T1.get("0285").unwrap().ancestors().map(|entry| entry.notation).collect::<Vec<_>>(), ["028", "02"]);
+        assert!(history.children().contains(&biography));
+        assert!(Table::T2.top_level().iter().all(|entry| entry.notation.len() == 1), "Expected T2's top level to be single digits");
+
+        assert_eq!(TableEntry::parse("T2--73").map(|entry| entry.name), Some("United States"));
+        assert_eq!(TableEntry::parse("t3b—3").map(|entry| entry.table), Some(Table::T3B));
+        assert!(TableEntry::parse("T7-1").is_none() && TableEntry::parse("T1").is_none());
+        assert_eq!("t3c".parse::<Table>(), Ok(Table::T3C));
+        assert_eq!("T7".parse::<Table>(), Err(ParseTableError("T7".to_string())));
+
+        for table in Table::ALL {
+            let entries = table.entries().collect::<Vec<_>>();
+            assert!(!entries.is_empty(), "Expected {table} to have entries");
+            assert!(entries.windows(2).all(|pair| pair[0].notation < pair[1].notation), "Expected {table} to be in shelf order");
+        }
+    }
+}
@@ -0,0 +1,82 @@
+//! Ready-made Tauri commands over the standard dataset, for desktop catalog apps
+//!
+//! Register every command with [handler], or list them with the app's own commands (ie `tauri::generate_handler![dewey_decimal::tauri::get_class, my_command]`). TypeScript wrappers calling them, along with the declarations of the types they return, are generated by `bindings::export_tauri_bindings`.
+//!
+//! ```rust,ignore
+//! tauri::Builder::default()
+//!     .invoke_handler(dewey_decimal::tauri::handler())
+//!     .run(tauri::generate_context!())
+//!     .expect("error while running tauri application");
+//! ```
+//!
+//! Commands are run off the main thread, so building large trees doesn't block the UI.
+
+use ::tauri::{ ipc::Invoke, Runtime };
+
+use crate::{ Class, ClassTree, Dewey };
+
+/// Gets a class by exact code match (see [Dewey::get_class])
+#[::tauri::command(async)]
+pub fn get_class(code: String) -> Option<Class> {
+    Dewey::standard().get_class(code)
+}
+
+/// Gets the direct children of a class, or the main classes for an empty code (see [Dewey::children_of])
+#[::tauri::command(async)]
+pub fn children(code: String) -> Vec<Class> {
+    Dewey::standard().children_of(code).to_vec()
+}
+
+/// Searches class names by keyword, keeping up to `limit` results (see [Dewey::search])
+#[::tauri::command(async)]
+pub fn search(query: String, limit: Option<usize>) -> Vec<Class> {
+    let mut results = Dewey::standard().search(query);
+    if let Some(limit) = limit {
+        results.truncate(limit);
+    }
+    results
+}
+
+/// Builds the subtree of a class, or the whole classification for an empty code (see [Dewey::subtree] & [Dewey::to_tree])
+#[::tauri::command(async)]
+pub fn tree(code: String) -> Vec<ClassTree> {
+    let dewey = Dewey::standard();
+    if code.trim().is_empty() {
+        dewey.to_tree()
+    } else {
+        dewey.subtree(code).into_iter().collect()
+    }
+}
+
+/// Builds an invoke handler registering every command of this module
+///
+/// # Returns
+///
+/// - `impl Fn(Invoke<R>) -> bool` - The handler, to pass to `tauri::Builder::invoke_handler`
+pub fn handler<R: Runtime>() -> impl Fn(Invoke<R>) -> bool + Send + Sync + 'static {
+    ::tauri::generate_handler![get_class, children, search, tree]
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_commands() {
+        let dewey = Dewey::standard();
+        assert_eq!(get_class("516".to_string()).map(|class| class.name), dewey.get_class("516").map(|class| class.name));
+        assert!(get_class("abc".to_string()).is_none());
+
+        assert_eq!(children(String::new()).len(), dewey.categories().len());
+        assert_eq!(children("51".to_string()).len(), dewey.children_of("51").len());
+
+        assert!(search("geometry".to_string(), Some(2)).len() <= 2);
+        assert_eq!(search("geometry".to_string(), None).len(), dewey.search("geometry").len());
+
+        assert_eq!(tree(" ".to_string()).len(), dewey.categories().len());
+        let subtree = tree("516".to_string());
+        assert_eq!(subtree.len(), 1);
+        assert_eq!(subtree[0].class.code.as_ref(), "516");
+        assert!(tree("999999".to_string()).is_empty());
+    }
+}
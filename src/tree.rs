@@ -0,0 +1,75 @@
+use crate::{ Class, Dewey };
+
+/// Node of the nested classification tree produced by [Dewey::to_tree], ie for rendering an explorer widget from a single payload
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+pub struct ClassTree {
+    /// The [Class] at this node
+    pub class: Class,
+
+    /// Subtrees of the class's direct children, in shelf order
+    pub children: Vec<ClassTree>,
+}
+
+impl Dewey {
+    fn tree_of(&self, class: &'static Class) -> ClassTree {
+        ClassTree {
+            class: class.clone(),
+            children: self.children_of(&class.code).iter().map(|child| self.tree_of(child)).collect(),
+        }
+    }
+
+    /// Builds the entire classification as a nested tree
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<ClassTree>` - One [ClassTree] per main class, in shelf order
+    pub fn to_tree(&self) -> Vec<ClassTree> {
+        self.children_of("").iter().map(|class| self.tree_of(class)).collect()
+    }
+
+    /// Builds the subtree of a class as a nested tree
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code of the subtree's root
+    ///
+    /// # Returns
+    ///
+    /// - `Option<ClassTree>` - The [ClassTree] rooted at the class, or [None] if the class doesn't exist
+    pub fn subtree(&self, code: impl AsRef<str>) -> Option<ClassTree> {
+        self.class_ref(code).map(|class| self.tree_of(class))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn size(tree: &ClassTree) -> usize {
+        1 + tree.children.iter().map(size).sum::<usize>()
+    }
+
+    #[test]
+    fn test_to_tree() {
+        let dewey = Dewey::standard();
+        let tree = dewey.to_tree();
+        assert_eq!(tree.len(), dewey.categories().len(), "Expected one tree per main class");
+        assert_eq!(tree.iter().map(size).sum::<usize>(), dewey.all().len(), "Expected every class once");
+
+        let subtree = dewey.subtree("51").unwrap();
+        assert_eq!(subtree.children.iter().map(|child| child.class.clone()).collect::<Vec<_>>(), dewey.get_direct_children("51"));
+        assert!(subtree.children.iter().all(|child| child.children.len() == child.class.child_count));
+        assert!(dewey.subtree("008").is_none(), "This code is unused!");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_tree_json() {
+        let json = serde_json::to_value(Dewey::standard().subtree("51").unwrap()).unwrap();
+        assert_eq!(json["class"]["code"], "51");
+        assert_eq!(json["children"][0]["class"]["code"], "510");
+    }
+}
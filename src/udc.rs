@@ -0,0 +1,227 @@
+use crate::{ Class, Dewey };
+
+/// Correspondence between a DDC class and Universal Decimal Classification notation
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case", tag = "kind", content = "notation"))]
+pub enum UdcMapping {
+    /// The UDC class covers the same subject (ie `53` Physics), even if its notation differs (ie `811.111` for English language)
+    Aligned(&'static str),
+
+    /// The closest UDC class only partly covers the subject, being broader or narrower (ie `81` for DDC's `4` Language, which UDC groups with literature)
+    Approximate(&'static str),
+
+    /// The schemes diverge, with no UDC class close enough to map to (ie DDC's `38` Commerce, communications & transportation, which UDC spreads across economics & technology)
+    Divergent,
+}
+
+impl UdcMapping {
+    /// Gets the UDC notation of this mapping
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&'static str>` - The notation, or [None] if the schemes diverge
+    pub fn notation(&self) -> Option<&'static str> {
+        match self {
+            UdcMapping::Aligned(notation) | UdcMapping::Approximate(notation) => Some(notation),
+            UdcMapping::Divergent => None,
+        }
+    }
+}
+
+/// UDC mappings of DDC main classes & divisions
+const UDC_MAPPINGS: &[(&str, UdcMapping)] = {
+    use UdcMapping::*;
+    &[
+        ("0", Aligned("0")),
+        ("00", Approximate("00")),
+        ("01", Aligned("01")),
+        ("02", Aligned("02")),
+        ("03", Aligned("03")),
+        ("05", Aligned("05")),
+        ("06", Aligned("06")),
+        ("07", Approximate("070")),
+        ("08", Aligned("08")),
+        ("09", Aligned("09")),
+        ("1", Aligned("1")),
+        ("10", Approximate("1")),
+        ("11", Aligned("11")),
+        ("12", Approximate("165")),
+        ("13", Approximate("13")),
+        ("14", Aligned("14")),
+        ("15", Aligned("159.9")),
+        ("16", Aligned("16")),
+        ("17", Aligned("17")),
+        ("18", Approximate("1(091)")),
+        ("19", Approximate("1(091)")),
+        ("2", Aligned("2")),
+        ("20", Approximate("2")),
+        ("21", Approximate("2-1")),
+        ("22", Aligned("27-23")),
+        ("23", Approximate("27")),
+        ("24", Approximate("27")),
+        ("25", Approximate("27")),
+        ("26", Approximate("27")),
+        ("27", Approximate("27")),
+        ("28", Approximate("27")),
+        ("29", Approximate("22/29")),
+        ("3", Aligned("3")),
+        ("30", Approximate("316")),
+        ("31", Aligned("31")),
+        ("32", Aligned("32")),
+        ("33", Aligned("33")),
+        ("34", Aligned("34")),
+        ("35", Aligned("35")),
+        ("36", Aligned("36")),
+        ("37", Aligned("37")),
+        ("38", Divergent),
+        ("39", Aligned("39")),
+        ("4", Approximate("81")),
+        ("40", Approximate("81")),
+        ("41", Approximate("81'1")),
+        ("42", Aligned("811.111")),
+        ("43", Aligned("811.112.2")),
+        ("44", Aligned("811.133.1")),
+        ("45", Aligned("811.131.1")),
+        ("46", Aligned("811.134.2")),
+        ("47", Aligned("811.124")),
+        ("48", Aligned("811.14")),
+        ("49", Approximate("811")),
+        ("5", Aligned("5")),
+        ("50", Aligned("50")),
+        ("51", Aligned("51")),
+        ("52", Aligned("52")),
+        ("53", Aligned("53")),
+        ("54", Aligned("54")),
+        ("55", Aligned("55")),
+        ("56", Aligned("56")),
+        ("57", Aligned("57")),
+        ("58", Aligned("58")),
+        ("59", Aligned("59")),
+        ("6", Aligned("6")),
+        ("60", Aligned("60")),
+        ("61", Aligned("61")),
+        ("62", Aligned("62")),
+        ("63", Aligned("63")),
+        ("64", Aligned("64")),
+        ("65", Aligned("65")),
+        ("66", Aligned("66")),
+        ("67", Aligned("67")),
+        ("68", Aligned("68")),
+        ("69", Aligned("69")),
+        ("7", Aligned("7")),
+        ("70", Approximate("7")),
+        ("71", Aligned("71")),
+        ("72", Aligned("72")),
+        ("73", Aligned("73")),
+        ("74", Aligned("74")),
+        ("75", Aligned("75")),
+        ("76", Aligned("76")),
+        ("77", Aligned("77")),
+        ("78", Aligned("78")),
+        ("79", Aligned("79")),
+        ("8", Approximate("82")),
+        ("80", Approximate("82")),
+        ("81", Aligned("821.111(73)")),
+        ("82", Aligned("821.111")),
+        ("83", Aligned("821.112.2")),
+        ("84", Aligned("821.133.1")),
+        ("85", Aligned("821.131.1")),
+        ("86", Aligned("821.134.2")),
+        ("87", Aligned("821.124")),
+        ("88", Aligned("821.14")),
+        ("89", Approximate("821")),
+        ("9", Aligned("9")),
+        ("90", Approximate("93/94")),
+        ("91", Aligned("91")),
+        ("92", Approximate("929")),
+        ("93", Aligned("94(3)")),
+        ("94", Aligned("94(4)")),
+        ("95", Aligned("94(5)")),
+        ("96", Aligned("94(6)")),
+        ("97", Aligned("94(7)")),
+        ("98", Aligned("94(8)")),
+        ("99", Aligned("94(9)")),
+    ]
+};
+
+fn mapping_of(code: &str) -> Option<UdcMapping> {
+    UDC_MAPPINGS.iter().find(|(ddc, _)| *ddc == code).map(|(_, mapping)| *mapping)
+}
+
+impl Dewey {
+    /// Maps a class to Universal Decimal Classification notation
+    ///
+    /// Main classes & divisions are mapped individually. Sections map to their division's notation, which is at best [UdcMapping::Approximate] since UDC subdivides differently below that level.
+    ///
+    /// # Arguments
+    ///
+    /// - `code` (`impl AsRef<str>`) - Code of the class
+    ///
+    /// # Returns
+    ///
+    /// - `Option<UdcMapping>` - How the class maps to UDC, or [None] if the class doesn't exist or is below the section level
+    pub fn to_udc(&self, code: impl AsRef<str>) -> Option<UdcMapping> {
+        let class = self.class_ref(code)?;
+        match class.code.len() {
+            1 | 2 => mapping_of(&class.code),
+            3 => mapping_of(&class.code[..2]).map(|mapping| match mapping {
+                UdcMapping::Aligned(notation) => UdcMapping::Approximate(notation),
+                mapping => mapping,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Gets the DDC class closest to a UDC notation (ie `42` English for `811.111`)
+    ///
+    /// The mapping with the longest notation the input starts with is used, so auxiliaries & subdivisions are ignored (ie `53.02` matches `53`).
+    ///
+    /// # Arguments
+    ///
+    /// - `notation` (`impl AsRef<str>`) - UDC notation
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Class>` - The closest [Class], or [None] if no mapped notation matches
+    pub fn from_udc(&self, notation: impl AsRef<str>) -> Option<Class> {
+        let notation = notation.as_ref().trim();
+        // Reversed, so the first of several equally good mappings wins
+        UDC_MAPPINGS.iter()
+            .rev()
+            .filter(|(_, mapping)| mapping.notation().is_some_and(|udc| notation.starts_with(udc)))
+            .max_by_key(|(ddc, mapping)| {
+                (mapping.notation().map_or(0, str::len), matches!(mapping, UdcMapping::Aligned(_)), std::cmp::Reverse(ddc.len()))
+            })
+            .and_then(|(ddc, _)| self.get_class(ddc))
+    }
+}
+
+impl Class {
+    /// See [Dewey::to_udc]
+    pub fn to_udc(&self) -> Option<UdcMapping> {
+        Dewey::standard().to_udc(&self.code)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_udc() {
+        let dewey = Dewey::standard();
+        assert_eq!(dewey.to_udc("53"), Some(UdcMapping::Aligned("53")));
+        assert_eq!(dewey.to_udc("4"), Some(UdcMapping::Approximate("81")), "Expected language to diverge partially");
+        assert_eq!(dewey.to_udc("38"), Some(UdcMapping::Divergent));
+        assert_eq!(dewey.get_class("516").unwrap().to_udc(), Some(UdcMapping::Approximate("51")), "Expected sections to map approximately");
+        assert_eq!(dewey.to_udc("008"), None, "This code is unused!");
+        assert_eq!(UdcMapping::Divergent.notation(), None);
+
+        for (udc, code) in [("811.111", "42"), ("821.111(73)", "81"), ("53.02", "53"), ("1(091)", "18"), ("5", "5")] {
+            assert_eq!(dewey.from_udc(udc).map(|class| class.code).as_deref(), Some(code), "Unexpected class for {udc}");
+        }
+        assert!(dewey.from_udc("4").is_none(), "UDC's class 4 is vacant");
+        assert!(UDC_MAPPINGS.iter().all(|(code, _)| dewey.get_class(code).is_some()), "Expected every DDC code to exist");
+    }
+}
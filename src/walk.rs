@@ -0,0 +1,265 @@
+use std::{ collections::VecDeque, ops::ControlFlow };
+
+use crate::{ Class, Dewey };
+
+/// Controls how [Dewey::walk] proceeds after visiting a class
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VisitControl {
+    /// Continue into this class's children
+    Continue,
+
+    /// Skip this class's children, continuing with its next sibling
+    SkipChildren,
+
+    /// Stop the walk entirely
+    Stop,
+}
+
+/// Order in which [Dewey::iter_in] yields classes
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum TraversalOrder {
+    /// Every class followed by its subtree, in shelf order (`0`, `00`, `001`, ..., `01`, ...)
+    #[default]
+    DepthFirst,
+
+    /// All main classes, then all divisions, then all sections, each tier in shelf order
+    BreadthFirst,
+}
+
+impl Dewey {
+    /// Iterates over the entire classification tree depth-first, in shelf order, without copying any classes
+    ///
+    /// # Returns
+    ///
+    /// - `impl Iterator<Item = &'static Class>` - Lazy iterator over every [Class]
+    pub fn iter(&self) -> impl Iterator<Item = &'static Class> {
+        self.all_ref()
+    }
+
+    /// Iterates over the entire classification tree in the provided order, without copying any classes
+    ///
+    /// Breadth-first iteration follows the precomputed children of each class, so only one tier of the tree is queued at a time.
+    ///
+    /// # Arguments
+    ///
+    /// - `order` (`TraversalOrder`) - Order to yield classes in
+    ///
+    /// # Returns
+    ///
+    /// - `impl Iterator<Item = &'static Class>` - Lazy iterator over every [Class]
+    pub fn iter_in(&self, order: TraversalOrder) -> impl Iterator<Item = &'static Class> {
+        let dewey = *self;
+        let mut depth_first = (order == TraversalOrder::DepthFirst).then(|| self.all_ref());
+        let mut queue: VecDeque<&'static Class> = VecDeque::new();
+        if order == TraversalOrder::BreadthFirst {
+            queue.extend(self.children_of(""));
+        }
+
+        std::iter::from_fn(move || {
+            if let Some(classes) = &mut depth_first {
+                return classes.next();
+            }
+
+            let class = queue.pop_front()?;
+            queue.extend(dewey.children_of(&class.code));
+            Some(class)
+        })
+    }
+
+    /// Walks a subtree depth-first, in shelf order, calling `visitor` on every class
+    ///
+    /// The walk is performed in a single pass over the trie, without collecting intermediate [Vec]s.
+    ///
+    /// # Arguments
+    ///
+    /// - `root` (`impl AsRef<str>`) - Code of the subtree's root (an empty string walks the entire tree)
+    /// - `visitor` (`impl FnMut(&Class, usize) -> VisitControl`) - Called with each class and its depth relative to `root` (`root` itself has depth `0`)
+    pub fn walk(&self, root: impl AsRef<str>, mut visitor: impl FnMut(&Class, usize) -> VisitControl) {
+        let mut path: Vec<&str> = Vec::new();
+        let mut skipping: Option<&str> = None;
+
+        for (_, class) in self.classes().predictive_search::<Vec<u8>, _>(self.as_label(root)) {
+            let class: &Class = class;
+            if let Some(skipped) = skipping {
+                if class.code.starts_with(skipped) {
+                    continue;
+                }
+                skipping = None;
+            }
+
+            while path.last().is_some_and(|ancestor| !class.code.starts_with(ancestor)) {
+                let _ = path.pop();
+            }
+
+            match visitor(class, path.len()) {
+                VisitControl::Continue => path.push(&class.code),
+                VisitControl::SkipChildren => skipping = Some(&class.code),
+                VisitControl::Stop => return,
+            }
+        }
+    }
+
+    /// Visits the entire tree depth-first, in shelf order, letting `visitor` prune subtrees or stop early
+    ///
+    /// A [ControlFlow] flavored [Dewey::walk] over the whole tree: returning `ControlFlow::Continue(true)` descends into a class's children, `ControlFlow::Continue(false)` skips them, and `ControlFlow::Break(value)` ends the walk.
+    ///
+    /// ```rust
+    /// use std::ops::ControlFlow;
+    /// use dewey_decimal::Dewey;
+    ///
+    /// let dewey = Dewey::standard();
+    /// let mut divisions = 0;
+    /// let _ = dewey.visit::<()>(|_, depth| {
+    ///     divisions += usize::from(depth == 1);
+    ///     ControlFlow::Continue(depth < 1)
+    /// });
+    /// assert_eq!(divisions, dewey.categories().iter().map(|class| class.child_count).sum::<usize>());
+    ///
+    /// let found = dewey.visit(|class, _| if class.name == "Mathematics" { ControlFlow::Break(class.code.clone()) } else { ControlFlow::Continue(true) });
+    /// assert_eq!(found, ControlFlow::Break("51".into()));
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// - `visitor` (`impl FnMut(&Class, usize) -> ControlFlow<B, bool>`) - Called with each class and its depth (main classes have depth `0`), returning whether to descend into its children or a value to stop with
+    ///
+    /// # Returns
+    ///
+    /// - `ControlFlow<B>` - `ControlFlow::Break` with the visitor's value if it stopped the walk, `ControlFlow::Continue(())` otherwise
+    pub fn visit<B>(&self, mut visitor: impl FnMut(&Class, usize) -> ControlFlow<B, bool>) -> ControlFlow<B> {
+        let mut result = ControlFlow::Continue(());
+        self.walk("", |class, depth| match visitor(class, depth) {
+            ControlFlow::Continue(true) => VisitControl::Continue,
+            ControlFlow::Continue(false) => VisitControl::SkipChildren,
+            ControlFlow::Break(value) => {
+                result = ControlFlow::Break(value);
+                VisitControl::Stop
+            }
+        });
+        result
+    }
+
+    /// Performs a bottom-up reduction over a subtree
+    ///
+    /// Every class is visited after all of its children, receiving their results. Like [Dewey::walk], this is a single pass over the trie.
+    ///
+    /// # Arguments
+    ///
+    /// - `root` (`impl AsRef<str>`) - Code of the subtree's root
+    /// - `init` (`T`) - Initial accumulator, cloned for every class
+    /// - `f` (`impl FnMut(T, &Class, Vec<T>) -> T`) - Called with the initial accumulator, the class, and the results of its direct children
+    ///
+    /// # Returns
+    ///
+    /// - `Option<T>` - The result for `root`, or [None] if `root` doesn't exist
+    pub fn fold<T: Clone>(
+        &self,
+        root: impl AsRef<str>,
+        init: T,
+        mut f: impl FnMut(T, &Class, Vec<T>) -> T
+    ) -> Option<T> {
+        let label = self.as_label(root);
+        self.classes().exact_match(&label)?;
+
+        let mut stack: Vec<(&Class, Vec<T>)> = Vec::new();
+        let mut finish = |stack: &mut Vec<(&Class, Vec<T>)>| {
+            let (class, children) = stack.pop().unwrap();
+            let result = f(init.clone(), class, children);
+            match stack.last_mut() {
+                Some((_, siblings)) => {
+                    siblings.push(result);
+                    None
+                }
+                None => Some(result),
+            }
+        };
+
+        for (_, class) in self.classes().predictive_search::<Vec<u8>, _>(&label) {
+            let class: &Class = class;
+            while stack.last().is_some_and(|(ancestor, _)| !class.code.starts_with(ancestor.code.as_ref())) {
+                let _ = finish(&mut stack);
+            }
+            stack.push((class, Vec::new()));
+        }
+
+        let mut result = None;
+        while !stack.is_empty() {
+            result = finish(&mut stack);
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_walk() {
+        let mut visited = Vec::new();
+        Dewey::standard().walk("5", |class, depth| {
+            visited.push((class.code.clone(), depth));
+            if depth == 1 { VisitControl::SkipChildren } else { VisitControl::Continue }
+        });
+        assert_eq!(visited.len(), 11, "Expected the root and its divisions");
+        assert_eq!(visited[0], ("5".into(), 0));
+        assert_eq!(visited[1], ("50".into(), 1));
+
+        let mut count = 0;
+        Dewey::standard().walk("", |_, _| {
+            count += 1;
+            if count == 3 { VisitControl::Stop } else { VisitControl::Continue }
+        });
+        assert_eq!(count, 3, "Expected the walk to stop");
+    }
+
+    #[test]
+    fn test_visit() {
+        let dewey = Dewey::standard();
+        let mut visited = Vec::new();
+        let result = dewey.visit::<()>(|class, depth| {
+            visited.push((class.code.clone(), depth));
+            ControlFlow::Continue(depth < 1)
+        });
+        assert_eq!(result, ControlFlow::Continue(()));
+        assert!(visited.iter().all(|(code, depth)| *depth <= 1 && code.len() == depth + 1), "Expected no classes below divisions");
+        assert_eq!(visited.iter().filter(|(_, depth)| *depth == 0).count(), dewey.categories().len());
+
+        let mut count = 0;
+        let result = dewey.visit(|class, _| {
+            count += 1;
+            if class.code == "51" { ControlFlow::Break(class.code.clone()) } else { ControlFlow::Continue(true) }
+        });
+        assert_eq!(result, ControlFlow::Break("51".into()));
+        assert_eq!(count, dewey.iter().position(|class| class.code == "51").unwrap() + 1, "Expected the walk to stop");
+    }
+
+    #[test]
+    fn test_iter() {
+        let dewey = Dewey::standard();
+        let depth_first = dewey.iter().map(|class| class.code.as_ref()).collect::<Vec<_>>();
+        assert_eq!(depth_first.len(), dewey.all().len(), "Expected every class");
+        assert_eq!(&depth_first[..3], ["0", "00", "000"], "Expected shelf order");
+        assert_eq!(dewey.iter_in(TraversalOrder::DepthFirst).count(), depth_first.len());
+
+        let breadth_first = dewey.iter_in(TraversalOrder::BreadthFirst).map(|class| class.code.len()).collect::<Vec<_>>();
+        assert_eq!(breadth_first.len(), depth_first.len(), "Expected every class");
+        assert!(breadth_first.is_sorted(), "Expected shallower classes first");
+    }
+
+    #[test]
+    fn test_fold() {
+        let size = Dewey::standard().fold("0", 0usize, |_, _, children| 1 + children.iter().sum::<usize>());
+        assert_eq!(size, Some(98), "Unexpected subtree size");
+
+        let height = Dewey::standard().fold("5", 0usize, |_, _, children| {
+            children.into_iter().max().map_or(0, |h| h + 1)
+        });
+        assert_eq!(height, Some(2), "Unexpected subtree height");
+
+        assert!(Dewey::standard().fold("008", 0, |acc, _, _| acc).is_none(), "This code is unused!");
+    }
+}
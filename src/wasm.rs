@@ -0,0 +1,80 @@
+//! JavaScript bindings for the standard dataset, generated with `wasm-bindgen`
+//!
+//! Build the package with `wasm-pack build --target web -- --features wasm,offline`. Classes are returned as plain objects (`{ code, name, parent_code }`).
+
+use wasm_bindgen::prelude::*;
+
+use crate::{ dataset::is_valid_code, Class, Dewey };
+
+/// Gets a class by code, see [Dewey::get_class]
+///
+/// # Arguments
+///
+/// - `code` (`&str`) - Code of the class (ie `516`)
+///
+/// # Returns
+///
+/// - `Result<JsValue, JsValue>` - The class, or `undefined` if the code is invalid or doesn't exist
+#[wasm_bindgen(js_name = getClass)]
+pub fn get_class(code: &str) -> Result<JsValue, JsValue> {
+    match class(code) {
+        Some(class) => Ok(serde_wasm_bindgen::to_value(&class)?),
+        None => Ok(JsValue::UNDEFINED),
+    }
+}
+
+/// Gets the class returned by [get_class], skipping the lookup for invalid codes
+fn class(code: &str) -> Option<Class> {
+    let code = code.trim();
+    is_valid_code(code.trim_end_matches('X')).then(|| Dewey::standard().get_class(code)).flatten()
+}
+
+/// Gets the direct children of a class, see [Dewey::get_direct_children]
+///
+/// # Arguments
+///
+/// - `code` (`&str`) - Code of the parent class, or an empty string for the main classes
+///
+/// # Returns
+///
+/// - `Result<JsValue, JsValue>` - Array of child classes
+#[wasm_bindgen]
+pub fn children(code: &str) -> Result<JsValue, JsValue> {
+    Ok(serde_wasm_bindgen::to_value(&Dewey::standard().get_direct_children(code.trim()))?)
+}
+
+/// Searches class names, see [Dewey::search_options]
+///
+/// # Arguments
+///
+/// - `query` (`&str`) - Text to search for
+/// - `limit` (`usize`) - Maximum number of results
+///
+/// # Returns
+///
+/// - `Result<JsValue, JsValue>` - Array of matching classes, best first
+#[wasm_bindgen]
+pub fn search(query: &str, limit: usize) -> Result<JsValue, JsValue> {
+    Ok(serde_wasm_bindgen::to_value(&search_results(query, limit))?)
+}
+
+/// Gets the classes returned by [search]
+fn search_results(query: &str, limit: usize) -> Vec<Class> {
+    Dewey::standard().search_options().query(query).limit(limit).execute()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_lookups() {
+        assert_eq!(class(" 516 ").map(|class| class.code.to_string()), Some("516".to_string()));
+        assert_eq!(class("510X").map(|class| class.code.to_string()), Some("510".to_string()));
+        assert!(class("abc").is_none() && class("").is_none() && class("9999").is_none());
+
+        let results = search_results("geometry", 3);
+        assert!(!results.is_empty() && results.len() <= 3);
+        assert!(search_results("geometry", 0).is_empty());
+    }
+}
@@ -0,0 +1,41 @@
+//! The code style is crate-wide, so changing it is tested in its own process rather than alongside the unit tests
+
+use dewey_decimal::{ CodeStyle, Dewey };
+
+#[test]
+fn test_padded_code_style() {
+    Dewey::set_code_style(CodeStyle::Padded);
+    let dewey = Dewey::standard();
+    let class = dewey.get_class("51").unwrap();
+    assert_eq!(Dewey::code_style(), CodeStyle::Padded);
+    assert_eq!((class.code.as_ref(), class.display_code()), ("51", "510".to_string()), "Expected codes to be stored trimmed");
+    assert_eq!(class.to_string(), format!("510 {}", class.name));
+
+    #[cfg(feature = "serde")]
+    {
+        let value = serde_json::to_value(dewey.get_class("516").unwrap()).unwrap();
+        assert_eq!((&value["code"], &value["parent_code"]), (&serde_json::json!("516"), &serde_json::json!("510")));
+    }
+
+    let mut output = Vec::new();
+    dewey.write_csv(&mut output).unwrap();
+    let csv = String::from_utf8(output).unwrap();
+    let codes = csv.lines().skip(1).map(|line| line.split(',').next().unwrap()).collect::<Vec<_>>();
+    let unique = codes.iter().collect::<std::collections::HashSet<_>>();
+    assert_eq!(unique.len(), dewey.all().len(), "Expected exported codes to stay unique");
+    assert!(csv.lines().any(|line| line.starts_with("5,") && line.ends_with(",500")), "Expected padded display codes");
+    assert!(csv.lines().any(|line| line.starts_with("51,") && line.contains(",5,") && line.ends_with(",510")));
+
+    #[cfg(feature = "rusqlite")]
+    {
+        let mut connection = rusqlite::Connection::open_in_memory().unwrap();
+        dewey.seed_sqlite(&mut connection).unwrap();
+        let count: usize = connection.query_row("SELECT COUNT(*) FROM classes", [], |row| row.get(0)).unwrap();
+        assert_eq!(count, dewey.all().len(), "Expected one row per class with padded codes");
+        let display: String = connection.query_row("SELECT display_code FROM classes WHERE code = '5'", [], |row| row.get(0)).unwrap();
+        assert_eq!(display, "500");
+    }
+
+    Dewey::set_code_style(CodeStyle::Trimmed);
+    assert_eq!(dewey.get_class("51").unwrap().to_string(), format!("51 {}", class.name));
+}